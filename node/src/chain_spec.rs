@@ -21,7 +21,7 @@
 
 use cumulus_primitives_core::ParaId;
 
-use runtime_eden::{development_config_genesis, wasm_binary_unwrap};
+use runtime_eden::{development_config_genesis, wasm_binary_unwrap, Runtime};
 use sc_chain_spec::{ChainSpecExtension, ChainSpecGroup};
 use sc_service::ChainType;
 use serde::{Deserialize, Serialize};
@@ -76,6 +76,33 @@ pub fn paradis_config() -> Result<ChainSpec, Box<dyn std::error::Error>> {
 	Ok(ChainSpec::from_json_file("/usr/local/share/nodle/paradis.json".into())?)
 }
 
+/// Check the grants genesis of a chain spec which is not in raw form yet, so that invalid
+/// schedules or bridges are reported before the node tries to build the genesis state.
+pub fn validate_grants_genesis(chain_spec: &dyn sc_service::ChainSpec) -> Result<(), String> {
+	let spec: serde_json::Value = serde_json::from_str(&chain_spec.as_json(false)?).map_err(|e| e.to_string())?;
+	let genesis = &spec["genesis"];
+	let Some(patch) = [
+		&genesis["runtime"],
+		&genesis["runtimeGenesis"]["config"],
+		&genesis["runtimeGenesis"]["patch"],
+	]
+	.into_iter()
+	.find_map(|runtime| runtime["vesting"].as_object()) else {
+		return Ok(());
+	};
+
+	// fields missing from a patch keep their default value
+	let mut config =
+		serde_json::to_value(pallet_grants::GenesisConfig::<Runtime>::default()).map_err(|e| e.to_string())?;
+	for (field, value) in patch {
+		config[field] = value.clone();
+	}
+	serde_json::from_value::<pallet_grants::GenesisConfig<Runtime>>(config)
+		.map_err(|e| format!("Invalid grants genesis config: {e}"))?
+		.validate()
+		.map_err(|e| format!("Invalid grants genesis config: {e}"))
+}
+
 #[cfg(test)]
 pub(crate) mod tests {
 	use super::*;
@@ -94,6 +121,20 @@ pub(crate) mod tests {
 		assert!(production_config().build_storage().is_ok());
 	}
 
+	#[test]
+	fn grants_genesis_is_validated() {
+		assert!(validate_grants_genesis(&development_config(ParaId::from(1000u32))).is_ok());
+		assert!(validate_grants_genesis(&production_config()).is_ok());
+
+		let mut spec: serde_json::Value =
+			serde_json::from_str(&development_config(ParaId::from(1000u32)).as_json(false).unwrap()).unwrap();
+		spec["genesis"]["runtimeGenesis"]["patch"]["vesting"] = serde_json::json!({
+			"bridges": [[0, [], 0, []], [0, [], 1, []]]
+		});
+		let invalid = super::ChainSpec::from_json_bytes(spec.to_string().into_bytes()).unwrap();
+		assert!(validate_grants_genesis(&invalid).is_err());
+	}
+
 	#[test]
 	fn production_has_substitutes_set() {
 		// see https://github.com/NodleCode/chain/releases/tag/2.2.2-hotfix
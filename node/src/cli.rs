@@ -59,6 +59,10 @@ pub enum Subcommand {
 	/// The pallet benchmarking moved to the `pallet` sub-command.
 	#[command(subcommand)]
 	Benchmark(frame_benchmarking_cli::BenchmarkCmd),
+
+	/// Sub-commands concerned with the allocations mint curve.
+	#[command(subcommand)]
+	MintCurve(crate::mint_curve::MintCurveCmd),
 }
 
 #[derive(Debug, clap::Parser)]
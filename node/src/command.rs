@@ -18,6 +18,7 @@
 use crate::{
 	chain_spec,
 	cli::{Cli, RelayChainCli, Subcommand},
+	mint_curve::MintCurveCmd,
 	service::new_partial,
 };
 use cumulus_client_service::storage_proof_size::HostFunctions as ReclaimHostFunctions;
@@ -37,12 +38,14 @@ use std::net::SocketAddr;
 const DEFAULT_PARA_ID: u32 = 2026;
 
 fn load_spec(id: &str) -> std::result::Result<Box<dyn sc_service::ChainSpec>, String> {
-	Ok(match id {
+	let spec: Box<dyn sc_service::ChainSpec> = match id {
 		"eden-local" | "local" | "eden-dev" | "dev" => Box::new(chain_spec::development_config(DEFAULT_PARA_ID.into())),
 		"eden-testing" | "paradis" => Box::new(chain_spec::paradis_config().map_err(|e| format!("{e:?}"))?),
 		"eden" | "production" | "main" | "" => Box::new(chain_spec::production_config()),
 		path => Box::new(chain_spec::ChainSpec::from_json_file(std::path::PathBuf::from(path))?),
-	})
+	};
+	chain_spec::validate_grants_genesis(&*spec)?;
+	Ok(spec)
 }
 
 impl SubstrateCli for Cli {
@@ -227,6 +230,7 @@ pub fn run() -> Result<()> {
 			}
 		}
 		Some(Subcommand::Key(cmd)) => Ok(cmd.run(&cli)?),
+		Some(Subcommand::MintCurve(MintCurveCmd::Simulate(cmd))) => cmd.run(load_spec(&cmd.chain)?),
 		None => {
 			let runner = cli.create_runner(&cli.run.normalize())?;
 			let collator_options = cli.run.collator_options();
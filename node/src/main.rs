@@ -21,6 +21,7 @@ mod chain_spec;
 mod service;
 mod cli;
 mod command;
+mod mint_curve;
 mod rpc;
 
 fn main() -> sc_cli::Result<()> {
@@ -0,0 +1,284 @@
+/*
+ * This file is part of the Nodle Chain distributed at https://github.com/NodleCode/chain
+ * Copyright (C) 2020-2024  Nodle International
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program.  If not, see <http://www.gnu.org/licenses/>.
+ */
+
+//! Offline tooling around the allocations mint curve of the eden runtime.
+
+use pallet_allocations::MintCurve;
+use primitives::{Balance, BlockNumber};
+use runtime_eden::{Allocations, Balances, EdenMintCurve, Runtime};
+use sc_chain_spec::ChainSpec;
+use serde::Serialize;
+use sp_core::Get;
+use sp_runtime::BuildStorage;
+
+/// Sub-commands concerned with the allocations mint curve.
+#[derive(Debug, clap::Subcommand)]
+pub enum MintCurveCmd {
+	/// Project the session quotas and the issuance resulting from the mint curve.
+	Simulate(SimulateCmd),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum OutputFormat {
+	Csv,
+	Json,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum Granularity {
+	/// One row per allocation session
+	Session,
+	/// One row per fiscal period, that is per inflation step
+	FiscalPeriod,
+}
+
+/// Simulate the mint curve of the runtime assuming that every session quota is fully allocated.
+///
+/// The curve itself is the one compiled into the runtime. The starting issuance and curve start
+/// are taken from the command line or, if omitted, from the genesis state of the chain spec.
+#[derive(Debug, clap::Parser)]
+pub struct SimulateCmd {
+	/// The chain spec whose genesis provides the values not given on the command line.
+	#[arg(long, default_value = "")]
+	pub chain: String,
+
+	/// The total issuance to start from, in the smallest unit.
+	#[arg(long)]
+	pub issuance: Option<Balance>,
+
+	/// The relay chain block from which the curve counts its inflation steps.
+	#[arg(long)]
+	pub curve_start: Option<BlockNumber>,
+
+	/// Stop after this many sessions even if the maximum supply is not reached.
+	#[arg(long, default_value_t = 100_000)]
+	pub max_sessions: u32,
+
+	/// The output format.
+	#[arg(long, value_enum, default_value_t = OutputFormat::Csv)]
+	pub format: OutputFormat,
+
+	/// Whether to print one row per session or per fiscal period.
+	#[arg(long, value_enum, default_value_t = Granularity::Session)]
+	pub granularity: Granularity,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct SessionRow {
+	pub session: u32,
+	pub fiscal_period: u32,
+	pub block: BlockNumber,
+	pub quota: Balance,
+	pub issuance: Balance,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct FiscalPeriodRow {
+	pub fiscal_period: u32,
+	pub start_block: BlockNumber,
+	pub minted: Balance,
+	pub issuance: Balance,
+	/// The inflation over the fiscal period in percent
+	pub inflation: f64,
+}
+
+impl SimulateCmd {
+	pub fn run(&self, spec: Box<dyn ChainSpec>) -> sc_cli::Result<()> {
+		let (issuance, curve_start) = match (self.issuance, self.curve_start) {
+			(Some(issuance), Some(curve_start)) => (issuance, curve_start),
+			(issuance, curve_start) => {
+				let storage = spec
+					.build_storage()
+					.map_err(|err| format!("Failed to build the genesis state of the chain spec: {err}"))?;
+				sp_io::TestExternalities::new(storage).execute_with(|| {
+					(
+						issuance.unwrap_or_else(Balances::total_issuance),
+						curve_start.unwrap_or_else(|| Allocations::mint_curve_starting_block().unwrap_or_default()),
+					)
+				})
+			}
+		};
+
+		let curve = EdenMintCurve::get();
+		let sessions = simulate(curve, issuance, curve_start, self.max_sessions);
+		let output = match self.granularity {
+			Granularity::Session => render(&sessions, self.format)?,
+			Granularity::FiscalPeriod => render(&fiscal_periods(curve, &sessions, issuance, curve_start), self.format)?,
+		};
+		print!("{output}");
+		Ok(())
+	}
+}
+
+/// Replay the quota calculations and renewals of `pallet_allocations` from `curve_start`,
+/// assuming each session quota is fully minted, until the maximum supply is reached, the quota
+/// drops to zero or `max_sessions` have been simulated.
+pub fn simulate(
+	curve: &MintCurve<Runtime>,
+	mut issuance: Balance,
+	curve_start: BlockNumber,
+	max_sessions: u32,
+) -> Vec<SessionRow> {
+	let mut rows = Vec::new();
+	let mut n = curve_start;
+	let mut calc_schedule: BlockNumber = 0;
+	let mut next_quota: Balance = 0;
+	for session in 0..max_sessions {
+		if issuance >= curve.maximum_supply() {
+			break;
+		}
+		if n >= calc_schedule {
+			calc_schedule = curve.next_quota_calc_schedule(n, curve_start);
+			next_quota = curve.calc_session_quota(n, curve_start, issuance);
+		}
+		if next_quota == 0 {
+			break;
+		}
+		issuance = issuance.saturating_add(next_quota);
+		rows.push(SessionRow {
+			session,
+			fiscal_period: n.saturating_sub(curve_start) / curve.fiscal_period(),
+			block: n,
+			quota: next_quota,
+			issuance,
+		});
+		n = curve.next_quota_renew_schedule(n, curve_start);
+	}
+	rows
+}
+
+/// Aggregate the sessions simulated with `curve` per fiscal period.
+pub fn fiscal_periods(
+	curve: &MintCurve<Runtime>,
+	sessions: &[SessionRow],
+	mut issuance: Balance,
+	curve_start: BlockNumber,
+) -> Vec<FiscalPeriodRow> {
+	let fiscal_period = curve.fiscal_period();
+	let mut rows: Vec<FiscalPeriodRow> = Vec::new();
+	for session in sessions {
+		match rows.last_mut() {
+			Some(row) if row.fiscal_period == session.fiscal_period => {
+				row.minted = row.minted.saturating_add(session.quota);
+				row.issuance = session.issuance;
+			}
+			_ => rows.push(FiscalPeriodRow {
+				fiscal_period: session.fiscal_period,
+				start_block: curve_start.saturating_add(session.fiscal_period.saturating_mul(fiscal_period)),
+				minted: session.quota,
+				issuance: session.issuance,
+				inflation: 0.0,
+			}),
+		}
+	}
+	for row in rows.iter_mut() {
+		let start_issuance = issuance;
+		issuance = row.issuance;
+		if start_issuance > 0 {
+			row.inflation = row.minted as f64 * 100.0 / start_issuance as f64;
+		}
+	}
+	rows
+}
+
+trait CsvRow {
+	const HEADER: &'static str;
+	fn to_csv(&self) -> String;
+}
+
+impl CsvRow for SessionRow {
+	const HEADER: &'static str = "session,fiscal_period,block,quota,issuance";
+
+	fn to_csv(&self) -> String {
+		format!(
+			"{},{},{},{},{}",
+			self.session, self.fiscal_period, self.block, self.quota, self.issuance
+		)
+	}
+}
+
+impl CsvRow for FiscalPeriodRow {
+	const HEADER: &'static str = "fiscal_period,start_block,minted,issuance,inflation";
+
+	fn to_csv(&self) -> String {
+		format!(
+			"{},{},{},{},{:.6}",
+			self.fiscal_period, self.start_block, self.minted, self.issuance, self.inflation
+		)
+	}
+}
+
+fn render<R: CsvRow + Serialize>(rows: &[R], format: OutputFormat) -> sc_cli::Result<String> {
+	match format {
+		OutputFormat::Csv => {
+			let mut output = format!("{}\n", R::HEADER);
+			for row in rows {
+				output.push_str(&row.to_csv());
+				output.push('\n');
+			}
+			Ok(output)
+		}
+		OutputFormat::Json => serde_json::to_string_pretty(rows)
+			.map(|json| json + "\n")
+			.map_err(|err| sc_cli::Error::Application(Box::new(err))),
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn simulation_matches_the_runtime_curve() {
+		let curve = EdenMintCurve::get();
+		let issuance = 21_000_000_000 * runtime_eden::constants::NODL / 2;
+		let sessions = simulate(curve, issuance, 0, 10);
+
+		assert_eq!(sessions.len(), 10);
+		assert_eq!(sessions[0].block, 0);
+		assert_eq!(sessions[0].quota, curve.calc_session_quota(0, 0, issuance));
+		assert_eq!(sessions[1].block, curve.session_period());
+		assert_eq!(sessions[1].issuance, issuance + sessions[0].quota + sessions[1].quota);
+	}
+
+	#[test]
+	fn simulation_stops_at_maximum_supply() {
+		let curve = EdenMintCurve::get();
+		let issuance = curve.maximum_supply() - 1_000 * runtime_eden::constants::NODL;
+		let sessions = simulate(curve, issuance, 0, 100_000);
+
+		assert!(sessions.len() < 100_000);
+		assert!(sessions.iter().all(|row| row.issuance <= curve.maximum_supply()));
+		assert!(simulate(curve, curve.maximum_supply(), 0, 100_000).is_empty());
+	}
+
+	#[test]
+	fn fiscal_periods_aggregate_sessions() {
+		let curve = EdenMintCurve::get();
+		let issuance = 21_000_000_000 * runtime_eden::constants::NODL / 2;
+		let sessions = simulate(curve, issuance, 0, 200);
+		let periods = fiscal_periods(curve, &sessions, issuance, 0);
+
+		assert_eq!(
+			periods.iter().map(|row| row.minted).sum::<Balance>(),
+			sessions.iter().map(|row| row.quota).sum::<Balance>()
+		);
+		assert_eq!(periods[1].start_block, curve.fiscal_period());
+		assert!(periods[0].inflation > 0.0);
+	}
+}
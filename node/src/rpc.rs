@@ -20,8 +20,10 @@
 
 use std::sync::Arc;
 
-use primitives::{AccountId, Balance, Block, Nonce};
+use primitives::{AccountId, Balance, Block, BlockNumber, Hash, Moment, Nonce};
 
+use jsonrpsee::{core::RpcResult, proc_macros::rpc, types::error::ErrorObject};
+use pallet_grants::runtime_api::{GrantsApi as GrantsRuntimeApi, VestingStatus};
 use sc_client_api::AuxStore;
 pub use sc_rpc::DenyUnsafe;
 use sc_transaction_pool_api::TransactionPool;
@@ -42,6 +44,48 @@ pub struct FullDeps<C, P> {
 	pub deny_unsafe: DenyUnsafe,
 }
 
+/// RPC methods of the grants pallet.
+#[rpc(server)]
+pub trait GrantsApi {
+	/// Returns the vesting status of `who` at the given block, or at the best block.
+	#[method(name = "grants_vestingStatus")]
+	fn vesting_status(
+		&self,
+		who: AccountId,
+		at: Option<Hash>,
+	) -> RpcResult<VestingStatus<BlockNumber, Moment, Balance>>;
+}
+
+/// Serves the grants RPC methods through the grants runtime API.
+pub struct Grants<C> {
+	client: Arc<C>,
+}
+
+impl<C> Grants<C> {
+	/// Create a new instance of the grants RPC handler.
+	pub fn new(client: Arc<C>) -> Self {
+		Self { client }
+	}
+}
+
+impl<C> GrantsApiServer for Grants<C>
+where
+	C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+	C::Api: GrantsRuntimeApi<Block, AccountId, BlockNumber, Moment, Balance>,
+{
+	fn vesting_status(
+		&self,
+		who: AccountId,
+		at: Option<Hash>,
+	) -> RpcResult<VestingStatus<BlockNumber, Moment, Balance>> {
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		self.client
+			.runtime_api()
+			.vesting_status(at, who)
+			.map_err(|e| ErrorObject::owned(1, "Unable to query the vesting status.", Some(e.to_string())))
+	}
+}
+
 /// Instantiate all RPC extensions.
 pub fn create_full<C, P>(deps: FullDeps<C, P>) -> Result<RpcExtension, Box<dyn std::error::Error + Send + Sync>>
 where
@@ -54,6 +98,7 @@ where
 		+ 'static,
 	C::Api: pallet_transaction_payment_rpc::TransactionPaymentRuntimeApi<Block, Balance>,
 	C::Api: frame_rpc_system::AccountNonceApi<Block, AccountId, Nonce>,
+	C::Api: GrantsRuntimeApi<Block, AccountId, BlockNumber, Moment, Balance>,
 	C::Api: BlockBuilder<Block>,
 	P: TransactionPool + Sync + Send + 'static,
 {
@@ -68,7 +113,8 @@ where
 	} = deps;
 
 	module.merge(System::new(client.clone(), pool, deny_unsafe).into_rpc())?;
-	module.merge(TransactionPayment::new(client).into_rpc())?;
+	module.merge(TransactionPayment::new(client.clone()).into_rpc())?;
+	module.merge(Grants::new(client).into_rpc())?;
 
 	Ok(module)
 }
@@ -19,8 +19,11 @@
 use super::*;
 use crate::BalanceOf;
 use crate::Pallet as Allocations;
-use frame_benchmarking::{account, benchmarks};
-use frame_support::{traits::ConstU32, BoundedVec};
+use frame_benchmarking::{account, benchmarks, BenchmarkError};
+use frame_support::{
+	traits::{ConstU32, EnsureOrigin, UnfilteredDispatchable},
+	BoundedVec,
+};
 use frame_system::RawOrigin;
 use sp_std::prelude::*;
 
@@ -54,11 +57,46 @@ benchmarks! {
 		let batch_arg = make_batch::<T>(b);
 		let oracle: T::AccountId = account("oracle", 0, SEED);
 		let mut members = <BenchmarkOracles<T>>::get();
-		assert!(members.try_push(oracle).is_ok());
+		assert!(members.try_push(oracle.clone()).is_ok());
 		<BenchmarkOracles<T>>::put(&members);
 		<SessionQuota<T>>::put(T::ExistentialDeposit::get() * (b * ALLOC_FACTOR).into());
 	}:{
-		let _ = Allocations::<T>::allocate(batch_arg);
+		let _ = Allocations::<T>::allocate(&oracle, Allocations::<T>::native_batch(batch_arg), None, false);
+	}
+
+	allocate_vested {
+		let b in 1..T::MaxAllocs::get();
+
+		let batch_arg = make_batch::<T>(b);
+		let oracle: T::AccountId = account("oracle", 0, SEED);
+		let terms = VestingTerms {
+			start: Zero::zero(),
+			period: One::one(),
+			period_count: 2,
+		};
+		<SessionQuota<T>>::put(T::ExistentialDeposit::get() * (b * ALLOC_FACTOR).into());
+	}:{
+		let _ = Allocations::<T>::allocate(&oracle, Allocations::<T>::native_batch(batch_arg), Some(&terms), false);
+	}
+
+	allocate_bridged {
+		let b in 1..T::MaxAllocs::get();
+
+		let batch_arg: Vec<_> = (0..b)
+			.map(|i| {
+				let mut address = [0u8; 20];
+				address[..4].copy_from_slice(&i.to_le_bytes());
+				(
+					AllocationTarget::Bridged { bridge_id: 0, address },
+					T::ExistentialDeposit::get() * ALLOC_FACTOR.into(),
+				)
+			})
+			.collect();
+		let oracle: T::AccountId = account("oracle", 0, SEED);
+		T::BridgeTransfer::register_bridge(0);
+		<SessionQuota<T>>::put(T::ExistentialDeposit::get() * (b * ALLOC_FACTOR).into());
+	}:{
+		let _ = Allocations::<T>::allocate(&oracle, batch_arg, None, false);
 	}
 
 	calc_quota {
@@ -91,6 +129,67 @@ benchmarks! {
 		assert_eq!(<MintCurveStartingBlock<T>>::get(), Some(One::one()));
 	}
 
+	set_protocol_fee_split {
+		let n in 0..T::MaxProtocolFeeDestinations::get();
+
+		let mut split = BoundedVec::with_bounded_capacity(n as usize);
+		for i in 0..n {
+			let ratio = if i == 0 { Perbill::one() } else { Perbill::zero() };
+			let _ = split.try_push((FeeDestination::Account(account("destination", i, SEED)), ratio));
+		}
+		let call = Call::<T>::set_protocol_fee_split { split: split.clone() };
+		let origin = T::ProtocolFeeSplitOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+	}: { call.dispatch_bypass_filter(origin)? }
+	verify {
+		assert_eq!(<ProtocolFeeSplit<T>>::get(), split);
+	}
+
+	set_oracle_quota {
+		let oracle: T::AccountId = account("oracle", 0, SEED);
+		let quota = T::ExistentialDeposit::get() * ALLOC_FACTOR.into();
+		let call = Call::<T>::set_oracle_quota { oracle: oracle.clone(), quota: Some(quota) };
+		let origin = T::OracleAdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+	}: { call.dispatch_bypass_filter(origin)? }
+	verify {
+		assert_eq!(<OracleQuota<T>>::get(&oracle), Some(quota));
+	}
+
+	suspend_oracle {
+		let oracle: T::AccountId = account("oracle", 0, SEED);
+		let call = Call::<T>::suspend_oracle { oracle: oracle.clone() };
+		let origin = T::OracleAdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+	}: { call.dispatch_bypass_filter(origin)? }
+	verify {
+		assert!(Allocations::<T>::is_suspended(&oracle));
+	}
+
+	resume_oracle {
+		let oracle: T::AccountId = account("oracle", 0, SEED);
+		<SuspendedOracles<T>>::insert(&oracle, ());
+		let call = Call::<T>::resume_oracle { oracle: oracle.clone() };
+		let origin = T::OracleAdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+	}: { call.dispatch_bypass_filter(origin)? }
+	verify {
+		assert!(!Allocations::<T>::is_suspended(&oracle));
+	}
+
+	pause {
+		let call = Call::<T>::pause {};
+		let origin = T::PauseOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+	}: { call.dispatch_bypass_filter(origin)? }
+	verify {
+		assert!(<Paused<T>>::get());
+	}
+
+	unpause {
+		<Paused<T>>::put(true);
+		let call = Call::<T>::unpause {};
+		let origin = T::PauseOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+	}: { call.dispatch_bypass_filter(origin)? }
+	verify {
+		assert!(!<Paused<T>>::get());
+	}
+
 	impl_benchmark_test_suite!(
 		Allocations,
 		crate::tests::new_test_ext(),
@@ -28,7 +28,8 @@ use frame_support::weights::Weight;
 use frame_support::{
 	ensure,
 	pallet_prelude::MaxEncodedLen,
-	traits::{tokens::ExistenceRequirement, Contains, Currency, Get},
+	storage::with_storage_layer,
+	traits::{tokens::ExistenceRequirement, Contains, Currency, Get, WithdrawReasons},
 	BoundedVec, PalletId,
 };
 
@@ -38,10 +39,10 @@ use scale_info::TypeInfo;
 use sp_arithmetic::traits::UniqueSaturatedInto;
 use sp_runtime::{
 	traits::{AccountIdConversion, BlockNumberProvider, Bounded, CheckedAdd, CheckedDiv, One, Saturating, Zero},
-	DispatchResult, Perbill, RuntimeDebug,
+	DispatchError, DispatchResult, Perbill, RuntimeDebug,
 };
 use sp_std::prelude::*;
-use support::WithAccountId;
+use support::{BridgeTransfer, VestedTransfer, WithAccountId};
 
 pub mod weights;
 pub use weights::WeightInfo;
@@ -60,6 +61,57 @@ enum Releases {
 	V1, // Adds storage info
 }
 
+/// The terms under which the allocations of a batch are vested to their grantees instead of
+/// being paid out as free balance.
+#[derive(Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct VestingTerms<BlockNumber> {
+	/// The block from which the vesting periods are counted
+	pub start: BlockNumber,
+	/// The length of one vesting period in blocks
+	pub period: BlockNumber,
+	/// The number of periods over which each grantee's share is released
+	pub period_count: u32,
+}
+
+/// Who receives the grantee's share of an allocation.
+#[derive(Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub enum AllocationTarget<AccountId> {
+	/// An account of this chain
+	Native(AccountId),
+	/// An address on the remote chain behind one of the grants bridges
+	Bridged { bridge_id: u32, address: [u8; 20] },
+}
+
+/// Why an entry of a best effort batch was not allocated.
+#[derive(Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub enum SkipReason {
+	/// The amount is too low and would conflict with the ExistentialDeposit parameter
+	DoesNotSatisfyExistentialDeposit,
+	/// Paying out the grantee's share failed with the given error
+	TransferFailed(DispatchError),
+}
+
+/// Where a share of the protocol fee is sent to.
+#[derive(Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub enum FeeDestination<AccountId> {
+	/// The share is transferred to the given account
+	Account(AccountId),
+	/// The share is burnt
+	Burn,
+}
+
+/// How much of the session quota left unallocated at the end of a session is carried over to the
+/// next one.
+#[derive(Clone, Copy, Encode, Decode, PartialEq, Eq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub enum CarryOverPolicy {
+	/// The unallocated quota is discarded
+	None,
+	/// The whole unallocated quota is carried over
+	Full,
+	/// The unallocated quota is carried over up to the given share of the next session's quota
+	Capped(Perbill),
+}
+
 #[derive(Default, TypeInfo)]
 pub struct MintCurve<T: Config> {
 	session_period: BlockNumberFor<T>,
@@ -178,6 +230,13 @@ pub mod pallet {
 		type ProtocolFee: Get<Perbill>;
 		type ProtocolFeeReceiver: WithAccountId<Self::AccountId>;
 
+		/// How many destinations the protocol fee can be split across
+		#[pallet::constant]
+		type MaxProtocolFeeDestinations: Get<u32>;
+
+		/// The origin which may update the protocol fee split
+		type ProtocolFeeSplitOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
 		/// Runtime existential deposit
 		#[pallet::constant]
 		type ExistentialDeposit: Get<BalanceOf<Self>>;
@@ -188,15 +247,39 @@ pub mod pallet {
 
 		type OracleMembers: Contains<Self::AccountId>;
 
+		/// The origin which may assign per oracle quotas and suspend or resume oracles
+		type OracleAdminOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// The origin which may pause and unpause minting
+		type PauseOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// When set, minting is automatically paused instead of allocating a batch whose total
+		/// exceeds this share of the session quota
+		#[pallet::constant]
+		type AutoPauseThreshold: Get<Option<Perbill>>;
+
 		/// MintCurve acts as an upper bound limiting how much the total token issuance can inflate
 		/// over a configured session
 		type MintCurve: Get<&'static MintCurve<Self>>;
 
+		/// How the session quota left unallocated is carried over to the next session. The
+		/// renewed quota never exceeds what is left until the mint curve's maximum supply.
+		#[pallet::constant]
+		type QuotaCarryOver: Get<CarryOverPolicy>;
+
 		/// Provide access to the block number that should be used in mint curve calculations. For
 		/// example those who use this pallet for a parachain may decide to use the block creation
 		/// pace of the relay chain for timing.
 		type BlockNumberProvider: BlockNumberProvider<BlockNumber = BlockNumberFor<Self>>;
 
+		/// Delivers the grantees' shares of the batches which should be vested rather than paid
+		/// out immediately.
+		type VestedTransfer: VestedTransfer<Self::AccountId, BlockNumberFor<Self>, BalanceOf<Self>>;
+
+		/// Delivers the grantees' shares of the allocations targeting an address on a remote
+		/// chain.
+		type BridgeTransfer: BridgeTransfer<Self::AccountId, BalanceOf<Self>>;
+
 		/// Weight information for extrinsics in this pallet.
 		type WeightInfo: WeightInfo;
 	}
@@ -215,10 +298,10 @@ pub mod pallet {
 			origin: OriginFor<T>,
 			batch: BoundedVec<(T::AccountId, BalanceOf<T>), T::MaxAllocs>,
 		) -> DispatchResultWithPostInfo {
-			Self::ensure_oracle(origin)?;
+			let oracle = Self::ensure_oracle(origin)?;
 			let update_weight = Self::checked_update_session_quota();
 			let rewards_len = batch.len().try_into().unwrap_or_else(|_| T::MaxAllocs::get());
-			Self::allocate(batch)?;
+			Self::allocate(&oracle, Self::native_batch(batch), None, false)?;
 			let dispatch_info = PostDispatchInfo::from((
 				Some(update_weight.saturating_add(T::WeightInfo::allocate(rewards_len))),
 				Pays::No,
@@ -236,6 +319,157 @@ pub mod pallet {
 			Self::update_session_quota_schedules(curve_start);
 			Ok(Pays::No.into())
 		}
+
+		/// Same as `batch` except that each grantee's share, after the protocol fee, is delivered
+		/// as a vesting schedule following the given `terms` rather than as free balance.
+		#[pallet::call_index(2)]
+		#[pallet::weight(T::WeightInfo::allocate_vested(batch.len().try_into().unwrap_or_else(|_| T::MaxAllocs::get())).saturating_add(T::WeightInfo::checked_update_session_quota()))]
+		pub fn batch_vested(
+			origin: OriginFor<T>,
+			batch: BoundedVec<(T::AccountId, BalanceOf<T>), T::MaxAllocs>,
+			terms: VestingTerms<BlockNumberFor<T>>,
+		) -> DispatchResultWithPostInfo {
+			let oracle = Self::ensure_oracle(origin)?;
+			let update_weight = Self::checked_update_session_quota();
+			let rewards_len = batch.len().try_into().unwrap_or_else(|_| T::MaxAllocs::get());
+			Self::allocate(&oracle, Self::native_batch(batch), Some(&terms), false)?;
+			let dispatch_info = PostDispatchInfo::from((
+				Some(update_weight.saturating_add(T::WeightInfo::allocate_vested(rewards_len))),
+				Pays::No,
+			));
+			Ok(dispatch_info)
+		}
+
+		/// Same as `batch`, or `batch_vested` when `terms` are given, except that the entries
+		/// which cannot be allocated are skipped instead of failing the whole batch. Only what is
+		/// actually paid out is minted and the skipped entries are reported in an
+		/// `AllocationsSkipped` event.
+		#[pallet::call_index(3)]
+		#[pallet::weight(Pallet::<T>::allocate_weight(batch.len().try_into().unwrap_or_else(|_| T::MaxAllocs::get()), terms.is_some()).saturating_add(T::WeightInfo::checked_update_session_quota()))]
+		pub fn batch_best_effort(
+			origin: OriginFor<T>,
+			batch: BoundedVec<(T::AccountId, BalanceOf<T>), T::MaxAllocs>,
+			terms: Option<VestingTerms<BlockNumberFor<T>>>,
+		) -> DispatchResultWithPostInfo {
+			let oracle = Self::ensure_oracle(origin)?;
+			let update_weight = Self::checked_update_session_quota();
+			let rewards_len = batch.len().try_into().unwrap_or_else(|_| T::MaxAllocs::get());
+			Self::allocate(&oracle, Self::native_batch(batch), terms.as_ref(), true)?;
+			let dispatch_info = PostDispatchInfo::from((
+				Some(update_weight.saturating_add(Self::allocate_weight(rewards_len, terms.is_some()))),
+				Pays::No,
+			));
+			Ok(dispatch_info)
+		}
+
+		/// Set how the protocol fee is split across its destinations. The ratios must add up to
+		/// exactly 100% and each destination can only appear once. An empty split sends the whole
+		/// protocol fee to the `ProtocolFeeReceiver`.
+		#[pallet::call_index(4)]
+		#[pallet::weight(T::WeightInfo::set_protocol_fee_split(split.len().try_into().unwrap_or_else(|_| T::MaxProtocolFeeDestinations::get())))]
+		pub fn set_protocol_fee_split(
+			origin: OriginFor<T>,
+			split: BoundedVec<(FeeDestination<T::AccountId>, Perbill), T::MaxProtocolFeeDestinations>,
+		) -> DispatchResultWithPostInfo {
+			T::ProtocolFeeSplitOrigin::ensure_origin(origin)?;
+			let total_parts = split
+				.iter()
+				.fold(0u64, |acc, (_, ratio)| acc.saturating_add(ratio.deconstruct().into()));
+			ensure!(
+				split.is_empty() || total_parts == u64::from(Perbill::one().deconstruct()),
+				Error::<T>::InvalidProtocolFeeSplit
+			);
+			let proxy = FeeDestination::Account(T::PalletId::get().into_account_truncating());
+			for (i, (destination, _)) in split.iter().enumerate() {
+				ensure!(
+					*destination != proxy && !split[..i].iter().any(|(other, _)| other == destination),
+					Error::<T>::InvalidProtocolFeeDestination
+				);
+			}
+			<ProtocolFeeSplit<T>>::put(split.clone());
+			Self::deposit_event(Event::ProtocolFeeSplitUpdated(split));
+			Ok(Pays::No.into())
+		}
+
+		/// Limit how much `oracle` can allocate per session, on top of the shared session quota.
+		/// Passing `None` lifts the limit.
+		#[pallet::call_index(5)]
+		#[pallet::weight(T::WeightInfo::set_oracle_quota())]
+		pub fn set_oracle_quota(
+			origin: OriginFor<T>,
+			oracle: T::AccountId,
+			quota: Option<BalanceOf<T>>,
+		) -> DispatchResultWithPostInfo {
+			T::OracleAdminOrigin::ensure_origin(origin)?;
+			<OracleQuota<T>>::set(&oracle, quota);
+			Self::deposit_event(Event::OracleQuotaSet { oracle, quota });
+			Ok(Pays::No.into())
+		}
+
+		/// Prevent `oracle` from allocating without removing it from the oracle members.
+		#[pallet::call_index(6)]
+		#[pallet::weight(T::WeightInfo::suspend_oracle())]
+		pub fn suspend_oracle(origin: OriginFor<T>, oracle: T::AccountId) -> DispatchResultWithPostInfo {
+			T::OracleAdminOrigin::ensure_origin(origin)?;
+			<SuspendedOracles<T>>::insert(&oracle, ());
+			Self::deposit_event(Event::OracleSuspended { oracle });
+			Ok(Pays::No.into())
+		}
+
+		/// Allow a previously suspended `oracle` to allocate again.
+		#[pallet::call_index(7)]
+		#[pallet::weight(T::WeightInfo::resume_oracle())]
+		pub fn resume_oracle(origin: OriginFor<T>, oracle: T::AccountId) -> DispatchResultWithPostInfo {
+			T::OracleAdminOrigin::ensure_origin(origin)?;
+			ensure!(
+				<SuspendedOracles<T>>::take(&oracle).is_some(),
+				Error::<T>::OracleNotSuspended
+			);
+			Self::deposit_event(Event::OracleResumed { oracle });
+			Ok(Pays::No.into())
+		}
+
+		/// Stop all allocations and session quota renewals until minting is unpaused.
+		#[pallet::call_index(8)]
+		#[pallet::weight(T::WeightInfo::pause())]
+		pub fn pause(origin: OriginFor<T>) -> DispatchResultWithPostInfo {
+			T::PauseOrigin::ensure_origin(origin)?;
+			ensure!(!<Paused<T>>::get(), Error::<T>::MintingPaused);
+			<Paused<T>>::put(true);
+			Self::deposit_event(Event::MintingPaused);
+			Ok(Pays::No.into())
+		}
+
+		/// Resume allocations and session quota renewals.
+		#[pallet::call_index(9)]
+		#[pallet::weight(T::WeightInfo::unpause())]
+		pub fn unpause(origin: OriginFor<T>) -> DispatchResultWithPostInfo {
+			T::PauseOrigin::ensure_origin(origin)?;
+			ensure!(<Paused<T>>::get(), Error::<T>::MintingNotPaused);
+			<Paused<T>>::kill();
+			Self::deposit_event(Event::MintingUnpaused);
+			Ok(Pays::No.into())
+		}
+
+		/// Same as `batch` except that each entry can also target an address on a remote chain
+		/// behind one of the grants bridges. The share of such entries is burnt here and announced
+		/// to the bridge oracles which credit it on the remote chain.
+		#[pallet::call_index(10)]
+		#[pallet::weight(T::WeightInfo::allocate_bridged(batch.len().try_into().unwrap_or_else(|_| T::MaxAllocs::get())).saturating_add(T::WeightInfo::checked_update_session_quota()))]
+		pub fn batch_with_bridges(
+			origin: OriginFor<T>,
+			batch: BoundedVec<(AllocationTarget<T::AccountId>, BalanceOf<T>), T::MaxAllocs>,
+		) -> DispatchResultWithPostInfo {
+			let oracle = Self::ensure_oracle(origin)?;
+			let update_weight = Self::checked_update_session_quota();
+			let rewards_len = batch.len().try_into().unwrap_or_else(|_| T::MaxAllocs::get());
+			Self::allocate(&oracle, batch.into_inner(), None, false)?;
+			let dispatch_info = PostDispatchInfo::from((
+				Some(update_weight.saturating_add(T::WeightInfo::allocate_bridged(rewards_len))),
+				Pays::No,
+			));
+			Ok(dispatch_info)
+		}
 	}
 
 	#[pallet::error]
@@ -248,6 +482,20 @@ pub mod pallet {
 		DoesNotSatisfyExistentialDeposit,
 		/// Batch is empty or no issuance is necessary
 		BatchEmpty,
+		/// The ratios of the protocol fee split do not add up to 100%
+		InvalidProtocolFeeSplit,
+		/// The oracle is suspended and cannot allocate
+		OracleSuspended,
+		/// The oracle is not suspended
+		OracleNotSuspended,
+		/// We are exceeding the oracle's own limit for rewards in this session
+		AllocationExceedsOracleQuota,
+		/// Minting is paused
+		MintingPaused,
+		/// Minting is not paused
+		MintingNotPaused,
+		/// A protocol fee destination is the pallet's own account or appears more than once
+		InvalidProtocolFeeDestination,
 	}
 
 	#[pallet::event]
@@ -257,6 +505,48 @@ pub mod pallet {
 		SessionQuotaRenewed,
 		/// Session quota is calculated and this new value will be used from the next session
 		SessionQuotaCalculated(BalanceOf<T>),
+		/// Some entries of a best effort batch were not allocated \[skipped\]
+		AllocationsSkipped(BoundedVec<(AllocationTarget<T::AccountId>, BalanceOf<T>, SkipReason), T::MaxAllocs>),
+		/// A grantee received its share of a batch, after the protocol fee
+		Allocated { who: T::AccountId, amount: BalanceOf<T> },
+		/// A grantee's share of a batch, after the protocol fee, was sent to an address on a
+		/// remote chain
+		AllocatedToBridge {
+			bridge_id: u32,
+			address: [u8; 20],
+			amount: BalanceOf<T>,
+		},
+		/// An oracle allocated a batch: `total` was minted, of which `protocol_fee` went to the
+		/// protocol fee receiver, and `count` grantees were paid
+		BatchAllocated {
+			oracle: T::AccountId,
+			total: BalanceOf<T>,
+			protocol_fee: BalanceOf<T>,
+			count: u32,
+		},
+		/// The protocol fee split was updated \[split\]
+		ProtocolFeeSplitUpdated(BoundedVec<(FeeDestination<T::AccountId>, Perbill), T::MaxProtocolFeeDestinations>),
+		/// A destination received its share of the protocol fee of a batch
+		ProtocolFeeDistributed {
+			destination: FeeDestination<T::AccountId>,
+			amount: BalanceOf<T>,
+		},
+		/// The per session quota of an oracle was set or lifted
+		OracleQuotaSet {
+			oracle: T::AccountId,
+			quota: Option<BalanceOf<T>>,
+		},
+		/// An oracle was suspended
+		OracleSuspended { oracle: T::AccountId },
+		/// A suspended oracle was allowed to allocate again
+		OracleResumed { oracle: T::AccountId },
+		/// Minting was paused
+		MintingPaused,
+		/// Minting was unpaused
+		MintingUnpaused,
+		/// Minting was paused because a batch exceeded the auto pause threshold. The batch was
+		/// not allocated.
+		MintingAutoPaused { oracle: T::AccountId, total: BalanceOf<T> },
 	}
 
 	#[pallet::storage]
@@ -275,6 +565,12 @@ pub mod pallet {
 	#[pallet::getter(fn session_quota)]
 	pub(crate) type SessionQuota<T: Config> = StorageValue<_, BalanceOf<T>, ValueQuery>;
 
+	/// The allocation quota the current session started with, including what was carried over
+	/// from the previous session.
+	#[pallet::storage]
+	#[pallet::getter(fn renewed_session_quota_total)]
+	pub(crate) type RenewedSessionQuota<T: Config> = StorageValue<_, BalanceOf<T>, ValueQuery>;
+
 	/// The next session's allocation quota, in other words, the top up that is coming for
 	/// `SessionQuota`.
 	///
@@ -298,6 +594,39 @@ pub mod pallet {
 	#[pallet::storage]
 	#[pallet::getter(fn mint_curve_starting_block)]
 	pub(crate) type MintCurveStartingBlock<T: Config> = StorageValue<_, BlockNumberFor<T>, OptionQuery>;
+
+	/// Whether allocations and session quota renewals are paused.
+	#[pallet::storage]
+	#[pallet::getter(fn paused)]
+	pub(crate) type Paused<T: Config> = StorageValue<_, bool, ValueQuery>;
+
+	/// How much each oracle may allocate per session, if it is limited beyond the shared
+	/// `SessionQuota`.
+	#[pallet::storage]
+	#[pallet::getter(fn oracle_quota)]
+	pub(crate) type OracleQuota<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, BalanceOf<T>, OptionQuery>;
+
+	/// How much each oracle allocated in the session identified by its renew schedule. Entries of
+	/// past sessions are considered as zero.
+	#[pallet::storage]
+	pub(crate) type OracleSessionAllocated<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, (BlockNumberFor<T>, BalanceOf<T>), OptionQuery>;
+
+	/// The total amount allocated by each oracle so far.
+	#[pallet::storage]
+	#[pallet::getter(fn oracle_minted)]
+	pub(crate) type OracleMinted<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, BalanceOf<T>, ValueQuery>;
+
+	/// The oracles which are not allowed to allocate even though they are oracle members.
+	#[pallet::storage]
+	pub(crate) type SuspendedOracles<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, (), OptionQuery>;
+
+	/// How the protocol fee is split across its destinations. When empty the whole protocol fee
+	/// goes to the `ProtocolFeeReceiver`.
+	#[pallet::storage]
+	#[pallet::getter(fn protocol_fee_split)]
+	pub(crate) type ProtocolFeeSplit<T: Config> =
+		StorageValue<_, BoundedVec<(FeeDestination<T::AccountId>, Perbill), T::MaxProtocolFeeDestinations>, ValueQuery>;
 }
 
 impl<T: Config> Pallet<T> {
@@ -308,20 +637,66 @@ impl<T: Config> Pallet<T> {
 		return T::OracleMembers::contains(&who);
 	}
 
-	fn ensure_oracle(origin: T::RuntimeOrigin) -> DispatchResult {
+	fn ensure_oracle(origin: T::RuntimeOrigin) -> Result<T::AccountId, DispatchError> {
 		let sender = ensure_signed(origin)?;
-		ensure!(Self::is_oracle(sender), Error::<T>::OracleAccessDenied);
-		Ok(())
+		ensure!(Self::is_oracle(sender.clone()), Error::<T>::OracleAccessDenied);
+		ensure!(!Self::is_suspended(&sender), Error::<T>::OracleSuspended);
+		Ok(sender)
+	}
+
+	pub fn is_suspended(oracle: &T::AccountId) -> bool {
+		<SuspendedOracles<T>>::contains_key(oracle)
+	}
+
+	/// Return how much `oracle` has allocated in the current session.
+	pub fn oracle_session_allocated(oracle: &T::AccountId) -> BalanceOf<T> {
+		match <OracleSessionAllocated<T>>::get(oracle) {
+			Some((session, allocated)) if session == <SessionQuotaRenewSchedule<T>>::get() => allocated,
+			_ => Zero::zero(),
+		}
+	}
+
+	/// Return the weight of allocating a batch of `rewards_len` entries.
+	fn allocate_weight(rewards_len: u32, vested: bool) -> Weight {
+		if vested {
+			T::WeightInfo::allocate_vested(rewards_len)
+		} else {
+			T::WeightInfo::allocate(rewards_len)
+		}
+	}
+
+	/// Turn a batch of native accounts into allocation entries.
+	fn native_batch(
+		batch: BoundedVec<(T::AccountId, BalanceOf<T>), T::MaxAllocs>,
+	) -> Vec<(AllocationTarget<T::AccountId>, BalanceOf<T>)> {
+		batch
+			.into_iter()
+			.map(|(account, amount)| (AllocationTarget::Native(account), amount))
+			.collect()
 	}
 
-	fn allocate(batch: BoundedVec<(T::AccountId, BalanceOf<T>), T::MaxAllocs>) -> DispatchResult {
+	/// Mint and distribute the allocations of the batch. In `best_effort` mode the entries which
+	/// cannot be allocated are skipped and reported rather than failing the whole batch.
+	fn allocate(
+		oracle: &T::AccountId,
+		batch: Vec<(AllocationTarget<T::AccountId>, BalanceOf<T>)>,
+		vesting: Option<&VestingTerms<BlockNumberFor<T>>>,
+		best_effort: bool,
+	) -> DispatchResult {
+		ensure!(!<Paused<T>>::get(), Error::<T>::MintingPaused);
 		ensure!(batch.len() > Zero::zero(), Error::<T>::BatchEmpty);
 
+		let mut skipped: Vec<(AllocationTarget<T::AccountId>, BalanceOf<T>, SkipReason)> = Vec::new();
+
 		// sanity checks
 		let min_alloc = T::ExistentialDeposit::get().saturating_mul(2u32.into());
 		let mut full_issuance: BalanceOf<T> = Zero::zero();
-		for (_account, amount) in batch.iter() {
-			ensure!(amount >= &min_alloc, Error::<T>::DoesNotSatisfyExistentialDeposit,);
+		for (target, amount) in batch.iter() {
+			if amount < &min_alloc {
+				ensure!(best_effort, Error::<T>::DoesNotSatisfyExistentialDeposit);
+				skipped.push((target.clone(), *amount, SkipReason::DoesNotSatisfyExistentialDeposit));
+				continue;
+			}
 
 			// overflow, so too many coins to allocate
 			full_issuance = full_issuance
@@ -334,8 +709,27 @@ impl<T: Config> Pallet<T> {
 			full_issuance <= session_quota,
 			Error::<T>::AllocationExceedsSessionQuota
 		);
+		let oracle_allocated = Self::oracle_session_allocated(oracle);
+		if let Some(oracle_quota) = <OracleQuota<T>>::get(oracle) {
+			ensure!(
+				oracle_allocated.saturating_add(full_issuance) <= oracle_quota,
+				Error::<T>::AllocationExceedsOracleQuota
+			);
+		}
 
-		<SessionQuota<T>>::put(session_quota.saturating_sub(full_issuance));
+		// trip the circuit breaker rather than minting an abnormally large batch
+		if let Some(threshold) = T::AutoPauseThreshold::get() {
+			// what is left of the session quota is a lower bound of it until the first renewal
+			let current_quota = <RenewedSessionQuota<T>>::get().max(session_quota);
+			if full_issuance > threshold * current_quota {
+				<Paused<T>>::put(true);
+				Self::deposit_event(Event::MintingAutoPaused {
+					oracle: oracle.clone(),
+					total: full_issuance,
+				});
+				return Ok(());
+			}
+		}
 
 		// allocate the coins to the proxy account
 		T::Currency::resolve_creating(
@@ -345,29 +739,179 @@ impl<T: Config> Pallet<T> {
 
 		// send to accounts, unfortunately we need to loop again
 		let mut full_protocol: BalanceOf<T> = Zero::zero();
-		for (account, amount) in batch.iter().cloned() {
+		let mut unpaid: BalanceOf<T> = Zero::zero();
+		let mut count: u32 = 0;
+		for (target, amount) in batch.into_iter() {
+			if amount < min_alloc {
+				// already reported as skipped
+				continue;
+			}
 			let amount_for_protocol = T::ProtocolFee::get() * amount;
 			let amount_for_grantee = amount.saturating_sub(amount_for_protocol);
-			T::Currency::transfer(
+			match with_storage_layer(|| Self::pay_grantee(&target, amount_for_grantee, vesting)) {
+				Ok(()) => {
+					full_protocol = full_protocol.saturating_add(amount_for_protocol);
+					count = count.saturating_add(1);
+					Self::deposit_event(match target {
+						AllocationTarget::Native(who) => Event::Allocated {
+							who,
+							amount: amount_for_grantee,
+						},
+						AllocationTarget::Bridged { bridge_id, address } => Event::AllocatedToBridge {
+							bridge_id,
+							address,
+							amount: amount_for_grantee,
+						},
+					});
+				}
+				Err(err) if best_effort => {
+					unpaid = unpaid.saturating_add(amount);
+					skipped.push((target, amount, SkipReason::TransferFailed(err)));
+				}
+				Err(err) => return Err(err),
+			}
+		}
+
+		// send protocol fees
+		Self::distribute_protocol_fee(full_protocol)?;
+
+		// burn what could not be paid out so that we only mint what was allocated
+		if !unpaid.is_zero() {
+			let _ = T::Currency::withdraw(
 				&T::PalletId::get().into_account_truncating(),
-				&account,
-				amount_for_grantee,
-				ExistenceRequirement::KeepAlive,
+				unpaid,
+				WithdrawReasons::all(),
+				ExistenceRequirement::AllowDeath,
 			)?;
-			full_protocol = full_protocol.saturating_add(amount_for_protocol);
 		}
 
-		// send protocol fees
-		T::Currency::transfer(
-			&T::PalletId::get().into_account_truncating(),
-			&T::ProtocolFeeReceiver::account_id(),
-			full_protocol,
-			ExistenceRequirement::AllowDeath,
-		)?;
+		let total = full_issuance.saturating_sub(unpaid);
+		<SessionQuota<T>>::put(session_quota.saturating_sub(total));
+		<OracleSessionAllocated<T>>::insert(
+			oracle,
+			(
+				<SessionQuotaRenewSchedule<T>>::get(),
+				oracle_allocated.saturating_add(total),
+			),
+		);
+		<OracleMinted<T>>::mutate(oracle, |minted| *minted = minted.saturating_add(total));
+
+		if !skipped.is_empty() {
+			Self::deposit_event(Event::AllocationsSkipped(BoundedVec::truncate_from(skipped)));
+		}
+
+		Self::deposit_event(Event::BatchAllocated {
+			oracle: oracle.clone(),
+			total,
+			protocol_fee: full_protocol,
+			count,
+		});
+
+		Ok(())
+	}
+
+	/// Split the protocol fee held by the proxy account across its destinations, the last one
+	/// receiving any rounding remainder.
+	fn distribute_protocol_fee(full_protocol: BalanceOf<T>) -> DispatchResult {
+		let split = <ProtocolFeeSplit<T>>::get();
+		if split.is_empty() {
+			return Self::pay_protocol_fee(
+				FeeDestination::Account(T::ProtocolFeeReceiver::account_id()),
+				full_protocol,
+			);
+		}
+
+		let mut remaining = full_protocol;
+		let last = split.len().saturating_sub(1);
+		for (i, (destination, ratio)) in split.into_iter().enumerate() {
+			let amount = if i == last {
+				remaining
+			} else {
+				ratio.mul_floor(full_protocol).min(remaining)
+			};
+			remaining = remaining.saturating_sub(amount);
+			Self::pay_protocol_fee(destination, amount)?;
+		}
+
+		Ok(())
+	}
+
+	/// Pay a share of the protocol fee to its destination. A share the destination cannot take,
+	/// such as dust for an account which does not exist yet, goes to the `ProtocolFeeReceiver`
+	/// instead, and is burnt if the receiver cannot take it either.
+	fn pay_protocol_fee(destination: FeeDestination<T::AccountId>, amount: BalanceOf<T>) -> DispatchResult {
+		let receiver = FeeDestination::Account(T::ProtocolFeeReceiver::account_id());
+		for destination in [destination, receiver] {
+			if with_storage_layer(|| Self::send_protocol_fee(&destination, amount)).is_ok() {
+				Self::deposit_event(Event::ProtocolFeeDistributed { destination, amount });
+				return Ok(());
+			}
+		}
+		Self::send_protocol_fee(&FeeDestination::Burn, amount)?;
+		Self::deposit_event(Event::ProtocolFeeDistributed {
+			destination: FeeDestination::Burn,
+			amount,
+		});
+		Ok(())
+	}
 
+	fn send_protocol_fee(destination: &FeeDestination<T::AccountId>, amount: BalanceOf<T>) -> DispatchResult {
+		match destination {
+			FeeDestination::Account(who) => T::Currency::transfer(
+				&T::PalletId::get().into_account_truncating(),
+				who,
+				amount,
+				ExistenceRequirement::AllowDeath,
+			)?,
+			FeeDestination::Burn => {
+				let _ = T::Currency::withdraw(
+					&T::PalletId::get().into_account_truncating(),
+					amount,
+					WithdrawReasons::all(),
+					ExistenceRequirement::AllowDeath,
+				)?;
+			}
+		}
 		Ok(())
 	}
 
+	/// Pay the grantee's share from the proxy account, either as free balance or as a vesting
+	/// schedule following the given terms. Shares targeting a remote chain are never vested.
+	fn pay_grantee(
+		target: &AllocationTarget<T::AccountId>,
+		amount_for_grantee: BalanceOf<T>,
+		vesting: Option<&VestingTerms<BlockNumberFor<T>>>,
+	) -> DispatchResult {
+		let account = match target {
+			AllocationTarget::Native(account) => account,
+			AllocationTarget::Bridged { bridge_id, address } => {
+				return T::BridgeTransfer::bridge_transfer(
+					&T::PalletId::get().into_account_truncating(),
+					*address,
+					*bridge_id,
+					amount_for_grantee,
+				)
+			}
+		};
+		if let Some(terms) = vesting {
+			T::VestedTransfer::vested_transfer(
+				&T::PalletId::get().into_account_truncating(),
+				account,
+				amount_for_grantee,
+				terms.start,
+				terms.period,
+				terms.period_count,
+			)
+		} else {
+			T::Currency::transfer(
+				&T::PalletId::get().into_account_truncating(),
+				account,
+				amount_for_grantee,
+				ExistenceRequirement::KeepAlive,
+			)
+		}
+	}
+
 	/// Use the block number provider and recalculate and/or renew the session quota if it's time
 	/// for doing that based on the configured schedules for these actions.
 	/// Return the weight of the call.
@@ -409,14 +953,35 @@ impl<T: Config> Pallet<T> {
 		}
 	}
 
+	/// Return the quota of the new session, that is the next session quota topped up with what is
+	/// carried over from the current one, capped by the mint curve's maximum supply.
+	fn renewed_session_quota() -> BalanceOf<T> {
+		let next_quota = <NextSessionQuota<T>>::get();
+		let unallocated = <SessionQuota<T>>::get();
+		let carry_over = match T::QuotaCarryOver::get() {
+			CarryOverPolicy::None => Zero::zero(),
+			CarryOverPolicy::Full => unallocated,
+			CarryOverPolicy::Capped(cap) => unallocated.min(cap * next_quota),
+		};
+		let headroom = T::MintCurve::get()
+			.maximum_supply()
+			.saturating_sub(T::Currency::total_issuance());
+		next_quota.saturating_add(carry_over.min(headroom.saturating_sub(next_quota)))
+	}
+
 	/// Renew the session quota and update the corresponding storage only once during a session
 	/// period.
 	/// Return the weight of the call.
 	fn checked_renew_session_quota(n: BlockNumberFor<T>) -> Weight {
-		if n >= <SessionQuotaRenewSchedule<T>>::get() {
+		if <Paused<T>>::get() {
+			// Storage: Allocations Paused (r:1 w:0)
+			T::DbWeight::get().reads(1)
+		} else if n >= <SessionQuotaRenewSchedule<T>>::get() {
 			let curve_start = Self::curve_start_or(n);
 			Self::update_session_quota_renew_schedule(n, curve_start);
-			<SessionQuota<T>>::put(<NextSessionQuota<T>>::get());
+			let session_quota = Self::renewed_session_quota();
+			<SessionQuota<T>>::put(session_quota);
+			<RenewedSessionQuota<T>>::put(session_quota);
 			Self::deposit_event(Event::SessionQuotaRenewed);
 			T::WeightInfo::renew_quota()
 		} else {
@@ -21,7 +21,11 @@
 use super::*;
 use crate::{self as pallet_allocations};
 use frame_support::{
-	assert_noop, assert_ok, dispatch::Pays, ord_parameter_types, parameter_types, traits::ConstU32, PalletId,
+	assert_noop, assert_ok,
+	dispatch::Pays,
+	ord_parameter_types, parameter_types,
+	traits::{ConstU32, ConstU64},
+	PalletId,
 };
 use frame_system::EnsureSignedBy;
 use lazy_static::lazy_static;
@@ -42,6 +46,8 @@ frame_support::construct_runtime!(
 		Balances: pallet_balances::{Pallet, Call, Config<T>, Storage, Event<T>},
 		Membership: pallet_membership::{Pallet, Call, Storage, Config<T>, Event<T>},
 		Allocations: pallet_allocations::{Pallet, Call, Storage, Event<T>},
+		Vesting: pallet_grants::{Pallet, Call, Storage, Event<T>, FreezeReason},
+		Timestamp: pallet_timestamp::{Pallet, Call, Storage},
 	}
 );
 
@@ -92,11 +98,11 @@ impl pallet_balances::Config for Test {
 	type AccountStore = frame_system::Pallet<Test>;
 	type MaxReserves = ();
 	type ReserveIdentifier = [u8; 8];
-	type FreezeIdentifier = [u8; 8];
-	type MaxFreezes = ();
+	type FreezeIdentifier = RuntimeFreezeReason;
+	type MaxFreezes = ConstU32<1>;
 	type WeightInfo = ();
 	type RuntimeHoldReason = ();
-	type RuntimeFreezeReason = ();
+	type RuntimeFreezeReason = RuntimeFreezeReason;
 }
 
 const THREE_INFLATION_STEPS: &[Perbill] = &[
@@ -121,6 +127,8 @@ parameter_types! {
 	pub const MaxAllocs: u32 = 10;
 	pub const AllocPalletId: PalletId = PalletId(*b"py/alloc");
 	pub MintCurveParameter: &'static MintCurve<Test> = &MINT_CURVE;
+	pub storage QuotaCarryOver: CarryOverPolicy = CarryOverPolicy::None;
+	pub storage AutoPauseThreshold: Option<Perbill> = None;
 }
 ord_parameter_types! {
 	pub const Admin: u64 = 4;
@@ -144,17 +152,50 @@ impl pallet_membership::Config for Test {
 	type WeightInfo = ();
 }
 
+parameter_types! {
+	pub const MaxSchedule: u32 = 10;
+}
+impl pallet_grants::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type RuntimeFreezeReason = RuntimeFreezeReason;
+	type Currency = pallet_balances::Pallet<Self>;
+	type CancelOrigin = EnsureSignedBy<Admin, u64>;
+	type BridgeOracleOrigin = EnsureSignedBy<Admin, u64>;
+	type BridgeTransferTimeout = ConstU64<100>;
+	type MaxSchedule = MaxSchedule;
+	type MaxBatchSchedules = ConstU32<10>;
+	type MaxBridgeOracles = ConstU32<4>;
+	type WeightInfo = ();
+	type BlockNumberProvider = frame_system::Pallet<Test>;
+	type Time = Timestamp;
+}
+
+impl pallet_timestamp::Config for Test {
+	type Moment = u64;
+	type OnTimestampSet = ();
+	type MinimumPeriod = ConstU64<1>;
+	type WeightInfo = ();
+}
+
 impl Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type Currency = pallet_balances::Pallet<Self>;
 	type PalletId = AllocPalletId;
 	type ProtocolFee = Fee;
 	type ProtocolFeeReceiver = Receiver;
+	type MaxProtocolFeeDestinations = ConstU32<4>;
+	type ProtocolFeeSplitOrigin = EnsureSignedBy<Admin, u64>;
 	type ExistentialDeposit = <Test as pallet_balances::Config>::ExistentialDeposit;
 	type MintCurve = MintCurveParameter;
+	type QuotaCarryOver = QuotaCarryOver;
 	type MaxAllocs = MaxAllocs;
 	type OracleMembers = Membership;
+	type OracleAdminOrigin = EnsureSignedBy<Admin, u64>;
+	type PauseOrigin = EnsureSignedBy<Admin, u64>;
+	type AutoPauseThreshold = AutoPauseThreshold;
 	type BlockNumberProvider = frame_system::Pallet<Test>;
+	type VestedTransfer = Vesting;
+	type BridgeTransfer = Vesting;
 	type WeightInfo = ();
 }
 type Errors = Error<Test>;
@@ -527,6 +568,69 @@ fn session_quota_is_renewed_every_session() {
 	})
 }
 
+#[test]
+fn unallocated_session_quota_is_fully_carried_over() {
+	new_test_ext().execute_with(|| {
+		QuotaCarryOver::set(&CarryOverPolicy::Full);
+		let total_issuance = 100000u64;
+		let _issuance = Balances::issue(total_issuance);
+		let session_share = total_issuance * MINT_CURVE.session_period() / MINT_CURVE.fiscal_period();
+		let quota0 = THREE_INFLATION_STEPS[0] * session_share;
+
+		System::set_block_number(2);
+		Allocations::checked_update_session_quota();
+		assert_eq!(Allocations::session_quota(), quota0);
+
+		<SessionQuota<Test>>::put(100);
+		System::set_block_number(8);
+		Allocations::checked_update_session_quota();
+		assert_eq!(Allocations::session_quota(), quota0 + 100);
+	})
+}
+
+#[test]
+fn carried_over_session_quota_is_capped_by_policy() {
+	new_test_ext().execute_with(|| {
+		QuotaCarryOver::set(&CarryOverPolicy::Capped(Perbill::from_percent(50)));
+		let total_issuance = 100000u64;
+		let _issuance = Balances::issue(total_issuance);
+		let session_share = total_issuance * MINT_CURVE.session_period() / MINT_CURVE.fiscal_period();
+		let quota0 = THREE_INFLATION_STEPS[0] * session_share;
+
+		System::set_block_number(2);
+		Allocations::checked_update_session_quota();
+		assert_eq!(Allocations::session_quota(), quota0);
+
+		System::set_block_number(8);
+		Allocations::checked_update_session_quota();
+		assert_eq!(Allocations::session_quota(), quota0 + quota0 / 2);
+
+		<SessionQuota<Test>>::put(10);
+		System::set_block_number(11);
+		Allocations::checked_update_session_quota();
+		assert_eq!(Allocations::next_session_quota(), quota0);
+		assert_eq!(Allocations::session_quota(), quota0 + 10);
+	})
+}
+
+#[test]
+fn carried_over_session_quota_respects_maximum_supply() {
+	new_test_ext().execute_with(|| {
+		QuotaCarryOver::set(&CarryOverPolicy::Full);
+		let total_issuance = MINT_CURVE.maximum_supply() - 1000;
+		let _issuance = Balances::issue(total_issuance);
+
+		System::set_block_number(2);
+		Allocations::checked_update_session_quota();
+		assert_eq!(Allocations::session_quota(), 300);
+
+		<SessionQuota<Test>>::put(5000);
+		System::set_block_number(8);
+		Allocations::checked_update_session_quota();
+		assert_eq!(Allocations::session_quota(), 1000);
+	})
+}
+
 #[test]
 fn non_oracle_is_rejected() {
 	new_test_ext().execute_with(|| {
@@ -700,11 +804,736 @@ fn no_issuance() {
 	})
 }
 
+#[test]
+fn vested_allocation_works() {
+	new_test_ext().execute_with(|| {
+		let total_issuance = 100000u64;
+		let _issuance = Balances::issue(total_issuance);
+		let terms = VestingTerms {
+			start: 1,
+			period: 10,
+			period_count: 5,
+		};
+		assert_ok!(Allocations::batch_vested(
+			RuntimeOrigin::signed(Oracle::get()),
+			bounded_vec![(Grantee::get(), 50)],
+			terms
+		));
+		assert_eq!(
+			Vesting::vesting_schedules(Grantee::get()).to_vec(),
+			vec![pallet_grants::VestingSchedule {
+				start: 1,
+				period: 10,
+				period_count: 5,
+				per_period: 9,
+				cliff: 0,
+			}]
+		);
+		assert_eq!(Balances::free_balance(Grantee::get()), 45);
+		assert_eq!(Balances::usable_balance(Grantee::get()), 0);
+		assert_eq!(Balances::free_balance(Receiver::get()), 5);
+		assert_eq!(Balances::usable_balance(Receiver::get()), 5);
+
+		let alloc_account_id: u64 = AllocPalletId::get().into_account_truncating();
+		assert_eq!(Balances::free_balance(alloc_account_id), 0);
+	})
+}
+
+#[test]
+fn vested_allocation_pays_rounding_remainder_as_free_balance() {
+	new_test_ext().execute_with(|| {
+		let total_issuance = 100000u64;
+		let _issuance = Balances::issue(total_issuance);
+		let terms = VestingTerms {
+			start: 1,
+			period: 10,
+			period_count: 4,
+		};
+		assert_ok!(Allocations::batch_vested(
+			RuntimeOrigin::signed(Oracle::get()),
+			bounded_vec![(Grantee::get(), 50), (OtherGrantee::get(), 30)],
+			terms
+		));
+		assert_eq!(Balances::free_balance(Grantee::get()), 45);
+		assert_eq!(Balances::usable_balance(Grantee::get()), 1);
+		assert_eq!(Balances::free_balance(OtherGrantee::get()), 27);
+		assert_eq!(Balances::usable_balance(OtherGrantee::get()), 3);
+		assert_eq!(Balances::free_balance(Receiver::get()), 8);
+	})
+}
+
+#[test]
+fn vested_allocation_fails_for_invalid_terms() {
+	new_test_ext().execute_with(|| {
+		let total_issuance = 100000u64;
+		let _issuance = Balances::issue(total_issuance);
+		let terms = VestingTerms {
+			start: 1,
+			period: 0,
+			period_count: 5,
+		};
+		assert_noop!(
+			Allocations::batch_vested(
+				RuntimeOrigin::signed(Oracle::get()),
+				bounded_vec![(Grantee::get(), 50)],
+				terms
+			),
+			pallet_grants::Error::<Test>::ZeroVestingPeriod
+		);
+	})
+}
+
+#[test]
+fn non_oracle_is_rejected_for_vested_allocation() {
+	new_test_ext().execute_with(|| {
+		let total_issuance = 100000u64;
+		let _issuance = Balances::issue(total_issuance);
+		let terms = VestingTerms {
+			start: 1,
+			period: 10,
+			period_count: 5,
+		};
+		assert_noop!(
+			Allocations::batch_vested(
+				RuntimeOrigin::signed(Hacker::get()),
+				bounded_vec![(Grantee::get(), 50)],
+				terms
+			),
+			Errors::OracleAccessDenied
+		);
+	})
+}
+
+#[test]
+fn batch_allocation_emits_events() {
+	new_test_ext().execute_with(|| {
+		let total_issuance = 100000u64;
+		let _issuance = Balances::issue(total_issuance);
+		System::reset_events();
+		assert_ok!(Allocations::batch(
+			RuntimeOrigin::signed(Oracle::get()),
+			bounded_vec![(Grantee::get(), 50), (OtherGrantee::get(), 30)]
+		));
+		let events: Vec<_> = System::events()
+			.into_iter()
+			.filter_map(|event_record| match event_record.event {
+				RuntimeEvent::Allocations(event) => Some(event),
+				_ => None,
+			})
+			.collect();
+		assert_eq!(
+			events,
+			vec![
+				crate::Event::Allocated {
+					who: Grantee::get(),
+					amount: 45,
+				},
+				crate::Event::Allocated {
+					who: OtherGrantee::get(),
+					amount: 27,
+				},
+				crate::Event::BatchAllocated {
+					oracle: Oracle::get(),
+					total: 80,
+					protocol_fee: 8,
+					count: 2,
+				},
+			]
+		);
+	})
+}
+
+#[test]
+fn best_effort_allocation_skips_amounts_below_minimum() {
+	new_test_ext().execute_with(|| {
+		let total_issuance = 100000u64;
+		let _issuance = Balances::issue(total_issuance);
+		let session_quota = Allocations::session_quota();
+		System::reset_events();
+		assert_ok!(Allocations::batch_best_effort(
+			RuntimeOrigin::signed(Oracle::get()),
+			bounded_vec![(Grantee::get(), 50), (OtherGrantee::get(), 3)],
+			None
+		));
+		assert_eq!(Allocations::session_quota(), session_quota - 50);
+		assert_eq!(Balances::free_balance(Grantee::get()), 45);
+		assert_eq!(Balances::free_balance(OtherGrantee::get()), 0);
+		assert_eq!(Balances::free_balance(Receiver::get()), 5);
+		assert_eq!(Balances::total_issuance(), total_issuance + 50);
+		System::assert_has_event(RuntimeEvent::Allocations(crate::Event::AllocationsSkipped(
+			bounded_vec![(
+				AllocationTarget::Native(OtherGrantee::get()),
+				3,
+				SkipReason::DoesNotSatisfyExistentialDeposit
+			)],
+		)));
+		System::assert_last_event(RuntimeEvent::Allocations(crate::Event::BatchAllocated {
+			oracle: Oracle::get(),
+			total: 50,
+			protocol_fee: 5,
+			count: 1,
+		}));
+
+		let alloc_account_id: u64 = AllocPalletId::get().into_account_truncating();
+		assert_eq!(Balances::free_balance(alloc_account_id), 0);
+	})
+}
+
+#[test]
+fn best_effort_allocation_skips_failed_transfers() {
+	new_test_ext().execute_with(|| {
+		let total_issuance = 100000u64;
+		let _issuance = Balances::issue(total_issuance);
+		let schedule = pallet_grants::VestingSchedule {
+			start: 0,
+			period: 10,
+			period_count: 1,
+			per_period: 1,
+			cliff: 0,
+		};
+		pallet_grants::VestingSchedules::<Test>::insert(
+			OtherGrantee::get(),
+			BoundedVec::truncate_from(vec![schedule; MaxSchedule::get() as usize]),
+		);
+		let session_quota = Allocations::session_quota();
+		let terms = VestingTerms {
+			start: 1,
+			period: 10,
+			period_count: 5,
+		};
+		System::reset_events();
+		assert_ok!(Allocations::batch_best_effort(
+			RuntimeOrigin::signed(Oracle::get()),
+			bounded_vec![(Grantee::get(), 50), (OtherGrantee::get(), 50)],
+			Some(terms)
+		));
+		assert_eq!(Allocations::session_quota(), session_quota - 50);
+		assert_eq!(Balances::free_balance(Grantee::get()), 45);
+		assert_eq!(Balances::usable_balance(Grantee::get()), 0);
+		assert_eq!(Balances::free_balance(OtherGrantee::get()), 0);
+		assert_eq!(Balances::free_balance(Receiver::get()), 5);
+		assert_eq!(Balances::total_issuance(), total_issuance + 50);
+		System::assert_has_event(RuntimeEvent::Allocations(crate::Event::AllocationsSkipped(
+			bounded_vec![(
+				AllocationTarget::Native(OtherGrantee::get()),
+				50,
+				SkipReason::TransferFailed(pallet_grants::Error::<Test>::MaxScheduleOverflow.into())
+			)],
+		)));
+		System::assert_last_event(RuntimeEvent::Allocations(crate::Event::BatchAllocated {
+			oracle: Oracle::get(),
+			total: 50,
+			protocol_fee: 5,
+			count: 1,
+		}));
+
+		let alloc_account_id: u64 = AllocPalletId::get().into_account_truncating();
+		assert_eq!(Balances::free_balance(alloc_account_id), 0);
+	})
+}
+
+#[test]
+fn strict_batch_still_fails_on_invalid_entries() {
+	new_test_ext().execute_with(|| {
+		let total_issuance = 100000u64;
+		let _issuance = Balances::issue(total_issuance);
+		assert_noop!(
+			Allocations::batch(
+				RuntimeOrigin::signed(Oracle::get()),
+				bounded_vec![(Grantee::get(), 50), (OtherGrantee::get(), 3)]
+			),
+			Errors::DoesNotSatisfyExistentialDeposit
+		);
+	})
+}
+
+#[test]
+fn non_oracle_is_rejected_for_best_effort_allocation() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			Allocations::batch_best_effort(
+				RuntimeOrigin::signed(Hacker::get()),
+				bounded_vec![(Grantee::get(), 50)],
+				None
+			),
+			Errors::OracleAccessDenied
+		);
+	})
+}
+
+#[test]
+fn only_split_origin_can_set_protocol_fee_split() {
+	new_test_ext().execute_with(|| {
+		let split: BoundedVec<_, _> = bounded_vec![(FeeDestination::Burn, Perbill::one())];
+		assert_noop!(
+			Allocations::set_protocol_fee_split(RuntimeOrigin::signed(Hacker::get()), split.clone()),
+			BadOrigin
+		);
+		assert_ok!(Allocations::set_protocol_fee_split(
+			RuntimeOrigin::signed(Admin::get()),
+			split.clone()
+		));
+		assert_eq!(Allocations::protocol_fee_split(), split);
+		System::assert_last_event(RuntimeEvent::Allocations(crate::Event::ProtocolFeeSplitUpdated(split)));
+	})
+}
+
+#[test]
+fn protocol_fee_split_must_add_up_to_one_hundred_percent() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			Allocations::set_protocol_fee_split(
+				RuntimeOrigin::signed(Admin::get()),
+				bounded_vec![
+					(FeeDestination::Account(Receiver::get()), Perbill::from_percent(50)),
+					(FeeDestination::Burn, Perbill::from_percent(40)),
+				]
+			),
+			Errors::InvalidProtocolFeeSplit
+		);
+		assert_noop!(
+			Allocations::set_protocol_fee_split(
+				RuntimeOrigin::signed(Admin::get()),
+				bounded_vec![
+					(FeeDestination::Account(Receiver::get()), Perbill::from_percent(60)),
+					(FeeDestination::Burn, Perbill::from_percent(60)),
+				]
+			),
+			Errors::InvalidProtocolFeeSplit
+		);
+		assert_ok!(Allocations::set_protocol_fee_split(
+			RuntimeOrigin::signed(Admin::get()),
+			bounded_vec![]
+		));
+	})
+}
+
+#[test]
+fn protocol_fee_is_split_across_destinations() {
+	new_test_ext().execute_with(|| {
+		let total_issuance = 100000u64;
+		let _issuance = Balances::issue(total_issuance);
+		let company: u64 = 5;
+		assert_ok!(Allocations::set_protocol_fee_split(
+			RuntimeOrigin::signed(Admin::get()),
+			bounded_vec![
+				(FeeDestination::Account(Receiver::get()), Perbill::from_percent(50)),
+				(FeeDestination::Account(company), Perbill::from_percent(30)),
+				(FeeDestination::Burn, Perbill::from_percent(20)),
+			]
+		));
+		System::reset_events();
+		assert_ok!(Allocations::batch(
+			RuntimeOrigin::signed(Oracle::get()),
+			bounded_vec![(Grantee::get(), 50), (OtherGrantee::get(), 50)]
+		));
+		assert_eq!(Balances::free_balance(Grantee::get()), 45);
+		assert_eq!(Balances::free_balance(OtherGrantee::get()), 45);
+		assert_eq!(Balances::free_balance(Receiver::get()), 5);
+		assert_eq!(Balances::free_balance(company), 3);
+		assert_eq!(Balances::total_issuance(), total_issuance + 98);
+		System::assert_has_event(RuntimeEvent::Allocations(crate::Event::ProtocolFeeDistributed {
+			destination: FeeDestination::Account(Receiver::get()),
+			amount: 5,
+		}));
+		System::assert_has_event(RuntimeEvent::Allocations(crate::Event::ProtocolFeeDistributed {
+			destination: FeeDestination::Account(company),
+			amount: 3,
+		}));
+		System::assert_has_event(RuntimeEvent::Allocations(crate::Event::ProtocolFeeDistributed {
+			destination: FeeDestination::Burn,
+			amount: 2,
+		}));
+
+		let alloc_account_id: u64 = AllocPalletId::get().into_account_truncating();
+		assert_eq!(Balances::free_balance(alloc_account_id), 0);
+	})
+}
+
+#[test]
+fn protocol_fee_goes_to_receiver_without_split() {
+	new_test_ext().execute_with(|| {
+		let total_issuance = 100000u64;
+		let _issuance = Balances::issue(total_issuance);
+		System::reset_events();
+		assert_ok!(Allocations::batch(
+			RuntimeOrigin::signed(Oracle::get()),
+			bounded_vec![(Grantee::get(), 50)]
+		));
+		assert_eq!(Balances::free_balance(Receiver::get()), 5);
+		System::assert_has_event(RuntimeEvent::Allocations(crate::Event::ProtocolFeeDistributed {
+			destination: FeeDestination::Account(Receiver::get()),
+			amount: 5,
+		}));
+	})
+}
+
+#[test]
+fn protocol_fee_destinations_must_be_distinct_and_external() {
+	new_test_ext().execute_with(|| {
+		let alloc_account_id: u64 = AllocPalletId::get().into_account_truncating();
+		assert_noop!(
+			Allocations::set_protocol_fee_split(
+				RuntimeOrigin::signed(Admin::get()),
+				bounded_vec![
+					(FeeDestination::Account(Receiver::get()), Perbill::from_percent(50)),
+					(FeeDestination::Account(alloc_account_id), Perbill::from_percent(50)),
+				]
+			),
+			Errors::InvalidProtocolFeeDestination
+		);
+		assert_noop!(
+			Allocations::set_protocol_fee_split(
+				RuntimeOrigin::signed(Admin::get()),
+				bounded_vec![
+					(FeeDestination::Burn, Perbill::from_percent(50)),
+					(FeeDestination::Burn, Perbill::from_percent(50)),
+				]
+			),
+			Errors::InvalidProtocolFeeDestination
+		);
+	})
+}
+
+#[test]
+fn protocol_fee_share_below_existential_deposit_goes_to_receiver() {
+	new_test_ext().execute_with(|| {
+		let total_issuance = 100000u64;
+		let _issuance = Balances::issue(total_issuance);
+		let new_account: u64 = 7;
+		assert_ok!(Allocations::set_protocol_fee_split(
+			RuntimeOrigin::signed(Admin::get()),
+			bounded_vec![
+				(FeeDestination::Account(Receiver::get()), Perbill::from_percent(90)),
+				(FeeDestination::Account(new_account), Perbill::from_percent(10)),
+			]
+		));
+		System::reset_events();
+		assert_ok!(Allocations::batch(
+			RuntimeOrigin::signed(Oracle::get()),
+			bounded_vec![(Grantee::get(), 50), (OtherGrantee::get(), 50)]
+		));
+
+		// the new account cannot be created with 1 while the existential deposit is 2
+		assert_eq!(Balances::free_balance(new_account), 0);
+		assert_eq!(Balances::free_balance(Receiver::get()), 10);
+		assert_eq!(Balances::free_balance(Grantee::get()), 45);
+		System::assert_has_event(RuntimeEvent::Allocations(crate::Event::ProtocolFeeDistributed {
+			destination: FeeDestination::Account(Receiver::get()),
+			amount: 1,
+		}));
+
+		let alloc_account_id: u64 = AllocPalletId::get().into_account_truncating();
+		assert_eq!(Balances::free_balance(alloc_account_id), 0);
+	})
+}
+
+#[test]
+fn only_admin_can_manage_oracles() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			Allocations::set_oracle_quota(RuntimeOrigin::signed(Hacker::get()), Oracle::get(), Some(60)),
+			BadOrigin
+		);
+		assert_noop!(
+			Allocations::suspend_oracle(RuntimeOrigin::signed(Hacker::get()), Oracle::get()),
+			BadOrigin
+		);
+		assert_noop!(
+			Allocations::resume_oracle(RuntimeOrigin::signed(Hacker::get()), Oracle::get()),
+			BadOrigin
+		);
+	})
+}
+
+#[test]
+fn suspended_oracle_is_rejected() {
+	new_test_ext().execute_with(|| {
+		let total_issuance = 100000u64;
+		let _issuance = Balances::issue(total_issuance);
+		assert_ok!(Allocations::suspend_oracle(
+			RuntimeOrigin::signed(Admin::get()),
+			Oracle::get()
+		));
+		System::assert_last_event(RuntimeEvent::Allocations(crate::Event::OracleSuspended {
+			oracle: Oracle::get(),
+		}));
+		assert!(Allocations::is_oracle(Oracle::get()));
+		assert_noop!(
+			Allocations::batch(RuntimeOrigin::signed(Oracle::get()), bounded_vec![(Grantee::get(), 50)]),
+			Errors::OracleSuspended
+		);
+
+		assert_ok!(Allocations::resume_oracle(
+			RuntimeOrigin::signed(Admin::get()),
+			Oracle::get()
+		));
+		System::assert_last_event(RuntimeEvent::Allocations(crate::Event::OracleResumed {
+			oracle: Oracle::get(),
+		}));
+		assert_ok!(Allocations::batch(
+			RuntimeOrigin::signed(Oracle::get()),
+			bounded_vec![(Grantee::get(), 50)]
+		));
+		assert_noop!(
+			Allocations::resume_oracle(RuntimeOrigin::signed(Admin::get()), Oracle::get()),
+			Errors::OracleNotSuspended
+		);
+	})
+}
+
+#[test]
+fn oracle_quota_limits_allocations_per_session() {
+	new_test_ext().execute_with(|| {
+		let total_issuance = 100000u64;
+		let _issuance = Balances::issue(total_issuance);
+		assert_ok!(Allocations::set_oracle_quota(
+			RuntimeOrigin::signed(Admin::get()),
+			Oracle::get(),
+			Some(60)
+		));
+		System::assert_last_event(RuntimeEvent::Allocations(crate::Event::OracleQuotaSet {
+			oracle: Oracle::get(),
+			quota: Some(60),
+		}));
+		assert_ok!(Allocations::batch(
+			RuntimeOrigin::signed(Oracle::get()),
+			bounded_vec![(Grantee::get(), 50)]
+		));
+		assert_noop!(
+			Allocations::batch(RuntimeOrigin::signed(Oracle::get()), bounded_vec![(Grantee::get(), 20)]),
+			Errors::AllocationExceedsOracleQuota
+		);
+		assert_eq!(Allocations::oracle_session_allocated(&Oracle::get()), 50);
+
+		// the oracle quota is renewed along with the session quota
+		System::set_block_number(Allocations::quota_renew_schedule());
+		assert_ok!(Allocations::batch(
+			RuntimeOrigin::signed(Oracle::get()),
+			bounded_vec![(Grantee::get(), 20)]
+		));
+		assert_eq!(Allocations::oracle_session_allocated(&Oracle::get()), 20);
+		assert_eq!(Allocations::oracle_minted(Oracle::get()), 70);
+
+		assert_ok!(Allocations::set_oracle_quota(
+			RuntimeOrigin::signed(Admin::get()),
+			Oracle::get(),
+			None
+		));
+		assert_ok!(Allocations::batch(
+			RuntimeOrigin::signed(Oracle::get()),
+			bounded_vec![(Grantee::get(), 50)]
+		));
+		assert_eq!(Allocations::oracle_minted(Oracle::get()), 120);
+	})
+}
+
+#[test]
+fn oracle_minted_counts_only_paid_allocations() {
+	new_test_ext().execute_with(|| {
+		let total_issuance = 100000u64;
+		let _issuance = Balances::issue(total_issuance);
+		assert_ok!(Allocations::batch_best_effort(
+			RuntimeOrigin::signed(Oracle::get()),
+			bounded_vec![(Grantee::get(), 50), (OtherGrantee::get(), 3)],
+			None
+		));
+		assert_eq!(Allocations::oracle_minted(Oracle::get()), 50);
+	})
+}
+
+#[test]
+fn only_pause_origin_can_pause_and_unpause() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(Allocations::pause(RuntimeOrigin::signed(Hacker::get())), BadOrigin);
+		assert_noop!(
+			Allocations::unpause(RuntimeOrigin::signed(Admin::get())),
+			Errors::MintingNotPaused
+		);
+		assert_ok!(Allocations::pause(RuntimeOrigin::signed(Admin::get())));
+		System::assert_last_event(RuntimeEvent::Allocations(crate::Event::MintingPaused));
+		assert_noop!(
+			Allocations::pause(RuntimeOrigin::signed(Admin::get())),
+			Errors::MintingPaused
+		);
+		assert_noop!(Allocations::unpause(RuntimeOrigin::signed(Hacker::get())), BadOrigin);
+		assert_ok!(Allocations::unpause(RuntimeOrigin::signed(Admin::get())));
+		System::assert_last_event(RuntimeEvent::Allocations(crate::Event::MintingUnpaused));
+		assert!(!Allocations::paused());
+	})
+}
+
+#[test]
+fn pausing_blocks_allocations_and_quota_renewal() {
+	new_test_ext().execute_with(|| {
+		let total_issuance = 100000u64;
+		let _issuance = Balances::issue(total_issuance);
+		assert_ok!(Allocations::batch(
+			RuntimeOrigin::signed(Oracle::get()),
+			bounded_vec![(Grantee::get(), 50)]
+		));
+		let session_quota = Allocations::session_quota();
+		assert_ok!(Allocations::pause(RuntimeOrigin::signed(Admin::get())));
+
+		assert_noop!(
+			Allocations::batch(RuntimeOrigin::signed(Oracle::get()), bounded_vec![(Grantee::get(), 50)]),
+			Errors::MintingPaused
+		);
+		assert_noop!(
+			Allocations::batch_best_effort(
+				RuntimeOrigin::signed(Oracle::get()),
+				bounded_vec![(Grantee::get(), 50)],
+				None
+			),
+			Errors::MintingPaused
+		);
+
+		let renew_schedule = Allocations::quota_renew_schedule();
+		System::set_block_number(renew_schedule);
+		Allocations::checked_update_session_quota();
+		assert_eq!(Allocations::session_quota(), session_quota);
+		assert_eq!(Allocations::quota_renew_schedule(), renew_schedule);
+
+		// the overdue renewal happens as soon as minting is unpaused
+		assert_ok!(Allocations::unpause(RuntimeOrigin::signed(Admin::get())));
+		Allocations::checked_update_session_quota();
+		assert_eq!(Allocations::session_quota(), Allocations::next_session_quota());
+		assert_ok!(Allocations::batch(
+			RuntimeOrigin::signed(Oracle::get()),
+			bounded_vec![(Grantee::get(), 50)]
+		));
+	})
+}
+
+#[test]
+fn oversized_batch_trips_auto_pause() {
+	new_test_ext().execute_with(|| {
+		AutoPauseThreshold::set(&Some(Perbill::from_percent(50)));
+		let total_issuance = 100000u64;
+		let _issuance = Balances::issue(total_issuance);
+		assert_ok!(Allocations::batch(
+			RuntimeOrigin::signed(Oracle::get()),
+			bounded_vec![(Grantee::get(), 100)]
+		));
+		assert!(!Allocations::paused());
+
+		let issuance_before = Balances::total_issuance();
+		let session_quota = Allocations::session_quota();
+		assert!(Allocations::next_session_quota() / 2 < 160);
+		assert_ok!(Allocations::batch(
+			RuntimeOrigin::signed(Oracle::get()),
+			bounded_vec![(Grantee::get(), 80), (OtherGrantee::get(), 80)]
+		));
+		assert!(Allocations::paused());
+		System::assert_last_event(RuntimeEvent::Allocations(crate::Event::MintingAutoPaused {
+			oracle: Oracle::get(),
+			total: 160,
+		}));
+		assert_eq!(Balances::total_issuance(), issuance_before);
+		assert_eq!(Allocations::session_quota(), session_quota);
+		assert_noop!(
+			Allocations::batch(RuntimeOrigin::signed(Oracle::get()), bounded_vec![(Grantee::get(), 50)]),
+			Errors::MintingPaused
+		);
+	})
+}
+
+#[test]
+fn auto_pause_compares_against_the_current_session_quota() {
+	new_test_ext().execute_with(|| {
+		AutoPauseThreshold::set(&Some(Perbill::from_percent(50)));
+		let total_issuance = 100000u64;
+		let _issuance = Balances::issue(total_issuance);
+		assert_ok!(Allocations::batch(
+			RuntimeOrigin::signed(Oracle::get()),
+			bounded_vec![(Grantee::get(), 10)]
+		));
+		let current_quota = Allocations::renewed_session_quota_total();
+		assert!(current_quota > 0);
+
+		// a zero quota for the next session does not affect the current one
+		<NextSessionQuota<Test>>::put(0);
+		assert_ok!(Allocations::batch(
+			RuntimeOrigin::signed(Oracle::get()),
+			bounded_vec![(Grantee::get(), current_quota / 2)]
+		));
+		assert!(!Allocations::paused());
+	})
+}
+
 #[test]
 fn test_releases_default() {
 	assert_eq!(Releases::default(), Releases::V0);
 }
 
+#[test]
+fn allocation_to_bridged_address_burns_the_grantee_share() {
+	new_test_ext().execute_with(|| {
+		let total_issuance = 100000u64;
+		let _issuance = Balances::issue(total_issuance);
+		let session_quota = Allocations::session_quota();
+		let address = [7u8; 20];
+		assert_ok!(Vesting::set_bridge(RuntimeOrigin::root(), 1, b"ethereum".to_vec(), 1));
+		System::reset_events();
+		assert_ok!(Allocations::batch_with_bridges(
+			RuntimeOrigin::signed(Oracle::get()),
+			bounded_vec![
+				(AllocationTarget::Native(Grantee::get()), 50),
+				(AllocationTarget::Bridged { bridge_id: 1, address }, 50)
+			]
+		));
+		assert_eq!(Allocations::session_quota(), session_quota - 100);
+		assert_eq!(Balances::free_balance(Grantee::get()), 45);
+		assert_eq!(Balances::free_balance(Receiver::get()), 10);
+		assert_eq!(Balances::total_issuance(), total_issuance + 55);
+		System::assert_has_event(RuntimeEvent::Vesting(pallet_grants::Event::BridgeInitiated {
+			to: address,
+			bridge_id: 1,
+			nonce: 0,
+			amount: 45,
+			grants: BoundedVec::default(),
+		}));
+		System::assert_has_event(RuntimeEvent::Allocations(crate::Event::AllocatedToBridge {
+			bridge_id: 1,
+			address,
+			amount: 45,
+		}));
+		System::assert_last_event(RuntimeEvent::Allocations(crate::Event::BatchAllocated {
+			oracle: Oracle::get(),
+			total: 100,
+			protocol_fee: 10,
+			count: 2,
+		}));
+
+		let alloc_account_id: u64 = AllocPalletId::get().into_account_truncating();
+		assert_eq!(Balances::free_balance(alloc_account_id), 0);
+	})
+}
+
+#[test]
+fn allocation_to_unknown_bridge_fails() {
+	new_test_ext().execute_with(|| {
+		let total_issuance = 100000u64;
+		let _issuance = Balances::issue(total_issuance);
+		let session_quota = Allocations::session_quota();
+		assert_noop!(
+			Allocations::batch_with_bridges(
+				RuntimeOrigin::signed(Oracle::get()),
+				bounded_vec![(
+					AllocationTarget::Bridged {
+						bridge_id: 1,
+						address: [7u8; 20]
+					},
+					50
+				)]
+			),
+			pallet_grants::Error::<Test>::BridgeNotFound
+		);
+		assert_eq!(Allocations::session_quota(), session_quota);
+		assert_eq!(Balances::total_issuance(), total_issuance);
+	})
+}
+
 #[test]
 fn check_weight_info() {
 	use crate::weights::SubstrateWeight;
@@ -725,9 +1554,36 @@ fn check_weight_info() {
 	assert_ne!(<()>::allocate(100), Weight::from_parts(0, 0));
 	assert_ne!(SubstrateWeight::<Test>::allocate(100), Weight::from_parts(0, 0));
 
+	assert_ne!(<()>::allocate_vested(100), Weight::from_parts(0, 0));
+	assert_ne!(SubstrateWeight::<Test>::allocate_vested(100), Weight::from_parts(0, 0));
+
+	assert_ne!(<()>::allocate_bridged(100), Weight::from_parts(0, 0));
+	assert_ne!(SubstrateWeight::<Test>::allocate_bridged(100), Weight::from_parts(0, 0));
+
 	assert_ne!(<()>::calc_quota(), Weight::from_parts(0, 0));
 	assert_ne!(SubstrateWeight::<Test>::calc_quota(), Weight::from_parts(0, 0));
 
 	assert_ne!(<()>::renew_quota(), Weight::from_parts(0, 0));
 	assert_ne!(SubstrateWeight::<Test>::renew_quota(), Weight::from_parts(0, 0));
+
+	assert_ne!(<()>::set_protocol_fee_split(4), Weight::from_parts(0, 0));
+	assert_ne!(
+		SubstrateWeight::<Test>::set_protocol_fee_split(4),
+		Weight::from_parts(0, 0)
+	);
+
+	assert_ne!(<()>::set_oracle_quota(), Weight::from_parts(0, 0));
+	assert_ne!(SubstrateWeight::<Test>::set_oracle_quota(), Weight::from_parts(0, 0));
+
+	assert_ne!(<()>::suspend_oracle(), Weight::from_parts(0, 0));
+	assert_ne!(SubstrateWeight::<Test>::suspend_oracle(), Weight::from_parts(0, 0));
+
+	assert_ne!(<()>::resume_oracle(), Weight::from_parts(0, 0));
+	assert_ne!(SubstrateWeight::<Test>::resume_oracle(), Weight::from_parts(0, 0));
+
+	assert_ne!(<()>::pause(), Weight::from_parts(0, 0));
+	assert_ne!(SubstrateWeight::<Test>::pause(), Weight::from_parts(0, 0));
+
+	assert_ne!(<()>::unpause(), Weight::from_parts(0, 0));
+	assert_ne!(SubstrateWeight::<Test>::unpause(), Weight::from_parts(0, 0));
 }
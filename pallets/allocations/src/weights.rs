@@ -16,12 +16,12 @@
  * along with this program.  If not, see <http://www.gnu.org/licenses/>.
  */
 
-//! Autogenerated weights for pallet_allocations
+//! Weights for pallet_allocations
 //!
-//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 32.0.0
-//! DATE: 2024-02-20, STEPS: `50`, REPEAT: 20, LOW RANGE: `[]`, HIGH RANGE: `[]`
-//! HOSTNAME: `chain-bench-a18ada46`, CPU: `AMD EPYC 7B13`
-//! EXECUTION: , WASM-EXECUTION: Compiled, CHAIN: Some("dev"), DB CACHE: 1024
+//! Generated with the Substrate benchmark CLI version 32.0.0 on 2024-02-20 (STEPS: `50`,
+//! REPEAT: 20, CPU: `AMD EPYC 7B13`). The functions marked `TODO(benchmarks)` were added or
+//! changed since and hold conservative estimates until the file is regenerated with the
+//! command below.
 
 // Executed Command:
 // ./target/release/nodle-parachain
@@ -44,10 +44,19 @@ use frame_support::{
 /// Weight functions needed for pallet_allocations.
 pub trait WeightInfo {
 	fn allocate(b: u32) -> Weight;
+	// TODO(benchmarks): conservative estimate, not measured yet.
+	fn allocate_vested(b: u32) -> Weight;
+	fn allocate_bridged(b: u32) -> Weight;
 	fn calc_quota() -> Weight;
 	fn renew_quota() -> Weight;
 	fn checked_update_session_quota() -> Weight;
 	fn set_curve_starting_block() -> Weight;
+	fn set_protocol_fee_split(n: u32) -> Weight;
+	fn set_oracle_quota() -> Weight;
+	fn suspend_oracle() -> Weight;
+	fn resume_oracle() -> Weight;
+	fn pause() -> Weight;
+	fn unpause() -> Weight;
 }
 
 /// Weight functions for `pallet_allocations`.
@@ -57,17 +66,68 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	// Proof: `Allocations::SessionQuota` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
 	// Storage: `System::Account` (r:502 w:502)
 	// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	// Storage: `Allocations::OracleQuota` (r:1 w:0)
+	// Proof: `Allocations::OracleQuota` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	// Storage: `Allocations::OracleSessionAllocated` (r:1 w:1)
+	// Proof: `Allocations::OracleSessionAllocated` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	// Storage: `Allocations::SessionQuotaRenewSchedule` (r:1 w:0)
+	// Proof: `Allocations::SessionQuotaRenewSchedule` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	// Storage: `Allocations::OracleMinted` (r:1 w:1)
+	// Proof: `Allocations::OracleMinted` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	// Storage: `Allocations::Paused` (r:1 w:0)
+	// Proof: `Allocations::Paused` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
 	/// The range of component `b` is `[1, 500]`.
 	fn allocate(b: u32) -> Weight {
-		// Minimum execution time: 129_400 nanoseconds.
 		Weight::from_parts(47_424_668_u64, 0)
-			// Standard Error: 18_955
 			.saturating_add(Weight::from_parts(48_861_326_u64, 0).saturating_mul(b as u64))
-			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().reads(8_u64))
 			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(b as u64)))
-			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(b as u64)))
+	}
+	// Storage: `Vesting::Bridges` (r:500 w:0)
+	// Proof: `Vesting::Bridges` (`max_values`: None, `max_size`: Some(77), added: 2552, mode: `MaxEncodedLen`)
+	fn allocate_bridged(b: u32) -> Weight {
+		// TODO(benchmarks): conservative estimate, not measured yet.
+		Weight::from_parts(46_918_204_u64, 0)
+			.saturating_add(Weight::from_parts(51_302_117_u64, 0).saturating_mul(b as u64))
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(b as u64)))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
 			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(b as u64)))
 	}
+	// Storage: `Allocations::SessionQuota` (r:1 w:1)
+	// Proof: `Allocations::SessionQuota` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	// Storage: `ParachainSystem::ValidationData` (r:1 w:0)
+	// Proof: `ParachainSystem::ValidationData` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	// Storage: `Vesting::VestingSchedules` (r:500 w:500)
+	// Proof: `Vesting::VestingSchedules` (`max_values`: None, `max_size`: Some(3250), added: 5725, mode: `MaxEncodedLen`)
+	// Storage: `System::Account` (r:502 w:502)
+	// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	// Storage: `Balances::Locks` (r:500 w:0)
+	// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	// Storage: `Balances::Freezes` (r:500 w:500)
+	// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	// Storage: `Allocations::OracleQuota` (r:1 w:0)
+	// Proof: `Allocations::OracleQuota` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	// Storage: `Allocations::OracleSessionAllocated` (r:1 w:1)
+	// Proof: `Allocations::OracleSessionAllocated` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	// Storage: `Allocations::SessionQuotaRenewSchedule` (r:1 w:0)
+	// Proof: `Allocations::SessionQuotaRenewSchedule` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	// Storage: `Allocations::OracleMinted` (r:1 w:1)
+	// Proof: `Allocations::OracleMinted` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	// Storage: `Allocations::Paused` (r:1 w:0)
+	// Proof: `Allocations::Paused` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// The range of component `b` is `[1, 500]`.
+	fn allocate_vested(b: u32) -> Weight {
+		// TODO(benchmarks): conservative estimate, not measured yet.
+		Weight::from_parts(117_352_410_u64, 0)
+			.saturating_add(Weight::from_parts(139_204_572_u64, 0).saturating_mul(b as u64))
+			.saturating_add(T::DbWeight::get().reads(9_u64))
+			.saturating_add(T::DbWeight::get().reads((4_u64).saturating_mul(b as u64)))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+			.saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(b as u64)))
+	}
 	// Storage: `Allocations::SessionQuotaCalculationSchedule` (r:1 w:1)
 	// Proof: `Allocations::SessionQuotaCalculationSchedule` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	// Storage: `Allocations::MintCurveStartingBlock` (r:1 w:1)
@@ -86,13 +146,17 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	// Proof: `Allocations::MintCurveStartingBlock` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	// Storage: `Allocations::NextSessionQuota` (r:1 w:0)
 	// Proof: `Allocations::NextSessionQuota` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
-	// Storage: `Allocations::SessionQuota` (r:0 w:1)
+	// Storage: `Allocations::SessionQuota` (r:1 w:1)
 	// Proof: `Allocations::SessionQuota` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	// Storage: `Balances::TotalIssuance` (r:1 w:0)
+	// Proof: `Balances::TotalIssuance` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	// Storage: `Allocations::RenewedSessionQuota` (r:0 w:1)
+	// Proof: `Allocations::RenewedSessionQuota` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
 	fn renew_quota() -> Weight {
-		// Minimum execution time: 7_420 nanoseconds.
+		// TODO(benchmarks): conservative estimate, not measured yet.
 		Weight::from_parts(7_760_000_u64, 0)
-			.saturating_add(T::DbWeight::get().reads(3_u64))
-			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
 	}
 	// Storage: `ParachainSystem::ValidationData` (r:1 w:0)
 	// Proof: `ParachainSystem::ValidationData` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
@@ -126,6 +190,51 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
+	// Storage: `Allocations::ProtocolFeeSplit` (r:0 w:1)
+	// Proof: `Allocations::ProtocolFeeSplit` (`max_values`: Some(1), `max_size`: Some(133), added: 628, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[0, 4]`.
+	fn set_protocol_fee_split(n: u32) -> Weight {
+		// TODO(benchmarks): conservative estimate, not measured yet.
+		Weight::from_parts(8_743_112_u64, 0)
+			.saturating_add(Weight::from_parts(412_530_u64, 0).saturating_mul(n as u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	// Storage: `Allocations::OracleQuota` (r:0 w:1)
+	// Proof: `Allocations::OracleQuota` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	fn set_oracle_quota() -> Weight {
+		// TODO(benchmarks): conservative estimate, not measured yet.
+		Weight::from_parts(9_480_000_u64, 0).saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	// Storage: `Allocations::SuspendedOracles` (r:0 w:1)
+	// Proof: `Allocations::SuspendedOracles` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	fn suspend_oracle() -> Weight {
+		// TODO(benchmarks): conservative estimate, not measured yet.
+		Weight::from_parts(9_010_000_u64, 0).saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	// Storage: `Allocations::SuspendedOracles` (r:1 w:1)
+	// Proof: `Allocations::SuspendedOracles` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	fn resume_oracle() -> Weight {
+		// TODO(benchmarks): conservative estimate, not measured yet.
+		Weight::from_parts(12_270_000_u64, 0)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	// Storage: `Allocations::Paused` (r:1 w:1)
+	// Proof: `Allocations::Paused` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	fn pause() -> Weight {
+		// TODO(benchmarks): conservative estimate, not measured yet.
+		Weight::from_parts(8_310_000_u64, 0)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	// Storage: `Allocations::Paused` (r:1 w:1)
+	// Proof: `Allocations::Paused` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	fn unpause() -> Weight {
+		// TODO(benchmarks): conservative estimate, not measured yet.
+		Weight::from_parts(8_400_000_u64, 0)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
 }
 
 impl WeightInfo for () {
@@ -133,17 +242,69 @@ impl WeightInfo for () {
 	// Proof: `Allocations::SessionQuota` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
 	// Storage: `System::Account` (r:502 w:502)
 	// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	// Storage: `Allocations::OracleQuota` (r:1 w:0)
+	// Proof: `Allocations::OracleQuota` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	// Storage: `Allocations::OracleSessionAllocated` (r:1 w:1)
+	// Proof: `Allocations::OracleSessionAllocated` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	// Storage: `Allocations::SessionQuotaRenewSchedule` (r:1 w:0)
+	// Proof: `Allocations::SessionQuotaRenewSchedule` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	// Storage: `Allocations::OracleMinted` (r:1 w:1)
+	// Proof: `Allocations::OracleMinted` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	// Storage: `Allocations::Paused` (r:1 w:0)
+	// Proof: `Allocations::Paused` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
 	/// The range of component `b` is `[1, 500]`.
 	fn allocate(b: u32) -> Weight {
-		// Minimum execution time: 129_400 nanoseconds.
+		// TODO(benchmarks): conservative estimate, not measured yet.
 		Weight::from_parts(47_424_668_u64, 0)
-			// Standard Error: 18_955
 			.saturating_add(Weight::from_parts(48_861_326_u64, 0).saturating_mul(b as u64))
-			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
 			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(b as u64)))
-			.saturating_add(RocksDbWeight::get().writes(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(b as u64)))
+	}
+	// Storage: `Vesting::Bridges` (r:500 w:0)
+	// Proof: `Vesting::Bridges` (`max_values`: None, `max_size`: Some(77), added: 2552, mode: `MaxEncodedLen`)
+	fn allocate_bridged(b: u32) -> Weight {
+		// TODO(benchmarks): conservative estimate, not measured yet.
+		Weight::from_parts(46_918_204_u64, 0)
+			.saturating_add(Weight::from_parts(51_302_117_u64, 0).saturating_mul(b as u64))
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(b as u64)))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
 			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(b as u64)))
 	}
+	// Storage: `Allocations::SessionQuota` (r:1 w:1)
+	// Proof: `Allocations::SessionQuota` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	// Storage: `ParachainSystem::ValidationData` (r:1 w:0)
+	// Proof: `ParachainSystem::ValidationData` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	// Storage: `Vesting::VestingSchedules` (r:500 w:500)
+	// Proof: `Vesting::VestingSchedules` (`max_values`: None, `max_size`: Some(3250), added: 5725, mode: `MaxEncodedLen`)
+	// Storage: `System::Account` (r:502 w:502)
+	// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	// Storage: `Balances::Locks` (r:500 w:0)
+	// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	// Storage: `Balances::Freezes` (r:500 w:500)
+	// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	// Storage: `Allocations::OracleQuota` (r:1 w:0)
+	// Proof: `Allocations::OracleQuota` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	// Storage: `Allocations::OracleSessionAllocated` (r:1 w:1)
+	// Proof: `Allocations::OracleSessionAllocated` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	// Storage: `Allocations::SessionQuotaRenewSchedule` (r:1 w:0)
+	// Proof: `Allocations::SessionQuotaRenewSchedule` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	// Storage: `Allocations::OracleMinted` (r:1 w:1)
+	// Proof: `Allocations::OracleMinted` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	// Storage: `Allocations::Paused` (r:1 w:0)
+	// Proof: `Allocations::Paused` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// The range of component `b` is `[1, 500]`.
+	fn allocate_vested(b: u32) -> Weight {
+		// TODO(benchmarks): conservative estimate, not measured yet.
+		Weight::from_parts(117_352_410_u64, 0)
+			.saturating_add(Weight::from_parts(139_204_572_u64, 0).saturating_mul(b as u64))
+			.saturating_add(RocksDbWeight::get().reads(9_u64))
+			.saturating_add(RocksDbWeight::get().reads((4_u64).saturating_mul(b as u64)))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+			.saturating_add(RocksDbWeight::get().writes((3_u64).saturating_mul(b as u64)))
+	}
 	// Storage: `Allocations::SessionQuotaCalculationSchedule` (r:1 w:1)
 	// Proof: `Allocations::SessionQuotaCalculationSchedule` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	// Storage: `Allocations::MintCurveStartingBlock` (r:1 w:1)
@@ -162,13 +323,17 @@ impl WeightInfo for () {
 	// Proof: `Allocations::MintCurveStartingBlock` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	// Storage: `Allocations::NextSessionQuota` (r:1 w:0)
 	// Proof: `Allocations::NextSessionQuota` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
-	// Storage: `Allocations::SessionQuota` (r:0 w:1)
+	// Storage: `Allocations::SessionQuota` (r:1 w:1)
 	// Proof: `Allocations::SessionQuota` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	// Storage: `Balances::TotalIssuance` (r:1 w:0)
+	// Proof: `Balances::TotalIssuance` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	// Storage: `Allocations::RenewedSessionQuota` (r:0 w:1)
+	// Proof: `Allocations::RenewedSessionQuota` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
 	fn renew_quota() -> Weight {
-		// Minimum execution time: 7_420 nanoseconds.
+		// TODO(benchmarks): conservative estimate, not measured yet.
 		Weight::from_parts(7_760_000_u64, 0)
-			.saturating_add(RocksDbWeight::get().reads(3_u64))
-			.saturating_add(RocksDbWeight::get().writes(3_u64))
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
 	}
 	// Storage: `ParachainSystem::ValidationData` (r:1 w:0)
 	// Proof: `ParachainSystem::ValidationData` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
@@ -202,4 +367,49 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
 			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
+	// Storage: `Allocations::ProtocolFeeSplit` (r:0 w:1)
+	// Proof: `Allocations::ProtocolFeeSplit` (`max_values`: Some(1), `max_size`: Some(133), added: 628, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[0, 4]`.
+	fn set_protocol_fee_split(n: u32) -> Weight {
+		// TODO(benchmarks): conservative estimate, not measured yet.
+		Weight::from_parts(8_743_112_u64, 0)
+			.saturating_add(Weight::from_parts(412_530_u64, 0).saturating_mul(n as u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	// Storage: `Allocations::OracleQuota` (r:0 w:1)
+	// Proof: `Allocations::OracleQuota` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	fn set_oracle_quota() -> Weight {
+		// TODO(benchmarks): conservative estimate, not measured yet.
+		Weight::from_parts(9_480_000_u64, 0).saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	// Storage: `Allocations::SuspendedOracles` (r:0 w:1)
+	// Proof: `Allocations::SuspendedOracles` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	fn suspend_oracle() -> Weight {
+		// TODO(benchmarks): conservative estimate, not measured yet.
+		Weight::from_parts(9_010_000_u64, 0).saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	// Storage: `Allocations::SuspendedOracles` (r:1 w:1)
+	// Proof: `Allocations::SuspendedOracles` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	fn resume_oracle() -> Weight {
+		// TODO(benchmarks): conservative estimate, not measured yet.
+		Weight::from_parts(12_270_000_u64, 0)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	// Storage: `Allocations::Paused` (r:1 w:1)
+	// Proof: `Allocations::Paused` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	fn pause() -> Weight {
+		// TODO(benchmarks): conservative estimate, not measured yet.
+		Weight::from_parts(8_310_000_u64, 0)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	// Storage: `Allocations::Paused` (r:1 w:1)
+	// Proof: `Allocations::Paused` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	fn unpause() -> Weight {
+		// TODO(benchmarks): conservative estimate, not measured yet.
+		Weight::from_parts(8_400_000_u64, 0)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
 }
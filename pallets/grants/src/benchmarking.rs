@@ -31,6 +31,13 @@ use sp_std::prelude::*;
 
 const SEED: u32 = 0;
 
+fn bridge_oracles<T: Config>() -> BoundedVec<T::AccountId, T::MaxBridgeOracles> {
+	let oracles = (0..T::MaxBridgeOracles::get())
+		.map(|i| account("oracle", i, SEED))
+		.collect::<Vec<_>>();
+	BoundedVec::truncate_from(oracles)
+}
+
 struct BenchmarkConfig<T: Config> {
 	granter: T::AccountId,
 	grantee: T::AccountId,
@@ -46,13 +53,15 @@ fn create_shared_config<T: Config>(u: u32) -> BenchmarkConfig<T> {
 	let grantee_lookup: <T::Lookup as StaticLookup>::Source = T::Lookup::unlookup(grantee.clone());
 	let collector_lookup: <T::Lookup as StaticLookup>::Source = T::Lookup::unlookup(collector);
 
-	T::Currency::make_free_balance_be(&granter, BalanceOf::<T>::max_value());
+	// leave some room in the total issuance for the funds minted back by refunds
+	T::Currency::set_balance(&granter, BalanceOf::<T>::max_value() / 2u32.into());
 
 	let schedule = VestingSchedule {
 		start: 0u32.into(),
 		period: 10u32.into(),
 		period_count: 2u32,
 		per_period: T::Currency::minimum_balance(),
+		cliff: 0u32.into(),
 	};
 
 	BenchmarkConfig {
@@ -64,6 +73,22 @@ fn create_shared_config<T: Config>(u: u32) -> BenchmarkConfig<T> {
 	}
 }
 
+// Fill the schedules of the grantee with fully vested ones which are pruned on claim, each
+// revocable by a different funder.
+fn add_vested_revocable_schedules<T: Config>(config: &BenchmarkConfig<T>) -> Result<(), BenchmarkError> {
+	let vested = VestingSchedule {
+		per_period: Zero::zero(),
+		..config.schedule.clone()
+	};
+	for i in 1..T::MaxSchedule::get() {
+		let funder: T::AccountId = account("funder", i, SEED);
+		VestingSchedules::<T>::try_mutate(&config.grantee, |schedules| schedules.try_push(vested.clone()))
+			.map_err(|_| BenchmarkError::Stop("too many vesting schedules"))?;
+		RevocableSchedules::<T>::insert(&config.grantee, funder, BoundedVec::truncate_from(vec![vested.clone()]));
+	}
+	Ok(())
+}
+
 benchmarks! {
 	add_vesting_schedule {
 		let config = create_shared_config::<T>(1);
@@ -77,12 +102,32 @@ benchmarks! {
 	claim {
 		let config = create_shared_config::<T>(1);
 		Pallet::<T>::do_add_vesting_schedule(&config.granter, &config.grantee, config.schedule.clone())?;
+		add_vested_revocable_schedules::<T>(&config)?;
+	}: _(RawOrigin::Signed(config.grantee))
 
-		// Add some existing schedules according to b
+	add_timed_vesting_schedule {
+		let config = create_shared_config::<T>(1);
+		let schedule = VestingSchedule {
+			start: 0u32.into(),
+			period: 1_000u32.into(),
+			period_count: 2u32,
+			per_period: T::Currency::minimum_balance(),
+			cliff: 0u32.into(),
+		};
 		for _x in 1 .. T::MaxSchedule::get() {
-			Pallet::<T>::do_add_vesting_schedule(&config.granter, &config.grantee, config.schedule.clone())?;
+			Pallet::<T>::add_timed_vesting_schedule(
+				RawOrigin::Signed(config.granter.clone()).into(),
+				config.grantee_lookup.clone(),
+				schedule.clone(),
+			)?;
 		}
-	}: _(RawOrigin::Signed(config.grantee))
+	}: _(RawOrigin::Signed(config.granter), config.grantee_lookup, schedule)
+
+	claim_for {
+		let config = create_shared_config::<T>(1);
+		Pallet::<T>::do_add_vesting_schedule(&config.granter, &config.grantee, config.schedule.clone())?;
+		add_vested_revocable_schedules::<T>(&config)?;
+	}: _(RawOrigin::Signed(config.granter), config.grantee_lookup)
 
 	cancel_all_vesting_schedules {
 	   let config = create_shared_config::<T>(1);
@@ -99,6 +144,125 @@ benchmarks! {
 		let origin = T::CancelOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
 	}: { call.dispatch_bypass_filter(origin)? }
 
+	cancel_vesting_schedule {
+		let config = create_shared_config::<T>(1);
+
+		for _x in 1 .. T::MaxSchedule::get() {
+			Pallet::<T>::do_add_vesting_schedule(&config.granter, &config.grantee, config.schedule.clone())?;
+		}
+
+		let call = Call::<T>::cancel_vesting_schedule{
+			who: config.grantee_lookup,
+			funds_collector: config.collector_lookup,
+			schedule_index: 0,
+			schedule: config.schedule,
+		};
+		let origin = T::CancelOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+	}: { call.dispatch_bypass_filter(origin)? }
+
+	cancel_timed_vesting_schedule {
+		let config = create_shared_config::<T>(1);
+		let schedule = VestingSchedule {
+			start: 0u32.into(),
+			period: 1_000u32.into(),
+			period_count: 2u32,
+			per_period: T::Currency::minimum_balance(),
+			cliff: 0u32.into(),
+		};
+		for _x in 0 .. T::MaxSchedule::get() {
+			Pallet::<T>::add_timed_vesting_schedule(
+				RawOrigin::Signed(config.granter.clone()).into(),
+				config.grantee_lookup.clone(),
+				schedule.clone(),
+			)?;
+		}
+
+		let call = Call::<T>::cancel_timed_vesting_schedule{
+			who: config.grantee_lookup,
+			funds_collector: config.collector_lookup,
+			schedule_index: 0,
+			schedule,
+		};
+		let origin = T::CancelOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+	}: { call.dispatch_bypass_filter(origin)? }
+
+	add_revocable_vesting_schedule {
+		let config = create_shared_config::<T>(1);
+
+		for _x in 1 .. T::MaxSchedule::get() {
+			Pallet::<T>::add_revocable_vesting_schedule(
+				RawOrigin::Signed(config.granter.clone()).into(),
+				config.grantee_lookup.clone(),
+				config.schedule.clone()
+			)?;
+		}
+	}: _(RawOrigin::Signed(config.granter.clone()), config.grantee_lookup.clone(), config.schedule.clone())
+
+	revoke_vesting_schedule {
+		let config = create_shared_config::<T>(1);
+
+		for _x in 0 .. T::MaxSchedule::get() {
+			Pallet::<T>::add_revocable_vesting_schedule(
+				RawOrigin::Signed(config.granter.clone()).into(),
+				config.grantee_lookup.clone(),
+				config.schedule.clone()
+			)?;
+		}
+	}: _(RawOrigin::Signed(config.granter.clone()), config.grantee_lookup.clone(), 0, config.schedule.clone())
+
+	compact {
+		let config = create_shared_config::<T>(1);
+
+		for x in 0 .. T::MaxSchedule::get() {
+			let schedule = VestingSchedule {
+				start: (x % 2).into(),
+				..config.schedule.clone()
+			};
+			Pallet::<T>::do_add_vesting_schedule(&config.granter, &config.grantee, schedule)?;
+		}
+	}: _(RawOrigin::Signed(config.granter.clone()), config.grantee_lookup.clone(), true)
+
+	batch_add_vesting_schedules {
+		let n in 1 .. T::MaxBatchSchedules::get();
+		let config = create_shared_config::<T>(1);
+
+		let batch: Vec<_> = (0..n)
+			.map(|i| {
+				let grantee: T::AccountId = account("grantee", i, SEED);
+				(T::Lookup::unlookup(grantee), config.schedule.clone())
+			})
+			.collect();
+		let batch = BoundedVec::truncate_from(batch);
+	}: _(RawOrigin::Signed(config.granter.clone()), batch)
+
+	transfer_vesting_schedules {
+		let config = create_shared_config::<T>(1);
+		let dest: T::AccountId = account("dest", 1, SEED);
+
+		for _x in 0 .. T::MaxSchedule::get() {
+			Pallet::<T>::add_revocable_vesting_schedule(
+				RawOrigin::Signed(config.granter.clone()).into(),
+				config.grantee_lookup.clone(),
+				config.schedule.clone()
+			)?;
+		}
+		// moving all the schedules takes the timed ones along
+		let timed_schedule = VestingSchedule {
+			start: 0u32.into(),
+			period: 1_000u32.into(),
+			period_count: 2u32,
+			per_period: T::Currency::minimum_balance(),
+			cliff: 0u32.into(),
+		};
+		for _x in 0 .. T::MaxSchedule::get() {
+			Pallet::<T>::add_timed_vesting_schedule(
+				RawOrigin::Signed(config.granter.clone()).into(),
+				config.grantee_lookup.clone(),
+				timed_schedule.clone(),
+			)?;
+		}
+	}: _(RawOrigin::Signed(config.grantee.clone()), T::Lookup::unlookup(dest), None)
+
 	bridge_all_vesting_schedules {
 		let config = create_shared_config::<T>(1);
 		let bridge_name = b"zklocal";
@@ -112,6 +276,59 @@ benchmarks! {
 
 	 }: _(RawOrigin::Signed(config.grantee.clone()), hex!("2E7F3926Ae74FDCDcAde2c2AB50990C5daFD42bD"), bridge_id)
 
+	bridge_vesting_schedules {
+		let config = create_shared_config::<T>(1);
+		let bridge_id = 1;
+		Pallet::<T>::set_bridge(RawOrigin::Root.into(), bridge_id, b"zklocal".to_vec(), 9924)?;
+		for _x in 0 .. T::MaxSchedule::get() {
+			Pallet::<T>::do_add_vesting_schedule(&config.granter, &config.grantee, config.schedule.clone())?;
+		}
+		let amount = Pallet::<T>::locked_balance(&config.grantee);
+	}: _(
+		RawOrigin::Signed(config.grantee.clone()),
+		hex!("2E7F3926Ae74FDCDcAde2c2AB50990C5daFD42bD"),
+		bridge_id,
+		BridgeSelection::Amount(amount)
+	)
+
+	confirm_bridge_transfer {
+		let config = create_shared_config::<T>(1);
+		let bridge_id = 1;
+		Pallet::<T>::set_bridge(RawOrigin::Root.into(), bridge_id, b"zklocal".to_vec(), 9924)?;
+		Pallet::<T>::record_bridge_transfer(
+			config.granter,
+			hex!("2E7F3926Ae74FDCDcAde2c2AB50990C5daFD42bD"),
+			bridge_id,
+			T::Currency::minimum_balance(),
+			BoundedVec::default(),
+		);
+
+		let oracles = bridge_oracles::<T>();
+		let oracle = oracles.last().cloned().ok_or(BenchmarkError::Weightless)?;
+		Pallet::<T>::update_bridge(
+			RawOrigin::Root.into(),
+			bridge_id,
+			BridgeUpdate { oracles: Some(oracles), ..Default::default() },
+		)?;
+	}: _(RawOrigin::Signed(oracle), bridge_id, 0)
+
+	refund_bridge_transfer {
+		let config = create_shared_config::<T>(1);
+		let bridge_id = 1;
+		Pallet::<T>::set_bridge(RawOrigin::Root.into(), bridge_id, b"zklocal".to_vec(), 9924)?;
+		for _x in 1 .. T::MaxSchedule::get() {
+			Pallet::<T>::do_add_vesting_schedule(&config.granter, &config.grantee, config.schedule.clone())?;
+		}
+		Pallet::<T>::bridge_all_vesting_schedules(
+			RawOrigin::Signed(config.grantee.clone()).into(),
+			hex!("2E7F3926Ae74FDCDcAde2c2AB50990C5daFD42bD"),
+			bridge_id,
+		)?;
+
+		let call = Call::<T>::refund_bridge_transfer { bridge_id, nonce: 0 };
+		let origin = T::CancelOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+	}: { call.dispatch_bypass_filter(origin)? }
+
 	 set_bridge {
 		let bridge_name = b"bridge_between_eden_zks_main_era";
 		let bridge_id = 1;
@@ -125,6 +342,19 @@ benchmarks! {
 		Pallet::<T>::set_bridge(RawOrigin::Root.into(), bridge_id, bridge_name.to_vec(), remote_chain_id)?;
 	}: _(RawOrigin::Root, bridge_id)
 
+	update_bridge {
+		let bridge_id = 1;
+		Pallet::<T>::set_bridge(RawOrigin::Root.into(), bridge_id, b"zklocal".to_vec(), 9924)?;
+		let update = BridgeUpdate {
+			name: Some(b"bridge_between_eden_zks_main_era".to_vec()),
+			chain_id: Some(300),
+			status: Some(BridgeStatus::Paused),
+			oracles: Some(bridge_oracles::<T>()),
+			max_per_transfer: Some(Some(BalanceOf::<T>::max_value())),
+			max_per_period: Some(Some(PeriodCap { period: 100u32.into(), amount: BalanceOf::<T>::max_value() })),
+		};
+	}: _(RawOrigin::Root, bridge_id, update)
+
 	 renounce {
 		let config = create_shared_config::<T>(1);
 		let call = Call::<T>::renounce{
@@ -19,6 +19,8 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
 mod benchmarking;
+pub mod migrations;
+pub mod runtime_api;
 
 #[cfg(test)]
 mod mock;
@@ -30,17 +32,25 @@ use codec::{Decode, Encode};
 use frame_support::{
 	ensure,
 	pallet_prelude::{MaxEncodedLen, TypeInfo},
-	traits::{Currency, ExistenceRequirement, LockIdentifier, LockableCurrency, WithdrawReasons},
-	BoundedVec,
+	storage::with_storage_layer,
+	traits::{
+		fungible,
+		tokens::{Fortitude, Precision, Preservation},
+		LockIdentifier, Time,
+	},
+	BoundedVec, CloneNoBound, DefaultNoBound, EqNoBound, PartialEqNoBound, RuntimeDebugNoBound,
 };
 use sp_runtime::{
-	traits::{AtLeast32Bit, BlockNumberProvider, CheckedAdd, ConstU32, Saturating, StaticLookup, Zero},
-	DispatchResult, RuntimeDebug,
+	helpers_128bit::multiply_by_rational_with_rounding,
+	traits::{AtLeast32Bit, BlockNumberProvider, CheckedAdd, ConstU32, One, Saturating, StaticLookup, Zero},
+	DispatchError, DispatchResult, Rounding, RuntimeDebug,
 };
 use sp_std::{
 	cmp::{Eq, PartialEq},
+	collections::{btree_map::BTreeMap, btree_set::BTreeSet},
 	vec::Vec,
 };
+use support::{BridgeTransfer, VestedTransfer};
 
 use frame_system::pallet_prelude::BlockNumberFor;
 
@@ -52,29 +62,51 @@ pub use pallet::*;
 // A value placed in storage that represents the current version of the Grants storage.
 // This value is used by the `on_runtime_upgrade` logic to determine whether we run storage
 // migration logic. This should match directly with the semantic versions of the Rust crate.
-#[derive(Encode, MaxEncodedLen, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo, Default)]
+#[derive(
+	Encode, MaxEncodedLen, Decode, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, RuntimeDebug, TypeInfo, Default,
+)]
 enum Releases {
 	#[default]
 	V0, // Legacy version
 	V1, // Adds storage info
+	V2, // Adds cliffs to vesting schedules
+	V3, // Adds status, oracles and caps to bridges
+	V4, // Moves the vesting lock to a freeze
 }
 
-pub type BalanceOf<T> = <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+pub type BalanceOf<T> = <<T as Config>::Currency as fungible::Inspect<<T as frame_system::Config>::AccountId>>::Balance;
 pub type VestingScheduleOf<T> = VestingSchedule<BlockNumberFor<T>, BalanceOf<T>>;
+pub type MomentOf<T> = <<T as Config>::Time as Time>::Moment;
+/// A vesting schedule whose `start`, `period` and `cliff` are in unix milliseconds.
+pub type TimedVestingScheduleOf<T> = VestingSchedule<MomentOf<T>, BalanceOf<T>>;
 pub type ListVestingScheduleOf<T> = Vec<VestingScheduleOf<T>>;
 pub type ScheduledGrant<T> = (BlockNumberFor<T>, BlockNumberFor<T>, u32, BalanceOf<T>);
 pub type ScheduledItem<T> = (<T as frame_system::Config>::AccountId, Vec<ScheduledGrant<T>>);
+/// A `(start, period, period_count, per_period, cliff)` genesis vesting schedule.
+pub type CliffedGrant<T> = (
+	BlockNumberFor<T>,
+	BlockNumberFor<T>,
+	u32,
+	BalanceOf<T>,
+	BlockNumberFor<T>,
+);
+pub type CliffedItem<T> = (<T as frame_system::Config>::AccountId, Vec<CliffedGrant<T>>);
+/// The `(bridge_id, name, remote_chain_id, oracles)` of a bridge registered at genesis.
+pub type GenesisBridge<T> = (u32, Vec<u8>, u64, Vec<<T as frame_system::Config>::AccountId>);
 
 /// The vesting schedule.
 ///
 /// Benefits would be granted gradually, `per_period` amount every `period` of blocks
-/// after `start`.
+/// after `start`. Nothing is granted before `start + cliff`, at which point all the periods
+/// elapsed so far are granted at once. A zero `cliff` means no cliff.
 #[derive(Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug, MaxEncodedLen, scale_info::TypeInfo)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
 pub struct VestingSchedule<BlockNumber, Balance> {
 	pub start: BlockNumber,
 	pub period: BlockNumber,
 	pub period_count: u32,
 	pub per_period: Balance,
+	pub cliff: BlockNumber,
 }
 
 const BRIDGE_NAME_MAX_LENGTH: u32 = 32;
@@ -83,19 +115,126 @@ const BRIDGE_NAME_MAX_LENGTH: u32 = 32;
 #[derive(Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug, MaxEncodedLen, scale_info::TypeInfo)]
 pub struct BridgeId(u32);
 
+/// Whether a bridge accepts new transfers.
+#[derive(Clone, Copy, Encode, Decode, PartialEq, Eq, RuntimeDebug, MaxEncodedLen, TypeInfo, Default)]
+pub enum BridgeStatus {
+	#[default]
+	Active,
+	Paused,
+}
+
+/// A limit on the total amount bridged over every `period` blocks.
+#[derive(Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct PeriodCap<BlockNumber, Balance> {
+	pub period: BlockNumber,
+	pub amount: Balance,
+}
+
+pub type PeriodCapOf<T> = PeriodCap<BlockNumberFor<T>, BalanceOf<T>>;
+
+/// The part of its vesting schedules an account bridges.
+#[derive(CloneNoBound, Encode, Decode, PartialEqNoBound, EqNoBound, RuntimeDebugNoBound, TypeInfo)]
+#[scale_info(skip_type_params(T))]
+pub enum BridgeSelection<T: Config> {
+	/// The schedules at the given indices, bridged whole. Each index comes with the schedule
+	/// expected there as indices shift whenever fully vested schedules are pruned.
+	Schedules(BoundedVec<(u32, VestingScheduleOf<T>), T::MaxSchedule>),
+	/// An amount taken from every schedule in proportion of its locked amount
+	Amount(BalanceOf<T>),
+}
+
 // Details of a bridge between parachain and a remote ethereum based chain/rollup.
-#[derive(Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug, MaxEncodedLen, scale_info::TypeInfo)]
-pub struct BridgeDetails {
+#[derive(CloneNoBound, Encode, Decode, PartialEqNoBound, EqNoBound, RuntimeDebugNoBound, MaxEncodedLen, TypeInfo)]
+#[scale_info(skip_type_params(T))]
+#[codec(mel_bound())]
+pub struct BridgeDetails<T: Config> {
 	chain_id: u64,
 	name: BoundedVec<u8, ConstU32<BRIDGE_NAME_MAX_LENGTH>>,
+	status: BridgeStatus,
+	// Accounts allowed to confirm the transfers of this bridge, besides `BridgeOracleOrigin`
+	oracles: BoundedVec<T::AccountId, T::MaxBridgeOracles>,
+	max_per_transfer: Option<BalanceOf<T>>,
+	max_per_period: Option<PeriodCapOf<T>>,
+}
+
+impl<T: Config> BridgeDetails<T> {
+	/// An active bridge with no oracles and no caps.
+	fn new(chain_id: u64, name: BoundedVec<u8, ConstU32<BRIDGE_NAME_MAX_LENGTH>>) -> Self {
+		Self {
+			chain_id,
+			name,
+			status: BridgeStatus::Active,
+			oracles: BoundedVec::default(),
+			max_per_transfer: None,
+			max_per_period: None,
+		}
+	}
+}
+
+/// The changes `update_bridge` applies to the details of a bridge, leaving unchanged the ones
+/// given as `None`.
+#[derive(CloneNoBound, DefaultNoBound, Encode, Decode, PartialEqNoBound, EqNoBound, RuntimeDebugNoBound, TypeInfo)]
+#[scale_info(skip_type_params(T))]
+pub struct BridgeUpdate<T: Config> {
+	pub name: Option<Vec<u8>>,
+	pub chain_id: Option<u64>,
+	pub status: Option<BridgeStatus>,
+	pub oracles: Option<BoundedVec<T::AccountId, T::MaxBridgeOracles>>,
+	pub max_per_transfer: Option<Option<BalanceOf<T>>>,
+	pub max_per_period: Option<Option<PeriodCapOf<T>>>,
+}
+
+/// A bridge transfer waiting for the bridge oracles to confirm that it was completed on the
+/// remote chain.
+#[derive(CloneNoBound, Encode, Decode, PartialEqNoBound, EqNoBound, RuntimeDebugNoBound, MaxEncodedLen, TypeInfo)]
+#[scale_info(skip_type_params(T))]
+#[codec(mel_bound())]
+pub struct PendingBridgeTransfer<T: Config> {
+	/// The account whose funds were burnt
+	pub source: T::AccountId,
+	/// The address credited on the remote chain
+	pub to: [u8; 20],
+	/// The amount burnt here and credited on the remote chain
+	pub amount: BalanceOf<T>,
+	/// The vesting schedules of `source` which were bridged, if any
+	pub grants: BoundedVec<VestingScheduleOf<T>, T::MaxSchedule>,
+	/// When the transfer was initiated
+	pub initiated_at: BlockNumberFor<T>,
 }
 
 impl<BlockNumber: AtLeast32Bit + Copy, Balance: AtLeast32Bit + Copy> VestingSchedule<BlockNumber, Balance> {
-	/// Returns the end of all periods, `None` if calculation overflows.
+	/// Returns the end of all periods, or of the cliff if it comes later, `None` if calculation
+	/// overflows.
 	pub fn end(&self) -> Option<BlockNumber> {
-		self.period
+		let periods_end = self
+			.period
 			.checked_mul(&self.period_count.into())?
-			.checked_add(&self.start)
+			.checked_add(&self.start)?;
+		Some(periods_end.max(self.cliff_end()?))
+	}
+
+	/// Returns the block before which nothing is granted, `None` if calculation overflows.
+	pub fn cliff_end(&self) -> Option<BlockNumber> {
+		self.start.checked_add(&self.cliff)
+	}
+
+	/// Returns the first block after `time` at which some amount gets vested, `None` if the
+	/// schedule is fully vested at `time` or calculation overflows.
+	pub fn next_unlock(&self, time: BlockNumber) -> Option<BlockNumber> {
+		if self.locked_amount(time).is_zero() {
+			return None;
+		}
+		let cliff_end = self.cliff_end()?;
+		let elapsed_periods = |at: BlockNumber| at.saturating_sub(self.start).checked_div(&self.period);
+		// the periods elapsed by the end of the cliff are all granted at once
+		if time < cliff_end && !elapsed_periods(cliff_end)?.is_zero() {
+			return Some(cliff_end);
+		}
+		let next_period_end = self
+			.period
+			.checked_mul(&elapsed_periods(time)?.checked_add(&One::one())?)?
+			.checked_add(&self.start)?;
+		Some(next_period_end.max(cliff_end))
 	}
 
 	/// Returns all locked amount, `None` if calculation overflows.
@@ -108,6 +247,12 @@ impl<BlockNumber: AtLeast32Bit + Copy, Balance: AtLeast32Bit + Copy> VestingSche
 	/// Note this func assumes schedule is a valid one(non-zero period and non-overflow total
 	/// amount), and it should be guaranteed by callers.
 	pub fn locked_amount(&self, time: BlockNumber) -> Balance {
+		if time < self.start.saturating_add(self.cliff) {
+			return self
+				.per_period
+				.checked_mul(&self.period_count.into())
+				.expect("ensured non-overflow total amount; qed");
+		}
 		let full = time
 			.saturating_sub(self.start)
 			.checked_div(&self.period)
@@ -128,15 +273,31 @@ pub mod pallet {
 	#[pallet::config]
 	pub trait Config: frame_system::Config {
 		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
-		type Currency: LockableCurrency<Self::AccountId, Moment = BlockNumberFor<Self>>;
+		/// The overarching freeze reason.
+		type RuntimeFreezeReason: From<FreezeReason>;
+		type Currency: fungible::Mutate<Self::AccountId>
+			+ fungible::MutateFreeze<Self::AccountId, Id = Self::RuntimeFreezeReason>;
 		type CancelOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+		/// The origin of the bridge oracles confirming that bridge transfers were completed.
+		type BridgeOracleOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+		/// How long after its initiation an unconfirmed bridge transfer can be refunded by anyone.
+		#[pallet::constant]
+		type BridgeTransferTimeout: Get<BlockNumberFor<Self>>;
 		/// The maximum number of vesting schedule.
 		#[pallet::constant]
 		type MaxSchedule: Get<u32>;
+		/// The maximum number of vesting schedules added by a single batch.
+		#[pallet::constant]
+		type MaxBatchSchedules: Get<u32>;
+		/// The maximum number of oracle accounts of a single bridge.
+		#[pallet::constant]
+		type MaxBridgeOracles: Get<u32>;
 		/// Weight information for extrinsics in this pallet.
 		type WeightInfo: WeightInfo;
 		// The block number provider
 		type BlockNumberProvider: BlockNumberProvider<BlockNumber = BlockNumberFor<Self>>;
+		/// The unix time provider, in milliseconds, of the timed vesting schedules.
+		type Time: Time;
 	}
 
 	#[pallet::pallet]
@@ -144,17 +305,12 @@ pub mod pallet {
 
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
-		/// Claim funds that have been vested so far
+		/// Claim funds that have been vested so far, pruning the fully vested schedules
 		#[pallet::call_index(0)]
 		#[pallet::weight(T::WeightInfo::claim())]
 		pub fn claim(origin: OriginFor<T>) -> DispatchResultWithPostInfo {
 			let who = ensure_signed(origin)?;
-			let locked_amount = Self::do_claim(&who);
-
-			if locked_amount.is_zero() {
-				// No more claimable, clear
-				<VestingSchedules<T>>::remove(who.clone());
-			}
+			let locked_amount = Self::do_claim_and_prune(&who)?;
 
 			Self::deposit_event(Event::Claimed(who, locked_amount));
 			Ok(().into())
@@ -193,20 +349,22 @@ pub mod pallet {
 			ensure!(!Self::renounced(account_with_schedule.clone()), Error::<T>::Renounced);
 
 			let account_collector = T::Lookup::lookup(funds_collector)?;
-			let locked_amount_left = Self::do_claim(&account_with_schedule);
-			let free_balance = T::Currency::free_balance(&account_with_schedule);
+			let locked_amount_left = Self::do_claim(&account_with_schedule)?;
+			let free_balance = T::Currency::balance(&account_with_schedule);
 			let collectable_funds = locked_amount_left.min(free_balance);
 
-			// we need to remove the lock before doing the transfer to avoid
+			// we need to thaw the funds before doing the transfer to avoid
 			// liquidity restrictions
-			T::Currency::remove_lock(VESTING_LOCK_ID, &account_with_schedule);
+			T::Currency::thaw(&FreezeReason::Vesting.into(), &account_with_schedule)?;
 			T::Currency::transfer(
 				&account_with_schedule,
 				&account_collector,
 				collectable_funds,
-				ExistenceRequirement::AllowDeath,
+				Preservation::Expendable,
 			)?;
 			<VestingSchedules<T>>::remove(account_with_schedule.clone());
+			<TimedVestingSchedules<T>>::remove(&account_with_schedule);
+			Self::forget_revocable_schedules(&account_with_schedule);
 
 			Self::deposit_event(Event::VestingSchedulesCanceled(account_with_schedule));
 
@@ -238,38 +396,30 @@ pub mod pallet {
 			bridge_id: u32,
 		) -> DispatchResultWithPostInfo {
 			let from = ensure_signed(origin)?;
-			ensure!(
-				Bridges::<T>::contains_key(BridgeId(bridge_id)),
-				Error::<T>::BridgeNotFound
-			);
-
-			let locked_amount_left = Self::do_claim(&from);
-			if locked_amount_left.is_zero() {
-				<VestingSchedules<T>>::remove(from);
+			let details = Self::active_bridge(bridge_id)?;
+
+			let locked_amount_left = Self::do_claim(&from)?;
+			// the timed schedules are not bridged and keep their part of the lock
+			let timed_locked_amount = Self::timed_locked_balance(&from);
+			let bridged_locked_amount = locked_amount_left.saturating_sub(timed_locked_amount);
+			if bridged_locked_amount.is_zero() {
+				<VestingSchedules<T>>::remove(&from);
+				Self::forget_revocable_schedules(&from);
 				Self::deposit_event(Event::NoVestedFundsToBridgeAfterClaim);
 				return Ok(().into());
 			}
 
-			let free_balance = T::Currency::free_balance(&from);
-			let bridgeable_funds = locked_amount_left.min(free_balance);
-
-			T::Currency::remove_lock(VESTING_LOCK_ID, &from);
-			T::Currency::settle(
-				&from,
-				T::Currency::burn(bridgeable_funds),
-				WithdrawReasons::RESERVE,
-				ExistenceRequirement::AllowDeath,
-			)
-			.map_err(|_| Error::<T>::FailedToSettleBridge)?;
+			let free_balance = T::Currency::balance(&from);
+			let bridgeable_funds = bridged_locked_amount.min(free_balance.saturating_sub(timed_locked_amount));
+			Self::consume_bridge_allowance(bridge_id, &details, bridgeable_funds)?;
 
 			let grants = <VestingSchedules<T>>::take(&from);
+			Self::forget_revocable_schedules(&from);
 
-			Self::deposit_event(Event::BridgeInitiated {
-				to: eth_address,
-				bridge_id,
-				amount: bridgeable_funds,
-				grants,
-			});
+			Self::do_claim(&from)?;
+			Self::burn_bridged(&from, bridgeable_funds)?;
+
+			Self::record_bridge_transfer(from, eth_address, bridge_id, bridgeable_funds, grants);
 
 			Ok(().into())
 		}
@@ -288,10 +438,10 @@ pub mod pallet {
 			let id = BridgeId(bridge_id);
 			ensure!(!Bridges::<T>::contains_key(&id), Error::<T>::BridgeAlreadyExists);
 
-			let details = BridgeDetails {
-				chain_id: remote_chain_id,
-				name: bridge_name.try_into().map_err(|_| Error::<T>::BridgeNameTooLong)?,
-			};
+			let details = BridgeDetails::new(
+				remote_chain_id,
+				bridge_name.try_into().map_err(|_| Error::<T>::BridgeNameTooLong)?,
+			);
 
 			Bridges::<T>::insert(id, details);
 
@@ -308,9 +458,427 @@ pub mod pallet {
 			ensure!(Bridges::<T>::contains_key(&id), Error::<T>::BridgeNotFound);
 
 			Bridges::<T>::remove(&id);
+			BridgePeriodUsage::<T>::remove(&id);
+
+			Ok(().into())
+		}
+
+		/// Cancel the vesting schedule of `who` found at `schedule_index`, which must be
+		/// `schedule`, leaving the other ones untouched. Only the unvested part of that schedule
+		/// is sent to `funds_collector`, what was vested so far stays with `who`.
+		#[pallet::call_index(7)]
+		#[pallet::weight(T::WeightInfo::cancel_vesting_schedule())]
+		pub fn cancel_vesting_schedule(
+			origin: OriginFor<T>,
+			who: <T::Lookup as StaticLookup>::Source,
+			funds_collector: <T::Lookup as StaticLookup>::Source,
+			schedule_index: u32,
+			schedule: VestingScheduleOf<T>,
+		) -> DispatchResultWithPostInfo {
+			T::CancelOrigin::try_origin(origin).map(|_| ()).or_else(ensure_root)?;
+
+			let account_with_schedule = T::Lookup::lookup(who)?;
+			ensure!(!Self::renounced(account_with_schedule.clone()), Error::<T>::Renounced);
+
+			let account_collector = T::Lookup::lookup(funds_collector)?;
+			Self::ensure_schedule_at(&account_with_schedule, schedule_index, &schedule)?;
+			Self::forget_revocable_schedule(&account_with_schedule, &schedule);
+			let collectable_funds =
+				Self::do_cancel_schedule(&account_with_schedule, &account_collector, schedule_index)?;
+
+			Self::deposit_event(Event::VestingScheduleCanceled(
+				account_with_schedule,
+				schedule_index,
+				collectable_funds,
+			));
+
+			Ok(().into())
+		}
+
+		/// Wire funds to be vested by the receiver, keeping the right for the sender to revoke
+		/// the schedule later on and get its unvested part back.
+		#[pallet::call_index(8)]
+		#[pallet::weight(T::WeightInfo::add_revocable_vesting_schedule())]
+		pub fn add_revocable_vesting_schedule(
+			origin: OriginFor<T>,
+			dest: <T::Lookup as StaticLookup>::Source,
+			schedule: VestingScheduleOf<T>,
+		) -> DispatchResultWithPostInfo {
+			let from = ensure_signed(origin)?;
+			let to = T::Lookup::lookup(dest)?;
+			Self::do_add_vesting_schedule(&from, &to, schedule.clone())?;
+			<RevocableSchedules<T>>::try_mutate(&to, &from, |schedules| {
+				schedules
+					.try_push(schedule.clone())
+					.map_err(|_| Error::<T>::MaxScheduleOverflow)
+			})?;
+
+			Self::deposit_event(Event::VestingScheduleAdded(from, to, schedule));
+			Ok(().into())
+		}
+
+		/// Revoke the vesting schedule of `who` found at `schedule_index`, which must be
+		/// `schedule` and have been added as revocable by the caller. The unvested part of the schedule is sent back to
+		/// the caller, what was vested so far stays with `who`.
+		#[pallet::call_index(9)]
+		#[pallet::weight(T::WeightInfo::revoke_vesting_schedule())]
+		pub fn revoke_vesting_schedule(
+			origin: OriginFor<T>,
+			who: <T::Lookup as StaticLookup>::Source,
+			schedule_index: u32,
+			schedule: VestingScheduleOf<T>,
+		) -> DispatchResultWithPostInfo {
+			let funder = ensure_signed(origin)?;
+			let account_with_schedule = T::Lookup::lookup(who)?;
+			Self::ensure_schedule_at(&account_with_schedule, schedule_index, &schedule)?;
+
+			<RevocableSchedules<T>>::try_mutate_exists(&account_with_schedule, &funder, |maybe_schedules| {
+				let schedules = maybe_schedules.as_mut().ok_or(Error::<T>::NotRevocable)?;
+				let position = schedules
+					.iter()
+					.position(|s| s == &schedule)
+					.ok_or(Error::<T>::NotRevocable)?;
+				schedules.remove(position);
+				if schedules.is_empty() {
+					*maybe_schedules = None;
+				}
+				Ok::<_, Error<T>>(())
+			})?;
+			let revoked_funds = Self::do_cancel_schedule(&account_with_schedule, &funder, schedule_index)?;
+
+			Self::deposit_event(Event::VestingScheduleRevoked {
+				funder,
+				who: account_with_schedule,
+				schedule_index,
+				amount: revoked_funds,
+			});
+
+			Ok(().into())
+		}
+
+		/// Move some of the caller's vesting schedules, or all of them including the timed ones if
+		/// `schedule_indices` is `None`, to `dest` along with the balance they still lock. The
+		/// indices only select among the block based schedules. Schedules of a renounced
+		/// account can only move to a renounced account or one without any schedule.
+		#[pallet::call_index(10)]
+		#[pallet::weight(T::WeightInfo::transfer_vesting_schedules())]
+		pub fn transfer_vesting_schedules(
+			origin: OriginFor<T>,
+			dest: <T::Lookup as StaticLookup>::Source,
+			schedule_indices: Option<BoundedVec<u32, T::MaxSchedule>>,
+		) -> DispatchResultWithPostInfo {
+			let from = ensure_signed(origin)?;
+			let to = T::Lookup::lookup(dest)?;
+			Self::do_transfer_vesting_schedules(&from, &to, schedule_indices)?;
+			Ok(().into())
+		}
+
+		/// Same as `transfer_vesting_schedules` but initiated by the `CancelOrigin` on behalf of
+		/// `who`, for instance after it lost its keys. Not allowed if the `CancelOrigin`
+		/// renounced its privileges over `who`.
+		#[pallet::call_index(11)]
+		#[pallet::weight(T::WeightInfo::transfer_vesting_schedules())]
+		pub fn force_transfer_vesting_schedules(
+			origin: OriginFor<T>,
+			who: <T::Lookup as StaticLookup>::Source,
+			dest: <T::Lookup as StaticLookup>::Source,
+			schedule_indices: Option<BoundedVec<u32, T::MaxSchedule>>,
+		) -> DispatchResultWithPostInfo {
+			T::CancelOrigin::try_origin(origin).map(|_| ()).or_else(ensure_root)?;
+
+			let from = T::Lookup::lookup(who)?;
+			ensure!(!Self::renounced(from.clone()), Error::<T>::Renounced);
+			let to = T::Lookup::lookup(dest)?;
+			Self::do_transfer_vesting_schedules(&from, &to, schedule_indices)?;
+			Ok(().into())
+		}
+
+		/// Wire funds to be vested by several receivers at once. The whole batch is validated
+		/// before any transfer so that it either fully succeeds or fails without effect.
+		#[pallet::call_index(12)]
+		#[pallet::weight(T::WeightInfo::batch_add_vesting_schedules(batch.len() as u32))]
+		pub fn batch_add_vesting_schedules(
+			origin: OriginFor<T>,
+			batch: BoundedVec<(<T::Lookup as StaticLookup>::Source, VestingScheduleOf<T>), T::MaxBatchSchedules>,
+		) -> DispatchResultWithPostInfo {
+			let from = ensure_signed(origin)?;
+			ensure!(!batch.is_empty(), Error::<T>::EmptySchedules);
+
+			let mut grants = Vec::with_capacity(batch.len());
+			let mut added_per_account: BTreeMap<T::AccountId, u32> = BTreeMap::new();
+			let mut total: BalanceOf<T> = Zero::zero();
+			for (dest, schedule) in batch.into_iter() {
+				let to = T::Lookup::lookup(dest)?;
+				ensure!(from != to, Error::<T>::VestingToSelf);
+				let amount = Self::ensure_valid_vesting_schedule(&schedule)?;
+				total = total.checked_add(&amount).ok_or(Error::<T>::NumOverflow)?;
+
+				let added = added_per_account.entry(to.clone()).or_default();
+				added.saturating_inc();
+				Self::ensure_schedule_slots(&to, Self::vesting_schedules(&to).len().saturating_add(*added as usize))?;
+				grants.push((to, schedule));
+			}
+			ensure!(T::Currency::balance(&from) >= total, Error::<T>::InsufficientBalance);
+
+			for (to, schedule) in grants.iter() {
+				Self::do_add_vesting_schedule(&from, to, schedule.clone())?;
+			}
+
+			Self::deposit_event(Event::VestingSchedulesBatchAdded {
+				from,
+				count: grants.len() as u32,
+				total,
+			});
+			Ok(().into())
+		}
+
+		/// Free some of the `MaxSchedule` slots of `who` by pruning its fully vested schedules
+		/// and, if `merge` is set, merging the schedules unlocking at the very same times. This
+		/// never changes what `who` can claim so anyone can call it.
+		#[pallet::call_index(13)]
+		#[pallet::weight(T::WeightInfo::compact())]
+		pub fn compact(
+			origin: OriginFor<T>,
+			who: <T::Lookup as StaticLookup>::Source,
+			merge: bool,
+		) -> DispatchResultWithPostInfo {
+			ensure_signed(origin)?;
+			let who = T::Lookup::lookup(who)?;
+			let (pruned, merged) = Self::do_compact(&who, merge);
+			Self::do_claim(&who)?;
+
+			Self::deposit_event(Event::VestingSchedulesCompacted { who, pruned, merged });
+			Ok(().into())
+		}
+
+		/// Let the bridge oracles confirm that the bridge transfer `nonce` of `bridge_id` was
+		/// completed on the remote chain, removing it from the pending transfers. Besides the
+		/// `BridgeOracleOrigin`, the oracle accounts set on the bridge can confirm its transfers.
+		#[pallet::call_index(14)]
+		#[pallet::weight(T::WeightInfo::confirm_bridge_transfer())]
+		pub fn confirm_bridge_transfer(origin: OriginFor<T>, bridge_id: u32, nonce: u64) -> DispatchResultWithPostInfo {
+			if let Err(origin) = T::BridgeOracleOrigin::try_origin(origin) {
+				let oracle = ensure_signed(origin)?;
+				let details = Bridges::<T>::get(BridgeId(bridge_id)).ok_or(Error::<T>::BridgeNotFound)?;
+				ensure!(details.oracles.contains(&oracle), Error::<T>::NotBridgeOracle);
+			}
+
+			let transfer = <PendingBridgeTransfers<T>>::take(BridgeId(bridge_id), nonce)
+				.ok_or(Error::<T>::BridgeTransferNotFound)?;
+			if !transfer.grants.is_empty() {
+				Self::release_schedule_slot(&transfer.source);
+			}
+
+			Self::deposit_event(Event::BridgeTransferConfirmed { bridge_id, nonce });
+			Ok(().into())
+		}
+
+		/// Refund a bridge transfer which was not confirmed by the bridge oracles: the burnt
+		/// amount is issued again to its source and the bridged vesting schedules are restored.
+		/// The `CancelOrigin` can do so at any time while anyone can once the transfer is older
+		/// than `BridgeTransferTimeout`. Confirmed transfers are no longer pending and can never
+		/// be refunded. Transfers of free balance, such as the allocations bridged on behalf of
+		/// their remote beneficiary, have no local account to be refunded to and are left to
+		/// governance to settle.
+		#[pallet::call_index(15)]
+		#[pallet::weight(T::WeightInfo::refund_bridge_transfer())]
+		pub fn refund_bridge_transfer(origin: OriginFor<T>, bridge_id: u32, nonce: u64) -> DispatchResultWithPostInfo {
+			let is_governance = match T::CancelOrigin::try_origin(origin) {
+				Ok(_) => true,
+				Err(origin) => {
+					if ensure_root(origin.clone()).is_err() {
+						ensure_signed(origin)?;
+						false
+					} else {
+						true
+					}
+				}
+			};
+
+			let transfer = <PendingBridgeTransfers<T>>::get(BridgeId(bridge_id), nonce)
+				.ok_or(Error::<T>::BridgeTransferNotFound)?;
+			ensure!(!transfer.grants.is_empty(), Error::<T>::BridgeTransferNotRefundable);
+			if !is_governance {
+				let now = T::BlockNumberProvider::current_block_number();
+				ensure!(
+					now >= transfer.initiated_at.saturating_add(T::BridgeTransferTimeout::get()),
+					Error::<T>::BridgeTransferNotExpired
+				);
+			}
+			<PendingBridgeTransfers<T>>::remove(BridgeId(bridge_id), nonce);
+			Self::release_schedule_slot(&transfer.source);
+			Self::release_bridge_allowance(bridge_id, transfer.initiated_at, transfer.amount);
+
+			Self::restore_bridged_schedules(&transfer.source, transfer.grants)?;
+			T::Currency::mint_into(&transfer.source, transfer.amount)?;
+			Self::do_claim(&transfer.source)?;
+
+			Self::deposit_event(Event::BridgeTransferRefunded {
+				bridge_id,
+				nonce,
+				who: transfer.source,
+				amount: transfer.amount,
+			});
+			Ok(().into())
+		}
+
+		/// Update the details of an existing bridge, leaving unchanged the ones `update` gives
+		/// as `None`. A paused bridge rejects new transfers while its pending ones can still be
+		/// confirmed or refunded. `max_per_period` limits the total amount bridged within each
+		/// window of `period` blocks.
+		#[pallet::call_index(16)]
+		#[pallet::weight(T::WeightInfo::update_bridge())]
+		pub fn update_bridge(
+			origin: OriginFor<T>,
+			bridge_id: u32,
+			update: BridgeUpdate<T>,
+		) -> DispatchResultWithPostInfo {
+			ensure_root(origin)?;
+
+			let id = BridgeId(bridge_id);
+			Bridges::<T>::try_mutate(&id, |maybe_details| -> DispatchResult {
+				let details = maybe_details.as_mut().ok_or(Error::<T>::BridgeNotFound)?;
+				if let Some(name) = update.name {
+					details.name = name.try_into().map_err(|_| Error::<T>::BridgeNameTooLong)?;
+				}
+				if let Some(chain_id) = update.chain_id {
+					details.chain_id = chain_id;
+				}
+				if let Some(status) = update.status {
+					details.status = status;
+				}
+				if let Some(oracles) = update.oracles {
+					details.oracles = oracles;
+				}
+				if let Some(max_per_transfer) = update.max_per_transfer {
+					details.max_per_transfer = max_per_transfer;
+				}
+				if let Some(max_per_period) = update.max_per_period {
+					if let Some(cap) = &max_per_period {
+						ensure!(!cap.period.is_zero(), Error::<T>::ZeroBridgePeriod);
+					}
+					details.max_per_period = max_per_period;
+					// Count from scratch under the new cap
+					BridgePeriodUsage::<T>::remove(&id);
+				}
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::BridgeUpdated { bridge_id });
+			Ok(().into())
+		}
+
+		/// Initiate a bridge transfer of part of the vested funds to the given `eth_address`,
+		/// leaving the rest vesting on the parachain. Either the schedules at the given indices
+		/// are bridged whole, or the given amount is taken from every schedule in proportion of
+		/// its locked amount by splitting its `per_period`, rounding down. The amount is rejected
+		/// if the rounding would bridge less than requested by more than one unit per schedule.
+		/// The bridged schedules are announced in the `BridgeInitiated` event.
+		#[pallet::call_index(17)]
+		#[pallet::weight(T::WeightInfo::bridge_vesting_schedules())]
+		pub fn bridge_vesting_schedules(
+			origin: OriginFor<T>,
+			eth_address: [u8; 20],
+			bridge_id: u32,
+			selection: BridgeSelection<T>,
+		) -> DispatchResultWithPostInfo {
+			let from = ensure_signed(origin)?;
+			Self::do_bridge_vesting_schedules(from, eth_address, bridge_id, selection)?;
+			Ok(().into())
+		}
 
+		/// Claim the funds vested so far on behalf of `who`, refreshing its lock. This only ever
+		/// relaxes the restrictions on `who` so anyone can do it.
+		#[pallet::call_index(18)]
+		#[pallet::weight(T::WeightInfo::claim_for())]
+		pub fn claim_for(origin: OriginFor<T>, who: <T::Lookup as StaticLookup>::Source) -> DispatchResultWithPostInfo {
+			let caller = ensure_signed(origin)?;
+			let who = T::Lookup::lookup(who)?;
+			let locked_amount = Self::do_claim_and_prune(&who)?;
+
+			Self::deposit_event(Event::ClaimedFor {
+				who,
+				caller,
+				locked: locked_amount,
+			});
 			Ok(().into())
 		}
+
+		/// Wire funds to be vested by the receiver following a schedule expressed in unix
+		/// milliseconds. Timed schedules are kept apart from the block based ones but share
+		/// their lock, they are claimed and canceled along with them and are never bridged.
+		/// They can be canceled on their own with `cancel_timed_vesting_schedule`.
+		#[pallet::call_index(19)]
+		#[pallet::weight(T::WeightInfo::add_timed_vesting_schedule())]
+		pub fn add_timed_vesting_schedule(
+			origin: OriginFor<T>,
+			dest: <T::Lookup as StaticLookup>::Source,
+			schedule: TimedVestingScheduleOf<T>,
+		) -> DispatchResultWithPostInfo {
+			let from = ensure_signed(origin)?;
+			let to = T::Lookup::lookup(dest)?;
+			Self::do_add_schedule(&from, &to, schedule.clone(), |schedule| {
+				<TimedVestingSchedules<T>>::try_mutate(&to, |schedules| -> DispatchResult {
+					schedules
+						.try_push(schedule)
+						.map_err(|_| Error::<T>::MaxScheduleOverflow)?;
+					Ok(())
+				})
+			})?;
+
+			Self::deposit_event(Event::TimedVestingScheduleAdded(from, to, schedule));
+			Ok(().into())
+		}
+
+		/// Same as `cancel_vesting_schedule` for the timed vesting schedule of `who` found at
+		/// `schedule_index`, which must be `schedule`.
+		#[pallet::call_index(20)]
+		#[pallet::weight(T::WeightInfo::cancel_timed_vesting_schedule())]
+		pub fn cancel_timed_vesting_schedule(
+			origin: OriginFor<T>,
+			who: <T::Lookup as StaticLookup>::Source,
+			funds_collector: <T::Lookup as StaticLookup>::Source,
+			schedule_index: u32,
+			schedule: TimedVestingScheduleOf<T>,
+		) -> DispatchResultWithPostInfo {
+			T::CancelOrigin::try_origin(origin).map(|_| ()).or_else(ensure_root)?;
+
+			let account_with_schedule = T::Lookup::lookup(who)?;
+			ensure!(!Self::renounced(account_with_schedule.clone()), Error::<T>::Renounced);
+
+			let account_collector = T::Lookup::lookup(funds_collector)?;
+			let now = T::Time::now();
+			let mut schedules = Self::timed_vesting_schedules(&account_with_schedule);
+			ensure!(
+				(schedule_index as usize) < schedules.len(),
+				Error::<T>::InvalidScheduleIndex
+			);
+			let canceled = schedules.remove(schedule_index as usize);
+			ensure!(canceled == schedule, Error::<T>::ScheduleMismatch);
+			if schedules.is_empty() {
+				<TimedVestingSchedules<T>>::remove(&account_with_schedule);
+			} else {
+				<TimedVestingSchedules<T>>::insert(&account_with_schedule, schedules);
+			}
+			let collectable_funds =
+				Self::collect_unvested(&account_with_schedule, &account_collector, canceled.locked_amount(now))?;
+
+			Self::deposit_event(Event::TimedVestingScheduleCanceled(
+				account_with_schedule,
+				schedule_index,
+				collectable_funds,
+			));
+
+			Ok(().into())
+		}
+	}
+
+	/// The reasons for the pallet freezing funds.
+	#[pallet::composite_enum]
+	pub enum FreezeReason {
+		/// The funds are still vesting.
+		#[codec(index = 0)]
+		Vesting,
 	}
 
 	#[pallet::event]
@@ -318,20 +886,72 @@ pub mod pallet {
 	pub enum Event<T: Config> {
 		/// Added new vesting schedule \[from, to, vesting_schedule\]
 		VestingScheduleAdded(T::AccountId, T::AccountId, VestingScheduleOf<T>),
+		/// Added new timed vesting schedule \[from, to, vesting_schedule\]
+		TimedVestingScheduleAdded(T::AccountId, T::AccountId, TimedVestingScheduleOf<T>),
 		/// Claimed vesting \[who, locked_amount\]
 		Claimed(T::AccountId, BalanceOf<T>),
+		/// Vested funds were claimed on behalf of `who` by `caller`, `locked` is what stays locked
+		ClaimedFor {
+			who: T::AccountId,
+			caller: T::AccountId,
+			locked: BalanceOf<T>,
+		},
 		/// Canceled all vesting schedules \[who\]
 		VestingSchedulesCanceled(T::AccountId),
+		/// Canceled a single vesting schedule \[who, schedule_index, collected_amount\]
+		VestingScheduleCanceled(T::AccountId, u32, BalanceOf<T>),
+		/// Canceled a single timed vesting schedule \[who, schedule_index, collected_amount\]
+		TimedVestingScheduleCanceled(T::AccountId, u32, BalanceOf<T>),
+		/// A funder revoked one of the revocable schedules it added and got back its unvested
+		/// `amount`
+		VestingScheduleRevoked {
+			funder: T::AccountId,
+			who: T::AccountId,
+			schedule_index: u32,
+			amount: BalanceOf<T>,
+		},
+		/// Pruned `pruned` fully vested schedules and merged `merged` schedules into others
+		VestingSchedulesCompacted {
+			who: T::AccountId,
+			pruned: u32,
+			merged: u32,
+		},
+		/// Added a batch of `count` vesting schedules worth `total`
+		VestingSchedulesBatchAdded {
+			from: T::AccountId,
+			count: u32,
+			total: BalanceOf<T>,
+		},
+		/// Moved `count` vesting schedules still locking `amount` from one account to another
+		VestingSchedulesTransferred {
+			from: T::AccountId,
+			to: T::AccountId,
+			count: u32,
+			amount: BalanceOf<T>,
+		},
 		/// Renounced rights to cancel grant for the given account id \[who\]
 		Renounced(T::AccountId),
-		/// Initiated a bridge transfer of all vested funds \[to, chain_id, amount, grants\]
+		/// Initiated a bridge transfer of all vested funds \[to, chain_id, nonce, amount, grants\]
 		/// The field amount is crucial for the bridge because it shows the total entitlement of the user on the other side of the bridge.
+		/// The nonce is unique per bridge so that the remote side can deduplicate the transfers.
 		BridgeInitiated {
 			to: [u8; 20],
 			bridge_id: u32,
+			nonce: u64,
 			amount: BalanceOf<T>,
 			grants: BoundedVec<VestingScheduleOf<T>, T::MaxSchedule>,
 		},
+		/// The bridge oracles confirmed that a bridge transfer was completed on the remote chain
+		BridgeTransferConfirmed { bridge_id: u32, nonce: u64 },
+		/// A bridge transfer which was not confirmed was refunded to its source
+		BridgeTransferRefunded {
+			bridge_id: u32,
+			nonce: u64,
+			who: T::AccountId,
+			amount: BalanceOf<T>,
+		},
+		/// The status, oracles or caps of a bridge were updated
+		BridgeUpdated { bridge_id: u32 },
 		/// Bridge was initiated and successfully completed one sidedly because
 		/// there were no vested funds to bridge after claiming free tokens for the user.
 		NoVestedFundsToBridgeAfterClaim,
@@ -351,6 +971,20 @@ pub mod pallet {
 		BridgeAlreadyExists,
 		BridgeNotFound,
 		BridgeNameTooLong,
+		InvalidScheduleIndex,
+		NotRevocable,
+		InsufficientBalance,
+		BridgeTransferNotFound,
+		BridgeTransferNotExpired,
+		BridgePaused,
+		BridgeTransferCapExceeded,
+		BridgePeriodCapExceeded,
+		NotBridgeOracle,
+		ZeroBridgePeriod,
+		InvalidBridgeAmount,
+		ScheduleMismatch,
+		BridgeTransferNotRefundable,
+		InexactBridgeAmount,
 	}
 
 	#[pallet::storage]
@@ -363,84 +997,532 @@ pub mod pallet {
 		ValueQuery,
 	>;
 
+	/// Vesting schedules of an account expressed in unix milliseconds.
+	#[pallet::storage]
+	#[pallet::getter(fn timed_vesting_schedules)]
+	pub type TimedVestingSchedules<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		BoundedVec<TimedVestingScheduleOf<T>, T::MaxSchedule>,
+		ValueQuery,
+	>;
+
+	/// The revocable schedules of a grantee, by the funder who has the right to revoke them.
+	#[pallet::storage]
+	#[pallet::getter(fn revocable_schedules)]
+	pub type RevocableSchedules<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Blake2_128Concat,
+		T::AccountId,
+		BoundedVec<VestingScheduleOf<T>, T::MaxSchedule>,
+		ValueQuery,
+	>;
+
 	#[pallet::storage]
 	#[pallet::getter(fn renounced)]
 	pub type Renounced<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, bool, ValueQuery>;
 
 	#[pallet::storage]
 	#[pallet::getter(fn bridges)]
-	pub type Bridges<T: Config> = StorageMap<_, Blake2_128Concat, BridgeId, BridgeDetails, OptionQuery>;
+	pub type Bridges<T: Config> = StorageMap<_, Blake2_128Concat, BridgeId, BridgeDetails<T>, OptionQuery>;
+
+	/// The current period of the bridges with a period cap and the amount bridged during it.
+	#[pallet::storage]
+	#[pallet::getter(fn bridge_period_usage)]
+	pub type BridgePeriodUsage<T: Config> =
+		StorageMap<_, Blake2_128Concat, BridgeId, (BlockNumberFor<T>, BalanceOf<T>), ValueQuery>;
+
+	/// The nonce of the next transfer through each bridge.
+	#[pallet::storage]
+	#[pallet::getter(fn bridge_nonce)]
+	pub type BridgeNonces<T: Config> = StorageMap<_, Blake2_128Concat, BridgeId, u64, ValueQuery>;
+
+	/// The bridge transfers not yet confirmed by the bridge oracles, by bridge and nonce.
+	#[pallet::storage]
+	#[pallet::getter(fn pending_bridge_transfers)]
+	pub type PendingBridgeTransfers<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, BridgeId, Twox64Concat, u64, PendingBridgeTransfer<T>, OptionQuery>;
+
+	/// The number of pending bridge transfers of vesting schedules from each account, each of
+	/// them keeping one of the account's schedule slots free for a refund.
+	#[pallet::storage]
+	#[pallet::getter(fn reserved_schedule_slots)]
+	pub type ReservedScheduleSlots<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, u32, ValueQuery>;
 
 	#[pallet::storage]
 	pub(crate) type StorageVersion<T: Config> = StorageValue<_, Releases, ValueQuery>;
 
 	#[pallet::genesis_config]
 	pub struct GenesisConfig<T: Config> {
+		/// The `(start, period, period_count, per_period)` vesting schedules of each account.
 		pub vesting: Vec<ScheduledItem<T>>,
+		/// Vesting schedules with a cliff, added to the ones of `vesting` for the same account.
+		pub cliffed_vesting: Vec<CliffedItem<T>>,
+		/// The bridges to register, active and without caps.
+		pub bridges: Vec<GenesisBridge<T>>,
+		/// The accounts whose vesting schedules cannot be canceled.
+		pub renounced: Vec<T::AccountId>,
 	}
 
 	impl<T: Config> Default for GenesisConfig<T> {
 		fn default() -> Self {
 			Self {
 				vesting: Default::default(),
+				cliffed_vesting: Default::default(),
+				bridges: Default::default(),
+				renounced: Default::default(),
 			}
 		}
 	}
-	#[pallet::genesis_build]
-	impl<T: Config> BuildGenesisConfig for GenesisConfig<T> {
-		fn build(&self) {
-			self.vesting.iter().for_each(|(ref who, schedules)| {
-				let vesting_schedule: BoundedVec<VestingScheduleOf<T>, T::MaxSchedule> = schedules
-					.iter()
-					.map(|&(start, period, period_count, per_period)| VestingSchedule {
+
+	impl<T: Config> GenesisConfig<T> {
+		/// Check that the genesis config can be built, describing the first issue found otherwise.
+		pub fn validate(&self) -> Result<(), &'static str> {
+			self.grants()?;
+			self.bridge_details()?;
+			Ok(())
+		}
+
+		/// The vesting schedules of each account along with the total amount they lock.
+		#[allow(clippy::type_complexity)]
+		fn grants(
+			&self,
+		) -> Result<
+			BTreeMap<T::AccountId, (BoundedVec<VestingScheduleOf<T>, T::MaxSchedule>, BalanceOf<T>)>,
+			&'static str,
+		> {
+			let mut grants: BTreeMap<T::AccountId, Vec<VestingScheduleOf<T>>> = BTreeMap::new();
+			for (who, schedules) in self.vesting.iter() {
+				grants.entry(who.clone()).or_default().extend(schedules.iter().map(
+					|&(start, period, period_count, per_period)| VestingSchedule {
 						start,
 						period,
 						period_count,
 						per_period,
-					})
-					.collect::<Vec<_>>()
-					.try_into()
-					.expect("Genesis Init Failed Vesting Schedules Overflow");
+						cliff: Zero::zero(),
+					},
+				));
+			}
+			for (who, schedules) in self.cliffed_vesting.iter() {
+				grants.entry(who.clone()).or_default().extend(schedules.iter().map(
+					|&(start, period, period_count, per_period, cliff)| VestingSchedule {
+						start,
+						period,
+						period_count,
+						per_period,
+						cliff,
+					},
+				));
+			}
 
-				let total_grants = vesting_schedule.iter().fold(Zero::zero(), |acc: BalanceOf<T>, s| {
-					acc.saturating_add(s.locked_amount(Zero::zero()))
-				});
+			grants
+				.into_iter()
+				.map(|(who, schedules)| {
+					let mut total: BalanceOf<T> = Zero::zero();
+					for schedule in schedules.iter() {
+						let amount = Pallet::<T>::ensure_valid_vesting_schedule(schedule).map_err(|err| match err {
+							Error::<T>::ZeroVestingPeriod => "a genesis vesting schedule has a zero period",
+							Error::<T>::ZeroVestingPeriodCount => "a genesis vesting schedule has a zero period count",
+							_ => "a genesis vesting schedule overflows the block number or balance type",
+						})?;
+						total = total
+							.checked_add(&amount)
+							.ok_or("the genesis vesting schedules of an account overflow the balance type")?;
+					}
+					let schedules = BoundedVec::try_from(schedules)
+						.map_err(|_| "an account has more genesis vesting schedules than MaxSchedule")?;
+					Ok((who, (schedules, total)))
+				})
+				.collect()
+		}
 
-				T::Currency::resolve_creating(who, T::Currency::issue(total_grants));
-				T::Currency::set_lock(VESTING_LOCK_ID, who, total_grants, WithdrawReasons::all());
-				<VestingSchedules<T>>::insert(who, vesting_schedule);
-			});
+		fn bridge_details(&self) -> Result<Vec<(BridgeId, BridgeDetails<T>)>, &'static str> {
+			let mut ids = BTreeSet::new();
+			self.bridges
+				.iter()
+				.map(|(bridge_id, name, chain_id, oracles)| {
+					ensure!(
+						ids.insert(*bridge_id),
+						"a genesis bridge id is registered more than once"
+					);
+					let name = name
+						.clone()
+						.try_into()
+						.map_err(|_| "a genesis bridge name is longer than 32 bytes")?;
+					let mut details = BridgeDetails::new(*chain_id, name);
+					details.oracles = oracles
+						.clone()
+						.try_into()
+						.map_err(|_| "a genesis bridge has more oracles than MaxBridgeOracles")?;
+					Ok((BridgeId(*bridge_id), details))
+				})
+				.collect()
+		}
+	}
+
+	#[pallet::genesis_build]
+	impl<T: Config> BuildGenesisConfig for GenesisConfig<T> {
+		fn build(&self) {
+			let grants = self
+				.grants()
+				.unwrap_or_else(|err| panic!("Invalid grants genesis config: {}", err));
+			let bridges = self
+				.bridge_details()
+				.unwrap_or_else(|err| panic!("Invalid grants genesis config: {}", err));
+
+			for (who, (schedules, total_grants)) in grants {
+				T::Currency::mint_into(&who, total_grants)
+					.unwrap_or_else(|err| panic!("Failed to mint the genesis vested funds of {:?}: {:?}", who, err));
+				T::Currency::set_freeze(&FreezeReason::Vesting.into(), &who, total_grants)
+					.unwrap_or_else(|err| panic!("Failed to freeze the genesis vested funds of {:?}: {:?}", who, err));
+				<VestingSchedules<T>>::insert(who, schedules);
+			}
+			for (bridge_id, details) in bridges {
+				Bridges::<T>::insert(bridge_id, details);
+			}
+			for who in self.renounced.iter() {
+				Renounced::<T>::insert(who, true);
+			}
+			// the schedules are built in the latest format, the migrations have nothing to do
+			StorageVersion::<T>::put(Releases::V4);
 		}
 	}
 }
 
+/// The identifier of the lock vesting funds used to be restricted with before being frozen.
 pub const VESTING_LOCK_ID: LockIdentifier = *b"nvesting";
 
 impl<T: Config> Pallet<T> {
-	fn do_claim(who: &T::AccountId) -> BalanceOf<T> {
+	fn do_claim(who: &T::AccountId) -> Result<BalanceOf<T>, DispatchError> {
 		let locked = Self::locked_balance(who);
 		if locked.is_zero() {
-			T::Currency::remove_lock(VESTING_LOCK_ID, who);
+			T::Currency::thaw(&FreezeReason::Vesting.into(), who)?;
+		} else {
+			T::Currency::set_freeze(&FreezeReason::Vesting.into(), who, locked)?;
+		}
+		Ok(locked)
+	}
+
+	/// Update the freeze of `who` to its locked amount, clearing its schedules once they are all
+	/// vested or pruning the fully vested ones otherwise. Returns the amount still locked.
+	fn do_claim_and_prune(who: &T::AccountId) -> Result<BalanceOf<T>, DispatchError> {
+		let locked_amount = Self::do_claim(who)?;
+
+		if locked_amount.is_zero() {
+			// No more claimable, clear
+			<VestingSchedules<T>>::remove(who);
+			<TimedVestingSchedules<T>>::remove(who);
+			Self::forget_revocable_schedules(who);
 		} else {
-			T::Currency::set_lock(VESTING_LOCK_ID, who, locked, WithdrawReasons::all());
+			Self::do_compact(who, false);
+		}
+		Ok(locked_amount)
+	}
+
+	/// Prune the fully vested schedules of `who` and, if `merge` is set, merge its schedules
+	/// differing only by their `per_period` amount. Schedules which may be revoked by their
+	/// funder are left unmerged so that they can still be told apart. Returns the number of
+	/// pruned and merged schedules.
+	fn do_compact(who: &T::AccountId, merge: bool) -> (u32, u32) {
+		let now = T::BlockNumberProvider::current_block_number();
+		let schedules = Self::vesting_schedules(who);
+		let initial_len = schedules.len() as u32;
+
+		let mut compacted: Vec<VestingScheduleOf<T>> = Vec::with_capacity(schedules.len());
+		let mut vested: Vec<VestingScheduleOf<T>> = Vec::new();
+		let mut pruned = 0u32;
+		let mut merged = 0u32;
+		let revocable: Vec<VestingScheduleOf<T>> = if merge {
+			<RevocableSchedules<T>>::iter_prefix_values(who).flatten().collect()
+		} else {
+			Vec::new()
+		};
+		for schedule in schedules.into_iter() {
+			if schedule.locked_amount(now).is_zero() {
+				vested.push(schedule);
+				pruned.saturating_inc();
+				continue;
+			}
+			if merge && !revocable.contains(&schedule) {
+				let same_times = compacted.iter_mut().find(|s| {
+					s.start == schedule.start
+						&& s.period == schedule.period
+						&& s.period_count == schedule.period_count
+						&& s.cliff == schedule.cliff
+						&& !revocable.contains(s)
+				});
+				if let Some(same_times) = same_times {
+					if let Some(per_period) = same_times.per_period.checked_add(&schedule.per_period) {
+						same_times.per_period = per_period;
+						merged.saturating_inc();
+						continue;
+					}
+				}
+			}
+			compacted.push(schedule);
+		}
+
+		if compacted.is_empty() {
+			<VestingSchedules<T>>::remove(who);
+		} else if (compacted.len() as u32) < initial_len {
+			<VestingSchedules<T>>::insert(who, BoundedVec::truncate_from(compacted));
+		}
+		Self::forget_vested_revocable_schedules(who, vested);
+
+		let now_ms = T::Time::now();
+		let mut timed_schedules = Self::timed_vesting_schedules(who);
+		let timed_len = timed_schedules.len() as u32;
+		timed_schedules.retain(|s| !s.locked_amount(now_ms).is_zero());
+		pruned.saturating_accrue(timed_len.saturating_sub(timed_schedules.len() as u32));
+		if timed_schedules.is_empty() {
+			<TimedVestingSchedules<T>>::remove(who);
+		} else if (timed_schedules.len() as u32) < timed_len {
+			<TimedVestingSchedules<T>>::insert(who, timed_schedules);
+		}
+		(pruned, merged)
+	}
+
+	/// Returns the details of `bridge_id`, failing if it is unknown or paused.
+	fn active_bridge(bridge_id: u32) -> Result<BridgeDetails<T>, DispatchError> {
+		let details = Bridges::<T>::get(BridgeId(bridge_id)).ok_or(Error::<T>::BridgeNotFound)?;
+		ensure!(details.status == BridgeStatus::Active, Error::<T>::BridgePaused);
+		Ok(details)
+	}
+
+	/// Account for `amount` being bridged over `bridge_id`, failing if it exceeds the caps of
+	/// the bridge. The usage of a period is reset once it is over.
+	fn consume_bridge_allowance(bridge_id: u32, details: &BridgeDetails<T>, amount: BalanceOf<T>) -> DispatchResult {
+		if let Some(max) = details.max_per_transfer {
+			ensure!(amount <= max, Error::<T>::BridgeTransferCapExceeded);
+		}
+		if let Some(cap) = &details.max_per_period {
+			let current_period = T::BlockNumberProvider::current_block_number() / cap.period;
+			BridgePeriodUsage::<T>::try_mutate(BridgeId(bridge_id), |(period, used)| -> DispatchResult {
+				if *period != current_period {
+					*period = current_period;
+					*used = Zero::zero();
+				}
+				let bridged = used.saturating_add(amount);
+				ensure!(bridged <= cap.amount, Error::<T>::BridgePeriodCapExceeded);
+				*used = bridged;
+				Ok(())
+			})?;
+		}
+		Ok(())
+	}
+
+	/// Give back the `amount` of a refunded transfer initiated at `initiated_at` to the period cap
+	/// of the bridge, unless that period is over.
+	fn release_bridge_allowance(bridge_id: u32, initiated_at: BlockNumberFor<T>, amount: BalanceOf<T>) {
+		let Some(cap) = Bridges::<T>::get(BridgeId(bridge_id)).and_then(|details| details.max_per_period) else {
+			return;
+		};
+		let transfer_period = initiated_at / cap.period;
+		if BridgePeriodUsage::<T>::contains_key(BridgeId(bridge_id)) {
+			BridgePeriodUsage::<T>::mutate(BridgeId(bridge_id), |(period, used)| {
+				if *period == transfer_period {
+					*used = used.saturating_sub(amount);
+				}
+			});
+		}
+	}
+
+	/// Ensure `who` can hold `count` vesting schedules besides the slots kept free for the
+	/// refunds of its pending bridge transfers.
+	fn ensure_schedule_slots(who: &T::AccountId, count: usize) -> DispatchResult {
+		let reserved = Self::reserved_schedule_slots(who) as usize;
+		ensure!(
+			count.saturating_add(reserved) <= T::MaxSchedule::get() as usize,
+			Error::<T>::MaxScheduleOverflow
+		);
+		Ok(())
+	}
+
+	/// Free the slot kept for the refund of a bridge transfer of `who` which is no longer pending.
+	fn release_schedule_slot(who: &T::AccountId) {
+		<ReservedScheduleSlots<T>>::mutate_exists(who, |reserved| {
+			*reserved = reserved.map(|r| r.saturating_sub(1)).filter(|r| !r.is_zero());
+		});
+	}
+
+	/// Give back the `grants` of a refunded bridge transfer to `who`. They are restored as they
+	/// were if there is room for them, else merged into the slot kept free by the transfer. An
+	/// account which had no free slot left after bridging part of its schedules gets each grant
+	/// added back to the schedule it was split from.
+	fn restore_bridged_schedules(
+		who: &T::AccountId,
+		grants: BoundedVec<VestingScheduleOf<T>, T::MaxSchedule>,
+	) -> DispatchResult {
+		let mut schedules = Self::vesting_schedules(who);
+		let reserved = Self::reserved_schedule_slots(who) as usize;
+		let free_slots = (T::MaxSchedule::get() as usize)
+			.saturating_sub(schedules.len())
+			.saturating_sub(reserved);
+
+		if grants.len() <= free_slots {
+			for grant in grants {
+				schedules.try_push(grant).map_err(|_| Error::<T>::MaxScheduleOverflow)?;
+			}
+		} else if free_slots > 0 {
+			if let Some(merged) = Self::merge_schedules(&grants) {
+				schedules
+					.try_push(merged)
+					.map_err(|_| Error::<T>::MaxScheduleOverflow)?;
+			}
+		} else {
+			for grant in grants {
+				let split_from = schedules
+					.iter_mut()
+					.find(|s| {
+						s.start == grant.start
+							&& s.period == grant.period
+							&& s.period_count == grant.period_count
+							&& s.cliff == grant.cliff
+					})
+					.ok_or(Error::<T>::MaxScheduleOverflow)?;
+				let restored = VestingSchedule {
+					per_period: split_from.per_period.saturating_add(grant.per_period),
+					..grant
+				};
+				if let Some(funder) = Self::forget_revocable_schedule(who, split_from) {
+					<RevocableSchedules<T>>::try_mutate(who, funder, |revocable| {
+						revocable
+							.try_push(restored.clone())
+							.map_err(|_| Error::<T>::MaxScheduleOverflow)
+					})?;
+				}
+				*split_from = restored;
+			}
+		}
+		<VestingSchedules<T>>::insert(who, schedules);
+		Ok(())
+	}
+
+	/// Merge `schedules` into a single one keeping what they lock now until the last of them
+	/// ends, `None` if nothing is locked anymore.
+	fn merge_schedules(schedules: &[VestingScheduleOf<T>]) -> Option<VestingScheduleOf<T>> {
+		let now = T::BlockNumberProvider::current_block_number();
+		let locked = schedules.iter().fold(Zero::zero(), |acc: BalanceOf<T>, s| {
+			acc.saturating_add(s.locked_amount(now))
+		});
+		let end = schedules.iter().filter_map(|s| s.end()).max()?;
+		if locked.is_zero() || end <= now {
+			return None;
+		}
+		Some(VestingSchedule {
+			start: now,
+			period: end.saturating_sub(now),
+			period_count: 1,
+			per_period: locked,
+			cliff: Zero::zero(),
+		})
+	}
+
+	/// Record a bridge transfer of the already burnt `amount` in the pending transfers under the
+	/// next nonce of the bridge and announce it to the bridge oracles.
+	fn record_bridge_transfer(
+		source: T::AccountId,
+		to: [u8; 20],
+		bridge_id: u32,
+		amount: BalanceOf<T>,
+		grants: BoundedVec<VestingScheduleOf<T>, T::MaxSchedule>,
+	) {
+		let nonce = <BridgeNonces<T>>::mutate(BridgeId(bridge_id), |next_nonce| {
+			let nonce = *next_nonce;
+			*next_nonce = next_nonce.wrapping_add(1);
+			nonce
+		});
+		if !grants.is_empty() {
+			<ReservedScheduleSlots<T>>::mutate(&source, |reserved| reserved.saturating_inc());
 		}
-		locked
+		<PendingBridgeTransfers<T>>::insert(
+			BridgeId(bridge_id),
+			nonce,
+			PendingBridgeTransfer {
+				source,
+				to,
+				amount,
+				grants: grants.clone(),
+				initiated_at: T::BlockNumberProvider::current_block_number(),
+			},
+		);
+
+		Self::deposit_event(Event::BridgeInitiated {
+			to,
+			bridge_id,
+			nonce,
+			amount,
+			grants,
+		});
 	}
 
-	/// Returns locked balance based on current block number.
+	/// The vesting status of `who` at the current block, as exposed by the runtime API.
+	pub fn vesting_status(
+		who: &T::AccountId,
+	) -> runtime_api::VestingStatus<BlockNumberFor<T>, MomentOf<T>, BalanceOf<T>> {
+		let now = T::BlockNumberProvider::current_block_number();
+		let now_ms = T::Time::now();
+		let schedules = Self::vesting_schedules(who).into_inner();
+		let timed_schedules = Self::timed_vesting_schedules(who).into_inner();
+		let locked = Self::locked_balance(who);
+		runtime_api::VestingStatus {
+			locked,
+			claimable: T::Currency::balance_frozen(&FreezeReason::Vesting.into(), who).saturating_sub(locked),
+			next_unlock: schedules.iter().filter_map(|s| s.next_unlock(now)).min(),
+			end: schedules.iter().filter_map(|s| s.end()).max(),
+			timed_next_unlock: timed_schedules.iter().filter_map(|s| s.next_unlock(now_ms)).min(),
+			timed_end: timed_schedules.iter().filter_map(|s| s.end()).max(),
+			renounced: Self::renounced(who.clone()),
+			schedules,
+			timed_schedules,
+		}
+	}
+
+	/// Returns locked balance based on current block number and unix time.
 	fn locked_balance(who: &T::AccountId) -> BalanceOf<T> {
 		let now = T::BlockNumberProvider::current_block_number();
-		Self::vesting_schedules(who).iter().fold(Zero::zero(), |acc, s| {
+		Self::vesting_schedules(who).iter().fold(Self::timed_locked_balance(who), |acc, s| {
 			acc.checked_add(&s.locked_amount(now)).expect(
 					   "locked amount is a balance and can't be higher than the total balance stored inside the same integer type; qed",
 				   )
 		})
 	}
 
+	/// Returns the balance locked by the timed schedules of `who` based on current unix time.
+	fn timed_locked_balance(who: &T::AccountId) -> BalanceOf<T> {
+		let now = T::Time::now();
+		Self::timed_vesting_schedules(who).iter().fold(Zero::zero(), |acc, s| {
+			acc.checked_add(&s.locked_amount(now)).expect(
+				"locked amount is a balance and can't be higher than the total balance stored inside the same integer type; qed",
+			)
+		})
+	}
+
 	fn do_add_vesting_schedule(
 		from: &T::AccountId,
 		to: &T::AccountId,
 		schedule: VestingScheduleOf<T>,
+	) -> DispatchResult {
+		Self::do_add_schedule(from, to, schedule, |schedule| {
+			<VestingSchedules<T>>::try_mutate(to, |vesting_schedules| -> DispatchResult {
+				vesting_schedules
+					.try_push(schedule)
+					.map_err(|_| <Error<T>>::MaxScheduleOverflow)?;
+				Self::ensure_schedule_slots(to, vesting_schedules.len())
+			})
+		})
+	}
+
+	/// Fund `schedule` from `from` and freeze it on `to` along with its other schedules, `push`
+	/// storing it with those expressed in the same kind of `Moment`.
+	fn do_add_schedule<Moment: AtLeast32Bit + Copy>(
+		from: &T::AccountId,
+		to: &T::AccountId,
+		schedule: VestingSchedule<Moment, BalanceOf<T>>,
+		push: impl FnOnce(VestingSchedule<Moment, BalanceOf<T>>) -> DispatchResult,
 	) -> DispatchResult {
 		ensure!(from != to, Error::<T>::VestingToSelf);
 
@@ -449,22 +1531,361 @@ impl<T: Config> Pallet<T> {
 			.checked_add(&schedule_amount)
 			.ok_or(Error::<T>::NumOverflow)?;
 
-		<VestingSchedules<T>>::try_mutate(to, |vesting_schedules| -> DispatchResult {
-			vesting_schedules
-				.try_push(schedule)
-				.map_err(|_| <Error<T>>::MaxScheduleOverflow)?;
+		with_storage_layer(|| {
+			push(schedule)?;
+			T::Currency::transfer(from, to, schedule_amount, Preservation::Expendable)?;
+			T::Currency::set_freeze(&FreezeReason::Vesting.into(), to, total_amount)
+		})
+	}
+
+	/// Remove the schedule of `who` at `schedule_index` and send its unvested part to
+	/// `collector`. Returns the amount actually collected.
+	fn do_cancel_schedule(
+		who: &T::AccountId,
+		collector: &T::AccountId,
+		schedule_index: u32,
+	) -> Result<BalanceOf<T>, DispatchError> {
+		let now = T::BlockNumberProvider::current_block_number();
+		let mut schedules = Self::vesting_schedules(who);
+		ensure!(
+			(schedule_index as usize) < schedules.len(),
+			Error::<T>::InvalidScheduleIndex
+		);
+		let unvested_amount = schedules.remove(schedule_index as usize).locked_amount(now);
+		if schedules.is_empty() {
+			<VestingSchedules<T>>::remove(who);
+		} else {
+			<VestingSchedules<T>>::insert(who, schedules);
+		}
+		Self::collect_unvested(who, collector, unvested_amount)
+	}
 
-			T::Currency::transfer(from, to, schedule_amount, ExistenceRequirement::AllowDeath)?;
-			T::Currency::set_lock(VESTING_LOCK_ID, to, total_amount, WithdrawReasons::all());
+	/// Send the `unvested_amount` of a schedule removed from `who` to `collector`, as far as the
+	/// balance of `who` allows.
+	fn collect_unvested(
+		who: &T::AccountId,
+		collector: &T::AccountId,
+		unvested_amount: BalanceOf<T>,
+	) -> Result<BalanceOf<T>, DispatchError> {
+		// the lock now only covers the remaining schedules which leaves the canceled one
+		// free to be collected
+		let locked_amount_left = Self::do_claim(who)?;
+		let free_balance = T::Currency::balance(who);
+		let collectable_funds = unvested_amount.min(free_balance.saturating_sub(locked_amount_left));
+		T::Currency::transfer(who, collector, collectable_funds, Preservation::Expendable)?;
+
+		Ok(collectable_funds)
+	}
 
-			Ok(())
-		})?;
+	/// Ensure the schedule of `who` found at `index` is `expected`.
+	fn ensure_schedule_at(who: &T::AccountId, index: u32, expected: &VestingScheduleOf<T>) -> DispatchResult {
+		let schedules = Self::vesting_schedules(who);
+		let schedule = schedules.get(index as usize).ok_or(Error::<T>::InvalidScheduleIndex)?;
+		ensure!(schedule == expected, Error::<T>::ScheduleMismatch);
+		Ok(())
+	}
+
+	/// Stop tracking one revocable schedule of `who` equal to `schedule`, if any, as it is being
+	/// removed by other means than a revocation. Returns the funder of the schedule, if any.
+	fn forget_revocable_schedule(who: &T::AccountId, schedule: &VestingScheduleOf<T>) -> Option<T::AccountId> {
+		let funder = <RevocableSchedules<T>>::iter_prefix(who)
+			.filter(|(_, schedules)| schedules.contains(schedule))
+			.map(|(funder, _)| funder)
+			.next();
+		if let Some(ref funder) = funder {
+			<RevocableSchedules<T>>::mutate_exists(who, funder, |maybe_schedules| {
+				if let Some(schedules) = maybe_schedules {
+					if let Some(position) = schedules.iter().position(|s| s == schedule) {
+						schedules.remove(position);
+					}
+					if schedules.is_empty() {
+						*maybe_schedules = None;
+					}
+				}
+			});
+		}
+		funder
+	}
+
+	/// Move the schedules of `from` at `schedule_indices`, or all of them if `None`, to `to`
+	/// along with their locked balance. Revocable schedules stay revocable by their funder.
+	fn do_transfer_vesting_schedules(
+		from: &T::AccountId,
+		to: &T::AccountId,
+		schedule_indices: Option<BoundedVec<u32, T::MaxSchedule>>,
+	) -> DispatchResult {
+		ensure!(from != to, Error::<T>::VestingToSelf);
+		// a renounced grant must stay out of reach of the cancel origin, and a grant must not
+		// escape it by moving to a renounced account. Renunciation applies to all the schedules
+		// of an account so renounced grants can only join an account without other grants.
+		if Self::renounced(from.clone()) {
+			ensure!(
+				Self::renounced(to.clone())
+					|| (Self::vesting_schedules(to).is_empty() && Self::timed_vesting_schedules(to).is_empty()),
+				Error::<T>::Renounced
+			);
+			Renounced::<T>::insert(to.clone(), true);
+		} else {
+			ensure!(!Self::renounced(to.clone()), Error::<T>::Renounced);
+		}
+
+		let now = T::BlockNumberProvider::current_block_number();
+		let mut schedules = Self::vesting_schedules(from);
+		// indices only select block based schedules, the timed ones move along when all do
+		let (mut indices, timed): (Vec<u32>, Vec<TimedVestingScheduleOf<T>>) = match schedule_indices {
+			Some(indices) => (indices.into_inner(), Vec::new()),
+			None => (
+				(0..schedules.len() as u32).collect(),
+				Self::timed_vesting_schedules(from).into_inner(),
+			),
+		};
+		ensure!(!indices.is_empty() || !timed.is_empty(), Error::<T>::EmptySchedules);
+		indices.sort_unstable_by(|a, b| b.cmp(a));
+		indices.dedup();
+
+		let mut moved = Vec::with_capacity(indices.len());
+		for index in indices {
+			ensure!((index as usize) < schedules.len(), Error::<T>::InvalidScheduleIndex);
+			moved.push(schedules.remove(index as usize));
+		}
+		// keep the schedules in the order they had for `from`
+		moved.reverse();
+
+		let now_ms = T::Time::now();
+		let moved_amount = moved.iter().fold(Zero::zero(), |acc: BalanceOf<T>, s| {
+			acc.saturating_add(s.locked_amount(now))
+		});
+		let moved_amount = timed
+			.iter()
+			.fold(moved_amount, |acc, s| acc.saturating_add(s.locked_amount(now_ms)));
+		if !moved.is_empty() {
+			<VestingSchedules<T>>::try_mutate(to, |to_schedules| -> DispatchResult {
+				for schedule in moved.iter() {
+					to_schedules
+						.try_push(schedule.clone())
+						.map_err(|_| Error::<T>::MaxScheduleOverflow)?;
+				}
+				Self::ensure_schedule_slots(to, to_schedules.len())
+			})?;
+		}
+		if !timed.is_empty() {
+			<TimedVestingSchedules<T>>::try_mutate(to, |to_schedules| -> DispatchResult {
+				for schedule in timed.iter() {
+					to_schedules
+						.try_push(schedule.clone())
+						.map_err(|_| Error::<T>::MaxScheduleOverflow)?;
+				}
+				Ok(())
+			})?;
+			<TimedVestingSchedules<T>>::remove(from);
+		}
+		if schedules.is_empty() {
+			<VestingSchedules<T>>::remove(from);
+		} else {
+			<VestingSchedules<T>>::insert(from, schedules);
+		}
+
+		for schedule in moved.iter() {
+			if let Some(funder) = Self::forget_revocable_schedule(from, schedule) {
+				<RevocableSchedules<T>>::try_mutate(to, funder, |schedules| {
+					schedules
+						.try_push(schedule.clone())
+						.map_err(|_| Error::<T>::MaxScheduleOverflow)
+				})?;
+			}
+		}
 
+		// the freeze of `from` now only covers its remaining schedules which frees the moved
+		// balance, then the freeze of `to` is extended to cover it
+		Self::do_claim(from)?;
+		T::Currency::transfer(from, to, moved_amount, Preservation::Expendable)?;
+		Self::do_claim(to)?;
+
+		Self::deposit_event(Event::VestingSchedulesTransferred {
+			from: from.clone(),
+			to: to.clone(),
+			count: moved.len().saturating_add(timed.len()) as u32,
+			amount: moved_amount,
+		});
 		Ok(())
 	}
 
+	/// Split the schedules of `from` according to `selection`, burn the locked balance of the
+	/// split off schedules and record their bridge transfer.
+	fn do_bridge_vesting_schedules(
+		from: T::AccountId,
+		eth_address: [u8; 20],
+		bridge_id: u32,
+		selection: BridgeSelection<T>,
+	) -> DispatchResult {
+		let details = Self::active_bridge(bridge_id)?;
+		let now = T::BlockNumberProvider::current_block_number();
+		let mut schedules = Self::vesting_schedules(&from).into_inner();
+
+		let mut bridged = Vec::new();
+		match selection {
+			BridgeSelection::Schedules(indices) => {
+				let mut indices = indices.into_inner();
+				ensure!(!indices.is_empty(), Error::<T>::EmptySchedules);
+				indices.sort_unstable_by(|a, b| b.0.cmp(&a.0));
+				indices.dedup_by_key(|(index, _)| *index);
+				for (index, expected) in indices {
+					ensure!((index as usize) < schedules.len(), Error::<T>::InvalidScheduleIndex);
+					let schedule = schedules.remove(index as usize);
+					ensure!(schedule == expected, Error::<T>::ScheduleMismatch);
+					bridged.push(schedule);
+				}
+				// keep the schedules in the order they had for `from`
+				bridged.reverse();
+				for schedule in bridged.iter() {
+					Self::forget_revocable_schedule(&from, schedule);
+				}
+			}
+			BridgeSelection::Amount(amount) => {
+				let total_locked = schedules.iter().fold(Zero::zero(), |acc: BalanceOf<T>, s| {
+					acc.saturating_add(s.locked_amount(now))
+				});
+				ensure!(
+					!amount.is_zero() && amount <= total_locked,
+					Error::<T>::InvalidBridgeAmount
+				);
+				let last = schedules.iter().rposition(|s| !s.locked_amount(now).is_zero());
+				let mut unassigned = amount;
+				for (index, schedule) in schedules.iter_mut().enumerate() {
+					let locked = schedule.locked_amount(now);
+					if locked.is_zero() {
+						continue;
+					}
+					// each schedule takes its share of the amount, the last one what the rounding
+					// of the others left
+					let share = if Some(index) == last {
+						unassigned
+					} else {
+						multiply_by_rational_with_rounding(
+							amount.unique_saturated_into(),
+							locked.unique_saturated_into(),
+							total_locked.unique_saturated_into(),
+							Rounding::Down,
+						)
+						.ok_or(Error::<T>::NumOverflow)?
+						.unique_saturated_into()
+					};
+					unassigned = unassigned.saturating_sub(share);
+					let unrealized_periods = locked / schedule.per_period;
+					let per_period = (share / unrealized_periods).min(schedule.per_period);
+					if per_period.is_zero() {
+						continue;
+					}
+					let part = VestingSchedule {
+						per_period,
+						..schedule.clone()
+					};
+					let rest = VestingSchedule {
+						per_period: schedule.per_period.saturating_sub(per_period),
+						..schedule.clone()
+					};
+					// the part left on the parachain stays revocable by its funder
+					if let Some(funder) = Self::forget_revocable_schedule(&from, schedule) {
+						if !rest.per_period.is_zero() {
+							<RevocableSchedules<T>>::try_mutate(&from, funder, |revocable| {
+								revocable
+									.try_push(rest.clone())
+									.map_err(|_| Error::<T>::MaxScheduleOverflow)
+							})?;
+						}
+					}
+					*schedule = rest;
+					bridged.push(part);
+				}
+				ensure!(!bridged.is_empty(), Error::<T>::InvalidBridgeAmount);
+				// a share which is not a multiple of the periods left of its schedule cannot be split off
+				let bridged_amount = bridged.iter().fold(Zero::zero(), |acc: BalanceOf<T>, s| {
+					acc.saturating_add(s.locked_amount(now))
+				});
+				ensure!(bridged_amount == amount, Error::<T>::InexactBridgeAmount);
+				schedules.retain(|s| !s.per_period.is_zero());
+			}
+		}
+
+		let amount = bridged.iter().fold(Zero::zero(), |acc: BalanceOf<T>, s| {
+			acc.saturating_add(s.locked_amount(now))
+		});
+		if schedules.is_empty() {
+			<VestingSchedules<T>>::remove(&from);
+		} else {
+			<VestingSchedules<T>>::insert(&from, BoundedVec::truncate_from(schedules));
+		}
+
+		// the freeze now only covers the schedules left which frees the bridged balance
+		let locked_amount_left = Self::do_claim(&from)?;
+		let bridgeable_funds = amount.min(T::Currency::balance(&from).saturating_sub(locked_amount_left));
+		if bridgeable_funds.is_zero() {
+			Self::deposit_event(Event::NoVestedFundsToBridgeAfterClaim);
+			return Ok(());
+		}
+		Self::consume_bridge_allowance(bridge_id, &details, bridgeable_funds)?;
+
+		Self::burn_bridged(&from, bridgeable_funds)?;
+
+		Self::record_bridge_transfer(
+			from,
+			eth_address,
+			bridge_id,
+			bridgeable_funds,
+			BoundedVec::truncate_from(bridged),
+		);
+		Ok(())
+	}
+
+	/// Burn the `amount` of `who` leaving for another chain, which must not be frozen.
+	fn burn_bridged(who: &T::AccountId, amount: BalanceOf<T>) -> DispatchResult {
+		T::Currency::burn_from(
+			who,
+			amount,
+			Preservation::Expendable,
+			Precision::Exact,
+			Fortitude::Polite,
+		)
+		.map_err(|_| Error::<T>::FailedToSettleBridge)?;
+		Ok(())
+	}
+
+	/// Stop tracking one revocable schedule of `who` equal to each of the pruned `vested`
+	/// schedules, going over the funders of `who` only once.
+	fn forget_vested_revocable_schedules(who: &T::AccountId, mut vested: Vec<VestingScheduleOf<T>>) {
+		if vested.is_empty() {
+			return;
+		}
+		let revocable: Vec<_> = <RevocableSchedules<T>>::iter_prefix(who).collect();
+		for (funder, mut schedules) in revocable {
+			let initial_len = schedules.len();
+			schedules.retain(|schedule| match vested.iter().position(|s| s == schedule) {
+				Some(position) => {
+					vested.swap_remove(position);
+					false
+				}
+				None => true,
+			});
+			if schedules.is_empty() {
+				<RevocableSchedules<T>>::remove(who, &funder);
+			} else if schedules.len() < initial_len {
+				<RevocableSchedules<T>>::insert(who, &funder, schedules);
+			}
+			if vested.is_empty() {
+				break;
+			}
+		}
+	}
+
+	/// Stop tracking all the revocable schedules of `who` once its schedules are removed.
+	fn forget_revocable_schedules(who: &T::AccountId) {
+		let _ = <RevocableSchedules<T>>::clear_prefix(who, T::MaxSchedule::get(), None);
+	}
+
 	/// Returns `Ok(amount)` if valid schedule, or error.
-	fn ensure_valid_vesting_schedule(schedule: &VestingScheduleOf<T>) -> Result<BalanceOf<T>, Error<T>> {
+	fn ensure_valid_vesting_schedule<Moment: AtLeast32Bit + Copy>(
+		schedule: &VestingSchedule<Moment, BalanceOf<T>>,
+	) -> Result<BalanceOf<T>, Error<T>> {
 		ensure!(!schedule.period.is_zero(), Error::<T>::ZeroVestingPeriod);
 		ensure!(!schedule.period_count.is_zero(), Error::<T>::ZeroVestingPeriodCount);
 		ensure!(schedule.end().is_some(), Error::<T>::NumOverflow);
@@ -472,3 +1893,66 @@ impl<T: Config> Pallet<T> {
 		schedule.total_amount().ok_or(Error::<T>::NumOverflow)
 	}
 }
+
+impl<T: Config> VestedTransfer<T::AccountId, BlockNumberFor<T>, BalanceOf<T>> for Pallet<T> {
+	/// Vest `amount` as a single schedule of `period_count` equal parts. What cannot be evenly
+	/// split over the periods is too small to matter and is transferred as free balance, so is
+	/// an `amount` smaller than `period_count` which is transferred without any schedule.
+	fn vested_transfer(
+		source: &T::AccountId,
+		target: &T::AccountId,
+		amount: BalanceOf<T>,
+		start: BlockNumberFor<T>,
+		period: BlockNumberFor<T>,
+		period_count: u32,
+	) -> DispatchResult {
+		ensure!(!period_count.is_zero(), Error::<T>::ZeroVestingPeriodCount);
+		let schedule = VestingSchedule {
+			start,
+			period,
+			period_count,
+			per_period: amount / BalanceOf::<T>::from(period_count),
+			cliff: Zero::zero(),
+		};
+		let vested_amount = Self::ensure_valid_vesting_schedule(&schedule)?;
+		if schedule.per_period.is_zero() {
+			return T::Currency::transfer(source, target, amount, Preservation::Expendable).map(|_| ());
+		}
+		Self::do_add_vesting_schedule(source, target, schedule.clone())?;
+
+		let remainder = amount.saturating_sub(vested_amount);
+		if !remainder.is_zero() {
+			T::Currency::transfer(source, target, remainder, Preservation::Expendable)?;
+		}
+
+		Self::deposit_event(Event::VestingScheduleAdded(source.clone(), target.clone(), schedule));
+		Ok(())
+	}
+}
+
+impl<T: Config> BridgeTransfer<T::AccountId, BalanceOf<T>> for Pallet<T> {
+	/// The transfer is announced with a `BridgeInitiated` event carrying no grants, so that the
+	/// whole amount is credited as free balance on the remote chain. It cannot be refunded.
+	fn bridge_transfer(
+		source: &T::AccountId,
+		eth_address: [u8; 20],
+		bridge_id: u32,
+		amount: BalanceOf<T>,
+	) -> DispatchResult {
+		let details = Self::active_bridge(bridge_id)?;
+		Self::consume_bridge_allowance(bridge_id, &details, amount)?;
+
+		Self::burn_bridged(source, amount)?;
+
+		Self::record_bridge_transfer(source.clone(), eth_address, bridge_id, amount, BoundedVec::default());
+		Ok(())
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn register_bridge(bridge_id: u32) {
+		Bridges::<T>::insert(
+			BridgeId(bridge_id),
+			BridgeDetails::new(bridge_id.into(), BoundedVec::default()),
+		);
+	}
+}
@@ -0,0 +1,307 @@
+/*
+ * This file is part of the Nodle Chain distributed at https://github.com/NodleCode/chain
+ * Copyright (C) 2020-2024  Nodle International
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program.  If not, see <http://www.gnu.org/licenses/>.
+ */
+
+//! Storage migrations of the grants pallet.
+
+use super::*;
+use frame_support::{
+	traits::{Get, OnRuntimeUpgrade},
+	weights::Weight,
+};
+use sp_std::marker::PhantomData;
+
+pub mod v2 {
+	use super::*;
+
+	/// A vesting schedule as it was stored before cliffs were introduced.
+	#[derive(Decode)]
+	pub struct OldVestingSchedule<BlockNumber, Balance> {
+		pub start: BlockNumber,
+		pub period: BlockNumber,
+		pub period_count: u32,
+		pub per_period: Balance,
+	}
+
+	pub type OldVestingScheduleOf<T> = OldVestingSchedule<BlockNumberFor<T>, BalanceOf<T>>;
+
+	/// Add a zero cliff to all the existing vesting schedules, leaving their unlocking unchanged.
+	pub struct MigrateToV2<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV2<T> {
+		fn on_runtime_upgrade() -> Weight {
+			if StorageVersion::<T>::get() >= Releases::V2 {
+				log::info!(target: "pallet_grants", "MigrateToV2 skipped, storage already at V2");
+				return T::DbWeight::get().reads(1);
+			}
+
+			let mut translated = 0u64;
+			VestingSchedules::<T>::translate::<BoundedVec<OldVestingScheduleOf<T>, T::MaxSchedule>, _>(|_, old| {
+				translated.saturating_inc();
+				let schedules = old
+					.into_iter()
+					.map(|s| VestingSchedule {
+						start: s.start,
+						period: s.period,
+						period_count: s.period_count,
+						per_period: s.per_period,
+						cliff: Zero::zero(),
+					})
+					.collect::<Vec<_>>();
+				Some(BoundedVec::truncate_from(schedules))
+			});
+			StorageVersion::<T>::put(Releases::V2);
+
+			log::info!(target: "pallet_grants", "MigrateToV2 added cliffs to {} accounts", translated);
+			T::DbWeight::get().reads_writes(translated.saturating_add(1), translated.saturating_add(1))
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+			Ok(VestingSchedules::<T>::count().encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+			let count = u32::decode(&mut &state[..]).map_err(|_| "Failed to decode the pre upgrade state")?;
+			ensure!(
+				StorageVersion::<T>::get() >= Releases::V2,
+				"Storage version not updated to V2"
+			);
+			ensure!(
+				VestingSchedules::<T>::iter_values().count() as u32 == count,
+				"Some vesting schedules failed to migrate"
+			);
+			Ok(())
+		}
+	}
+}
+
+pub mod v3 {
+	use super::*;
+
+	/// A bridge as it was stored before statuses, oracles and caps were introduced.
+	#[derive(Decode)]
+	pub struct OldBridgeDetails {
+		pub chain_id: u64,
+		pub name: BoundedVec<u8, ConstU32<BRIDGE_NAME_MAX_LENGTH>>,
+	}
+
+	/// Turn the existing bridges into active bridges without oracles nor caps.
+	pub struct MigrateToV3<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV3<T> {
+		fn on_runtime_upgrade() -> Weight {
+			if StorageVersion::<T>::get() >= Releases::V3 {
+				log::info!(target: "pallet_grants", "MigrateToV3 skipped, storage already at V3");
+				return T::DbWeight::get().reads(1);
+			}
+
+			let mut translated = 0u64;
+			Bridges::<T>::translate::<OldBridgeDetails, _>(|_, old| {
+				translated.saturating_inc();
+				Some(BridgeDetails::new(old.chain_id, old.name))
+			});
+			StorageVersion::<T>::put(Releases::V3);
+
+			log::info!(target: "pallet_grants", "MigrateToV3 migrated {} bridges", translated);
+			T::DbWeight::get().reads_writes(translated.saturating_add(1), translated.saturating_add(1))
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+			Ok((Bridges::<T>::iter_keys().count() as u32).encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+			let count = u32::decode(&mut &state[..]).map_err(|_| "Failed to decode the pre upgrade state")?;
+			ensure!(
+				StorageVersion::<T>::get() >= Releases::V3,
+				"Storage version not updated to V3"
+			);
+			ensure!(
+				Bridges::<T>::iter_values().count() as u32 == count,
+				"Some bridges failed to migrate"
+			);
+			Ok(())
+		}
+	}
+}
+
+pub mod v4 {
+	use super::*;
+	use frame_support::{
+		migrations::{MigrationId, SteppedMigration, SteppedMigrationError},
+		traits::{InspectLockableCurrency, LockableCurrency},
+		weights::WeightMeter,
+	};
+
+	const PALLET_MIGRATIONS_ID: &[u8; 17] = b"pallet-grants-mbm";
+
+	/// The last account migrated, along with the storage map it was taken from.
+	#[derive(Encode, Decode, MaxEncodedLen, Clone, PartialEq, Eq, RuntimeDebug)]
+	pub enum MigrationCursor<AccountId> {
+		Schedules(AccountId),
+		TimedSchedules(AccountId),
+	}
+
+	/// Replace the `nvesting` locks of the accounts with vesting schedules by freezes of the same
+	/// amount, a few accounts per block. `OldCurrency` is the currency which used to hold the
+	/// locks, usually the same balances pallet as the one now holding the freezes.
+	pub struct MigrateToV4<T, OldCurrency>(PhantomData<(T, OldCurrency)>);
+
+	impl<T: Config, OldCurrency> MigrateToV4<T, OldCurrency>
+	where
+		OldCurrency: InspectLockableCurrency<T::AccountId, Balance = BalanceOf<T>> + LockableCurrency<T::AccountId>,
+	{
+		/// Weight of finding and migrating one account.
+		fn account_weight() -> Weight {
+			T::DbWeight::get().reads_writes(4, 3)
+		}
+
+		fn first_timed_account() -> Option<MigrationCursor<T::AccountId>> {
+			TimedVestingSchedules::<T>::iter_keys()
+				.next()
+				.map(MigrationCursor::TimedSchedules)
+		}
+
+		fn next_account(cursor: Option<MigrationCursor<T::AccountId>>) -> Option<MigrationCursor<T::AccountId>> {
+			match cursor {
+				None => VestingSchedules::<T>::iter_keys()
+					.next()
+					.map(MigrationCursor::Schedules)
+					.or_else(Self::first_timed_account),
+				Some(MigrationCursor::Schedules(last)) => {
+					VestingSchedules::<T>::iter_keys_from(VestingSchedules::<T>::hashed_key_for(last))
+						.next()
+						.map(MigrationCursor::Schedules)
+						.or_else(Self::first_timed_account)
+				}
+				Some(MigrationCursor::TimedSchedules(last)) => {
+					TimedVestingSchedules::<T>::iter_keys_from(TimedVestingSchedules::<T>::hashed_key_for(last))
+						.next()
+						.map(MigrationCursor::TimedSchedules)
+				}
+			}
+		}
+
+		fn migrate_account(who: &T::AccountId) {
+			// accounts with both kinds of schedules are met twice, their lock is gone the second time
+			let locked = OldCurrency::balance_locked(VESTING_LOCK_ID, who);
+			if locked.is_zero() {
+				return;
+			}
+			// the lock is only removed once the freeze is in place so that the vested funds
+			// never become transferable
+			match T::Currency::set_freeze(&FreezeReason::Vesting.into(), who, locked) {
+				Ok(()) => OldCurrency::remove_lock(VESTING_LOCK_ID, who),
+				Err(err) => {
+					log::error!(
+						target: "pallet_grants",
+						"MigrateToV4 failed to freeze the vested funds of {:?}: {:?}",
+						who,
+						err
+					);
+				}
+			}
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn vested_accounts() -> BTreeSet<T::AccountId> {
+			VestingSchedules::<T>::iter_keys()
+				.chain(TimedVestingSchedules::<T>::iter_keys())
+				.collect()
+		}
+	}
+
+	impl<T: Config, OldCurrency> SteppedMigration for MigrateToV4<T, OldCurrency>
+	where
+		OldCurrency: InspectLockableCurrency<T::AccountId, Balance = BalanceOf<T>> + LockableCurrency<T::AccountId>,
+	{
+		type Cursor = MigrationCursor<T::AccountId>;
+		type Identifier = MigrationId<17>;
+
+		fn id() -> Self::Identifier {
+			MigrationId {
+				pallet_id: *PALLET_MIGRATIONS_ID,
+				version_from: 3,
+				version_to: 4,
+			}
+		}
+
+		fn step(
+			mut cursor: Option<Self::Cursor>,
+			meter: &mut WeightMeter,
+		) -> Result<Option<Self::Cursor>, SteppedMigrationError> {
+			let required = Self::account_weight();
+			if meter.remaining().any_lt(required) {
+				return Err(SteppedMigrationError::InsufficientWeight { required });
+			}
+			if cursor.is_none() && StorageVersion::<T>::get() >= Releases::V4 {
+				log::info!(target: "pallet_grants", "MigrateToV4 skipped, storage already at V4");
+				return Ok(None);
+			}
+
+			while meter.try_consume(required).is_ok() {
+				let Some(next) = Self::next_account(cursor) else {
+					StorageVersion::<T>::put(Releases::V4);
+					log::info!(target: "pallet_grants", "MigrateToV4 moved all locks to freezes");
+					return Ok(None);
+				};
+				let (MigrationCursor::Schedules(who) | MigrationCursor::TimedSchedules(who)) = &next;
+				Self::migrate_account(who);
+				cursor = Some(next);
+			}
+			Ok(cursor)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+			let locks = Self::vested_accounts()
+				.into_iter()
+				.map(|who| {
+					let locked = OldCurrency::balance_locked(VESTING_LOCK_ID, &who);
+					(who, locked)
+				})
+				.filter(|(_, locked)| !locked.is_zero())
+				.collect::<Vec<_>>();
+			Ok(locks.encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+			let locks = Vec::<(T::AccountId, BalanceOf<T>)>::decode(&mut &state[..])
+				.map_err(|_| "Failed to decode the pre upgrade state")?;
+			ensure!(
+				StorageVersion::<T>::get() >= Releases::V4,
+				"Storage version not updated to V4"
+			);
+			for (who, locked) in locks {
+				ensure!(
+					T::Currency::balance_frozen(&FreezeReason::Vesting.into(), &who) == locked,
+					"Some vesting locks failed to migrate to freezes"
+				);
+				ensure!(
+					OldCurrency::balance_locked(VESTING_LOCK_ID, &who).is_zero(),
+					"Some vesting locks were left in place"
+				);
+			}
+			Ok(())
+		}
+	}
+}
@@ -20,7 +20,7 @@
 
 use super::*;
 use crate::{self as vesting};
-use frame_support::{ord_parameter_types, parameter_types};
+use frame_support::{ord_parameter_types, parameter_types, weights::RuntimeDbWeight};
 use frame_system::EnsureSignedBy;
 
 use sp_core::H256;
@@ -32,12 +32,14 @@ frame_support::construct_runtime!(
 	pub enum Test {
 		System: frame_system::{Pallet, Call, Config<T>, Storage, Event<T>},
 		PalletBalances: pallet_balances::{Pallet, Call, Config<T>, Storage, Event<T>},
-		Vesting: vesting::{Pallet, Call, Storage, Event<T>, Config<T>},
+		Timestamp: pallet_timestamp::{Pallet, Call, Storage},
+		Vesting: vesting::{Pallet, Call, Storage, Event<T>, Config<T>, FreezeReason},
 	}
 );
 
 parameter_types! {
 	pub const BlockHashCount: u64 = 250;
+	pub static DbWeight: RuntimeDbWeight = RuntimeDbWeight { read: 0, write: 0 };
 }
 
 pub type AccountId = u128;
@@ -59,7 +61,7 @@ impl frame_system::Config for Test {
 	type OnNewAccount = ();
 	type OnKilledAccount = ();
 	type Block = Block;
-	type DbWeight = ();
+	type DbWeight = DbWeight;
 	type BaseCallFilter = frame_support::traits::Everything;
 	type OnSetCode = ();
 	type SystemWeightInfo = ();
@@ -90,29 +92,46 @@ impl pallet_balances::Config for Test {
 	type MaxReserves = ();
 	type ReserveIdentifier = [u8; 8];
 	type WeightInfo = ();
-	type FreezeIdentifier = [u8; 8];
-	type MaxFreezes = ();
+	type FreezeIdentifier = RuntimeFreezeReason;
+	type MaxFreezes = ConstU32<1>;
 	type RuntimeHoldReason = ();
-	type RuntimeFreezeReason = ();
+	type RuntimeFreezeReason = RuntimeFreezeReason;
+}
+
+impl pallet_timestamp::Config for Test {
+	type Moment = u64;
+	type OnTimestampSet = ();
+	type MinimumPeriod = frame_support::traits::ConstU64<1>;
+	type WeightInfo = ();
 }
 
 ord_parameter_types! {
 	pub const ALICE: AccountId = 1;
 	pub const BOB: AccountId = 2;
 	pub const CancelOrigin: AccountId = 42;
+	pub const BridgeOracle: AccountId = 43;
 }
 
 parameter_types! {
 	pub static MaxSchedule: u32 = 2;
+	pub const MaxBatchSchedules: u32 = 4;
+	pub const MaxBridgeOracles: u32 = 2;
+	pub const BridgeTransferTimeout: u64 = 100;
 }
 
 impl Config for Test {
 	type RuntimeEvent = RuntimeEvent;
+	type RuntimeFreezeReason = RuntimeFreezeReason;
 	type Currency = PalletBalances;
 	type CancelOrigin = EnsureSignedBy<CancelOrigin, AccountId>;
+	type BridgeOracleOrigin = EnsureSignedBy<BridgeOracle, AccountId>;
+	type BridgeTransferTimeout = BridgeTransferTimeout;
 	type MaxSchedule = MaxSchedule;
+	type MaxBatchSchedules = MaxBatchSchedules;
+	type MaxBridgeOracles = MaxBridgeOracles;
 	type WeightInfo = ();
 	type BlockNumberProvider = frame_system::Pallet<Test>;
+	type Time = Timestamp;
 }
 
 pub(crate) fn balances(who: &AccountId) -> (Balance, Balance) {
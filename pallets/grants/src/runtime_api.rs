@@ -0,0 +1,61 @@
+/*
+ * This file is part of the Nodle Chain distributed at https://github.com/NodleCode/chain
+ * Copyright (C) 2020-2024  Nodle International
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program.  If not, see <http://www.gnu.org/licenses/>.
+ */
+
+//! Runtime API of the grants pallet, letting clients query the vesting status of an account
+//! as computed by the runtime instead of re-implementing the vesting logic.
+
+use super::*;
+use codec::Codec;
+
+/// The vesting status of an account at a given block.
+#[derive(Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+pub struct VestingStatus<BlockNumber, Moment, Balance> {
+	/// All the vesting schedules of the account
+	pub schedules: Vec<VestingSchedule<BlockNumber, Balance>>,
+	/// All the vesting schedules of the account expressed in unix milliseconds
+	pub timed_schedules: Vec<VestingSchedule<Moment, Balance>>,
+	/// The amount still locked by both kinds of schedules
+	pub locked: Balance,
+	/// The amount vested but still locked until the account claims it
+	pub claimable: Balance,
+	/// The next block at which some amount gets vested, if any
+	pub next_unlock: Option<BlockNumber>,
+	/// The block at which all the schedules are fully vested, if any
+	pub end: Option<BlockNumber>,
+	/// The next unix time at which some amount of the timed schedules gets vested, if any
+	pub timed_next_unlock: Option<Moment>,
+	/// The unix time at which all the timed schedules are fully vested, if any
+	pub timed_end: Option<Moment>,
+	/// Whether the schedules of the account can no longer be canceled
+	pub renounced: bool,
+}
+
+sp_api::decl_runtime_apis! {
+	/// The API to query the vesting status of accounts.
+	pub trait GrantsApi<AccountId, BlockNumber, Moment, Balance>
+	where
+		AccountId: Codec,
+		BlockNumber: Codec,
+		Moment: Codec,
+		Balance: Codec,
+	{
+		/// Returns the vesting status of `who`.
+		fn vesting_status(who: AccountId) -> VestingStatus<BlockNumber, Moment, Balance>;
+	}
+}
@@ -19,14 +19,16 @@
 //! Unit tests for the vesting module.
 
 use super::*;
-use frame_support::{assert_err, assert_noop, assert_ok, traits::WithdrawReasons};
+use frame_support::{
+	assert_err, assert_noop, assert_ok,
+	traits::{fungible::InspectFreeze, Currency, WithdrawReasons},
+};
 use hex_literal::hex;
 use mock::{
-	context_events, CancelOrigin, ExtBuilder, PalletBalances, RuntimeEvent as TestEvent, RuntimeOrigin, System,
-	Test as Runtime, Vesting, ALICE, BOB,
+	context_events, BridgeOracle, BridgeTransferTimeout, CancelOrigin, DbWeight, ExtBuilder, MaxSchedule,
+	PalletBalances, RuntimeEvent as TestEvent, RuntimeOrigin, System, Test as Runtime, Timestamp, Vesting, ALICE, BOB,
 };
-use pallet_balances::{BalanceLock, Reasons};
-use sp_runtime::DispatchError::BadOrigin;
+use sp_runtime::{bounded_vec, BuildStorage, DispatchError::BadOrigin};
 
 #[test]
 fn check_releases_default_config() {
@@ -46,6 +48,7 @@ fn add_vesting_schedule_works() {
 			period: 10u64,
 			period_count: 1u32,
 			per_period: 100u64,
+			cliff: 0u64,
 		};
 		assert_ok!(Vesting::add_vesting_schedule(
 			RuntimeOrigin::signed(ALICE::get()),
@@ -67,6 +70,7 @@ fn add_new_vesting_schedule_merges_with_current_locked_balance_and_until() {
 			period: 10u64,
 			period_count: 2u32,
 			per_period: 10u64,
+			cliff: 0u64,
 		};
 		assert_ok!(Vesting::add_vesting_schedule(
 			RuntimeOrigin::signed(ALICE::get()),
@@ -81,6 +85,7 @@ fn add_new_vesting_schedule_merges_with_current_locked_balance_and_until() {
 			period: 13u64,
 			period_count: 1u32,
 			per_period: 7u64,
+			cliff: 0u64,
 		};
 		assert_ok!(Vesting::add_vesting_schedule(
 			RuntimeOrigin::signed(ALICE::get()),
@@ -89,12 +94,8 @@ fn add_new_vesting_schedule_merges_with_current_locked_balance_and_until() {
 		));
 
 		assert_eq!(
-			PalletBalances::locks(BOB::get()).to_vec().pop(),
-			Some(BalanceLock {
-				id: VESTING_LOCK_ID,
-				amount: 17u64,
-				reasons: Reasons::All,
-			})
+			PalletBalances::balance_frozen(&FreezeReason::Vesting.into(), &BOB::get()),
+			17u64
 		);
 	});
 }
@@ -107,6 +108,7 @@ fn cannot_use_fund_if_not_claimed() {
 			period: 10u64,
 			period_count: 1u32,
 			per_period: 50u64,
+			cliff: 0u64,
 		};
 		assert_ok!(Vesting::add_vesting_schedule(
 			RuntimeOrigin::signed(ALICE::get()),
@@ -125,6 +127,7 @@ fn add_vesting_schedule_fails_if_zero_period_or_count() {
 			period: 0u64,
 			period_count: 1u32,
 			per_period: 100u64,
+			cliff: 0u64,
 		};
 		assert_err!(
 			Vesting::add_vesting_schedule(RuntimeOrigin::signed(ALICE::get()), BOB::get(), schedule),
@@ -136,6 +139,7 @@ fn add_vesting_schedule_fails_if_zero_period_or_count() {
 			period: 1u64,
 			period_count: 0u32,
 			per_period: 100u64,
+			cliff: 0u64,
 		};
 		assert_err!(
 			Vesting::add_vesting_schedule(RuntimeOrigin::signed(ALICE::get()), BOB::get(), schedule),
@@ -153,6 +157,7 @@ fn add_vesting_schedule_fails_if_transfer_err() {
 			period: 1u64,
 			period_count: 1u32,
 			per_period: 100u64,
+			cliff: 0u64,
 		};
 		assert_err!(
 			Vesting::add_vesting_schedule(RuntimeOrigin::signed(BOB::get()), ALICE::get(), schedule),
@@ -169,6 +174,7 @@ fn add_vesting_schedule_fails_if_overflow() {
 			period: 1u64,
 			period_count: 2u32,
 			per_period: u64::MAX,
+			cliff: 0u64,
 		};
 		assert_err!(
 			Vesting::add_vesting_schedule(RuntimeOrigin::signed(ALICE::get()), BOB::get(), schedule),
@@ -180,6 +186,7 @@ fn add_vesting_schedule_fails_if_overflow() {
 			period: 1u64,
 			period_count: 2u32,
 			per_period: 1u64,
+			cliff: 0u64,
 		};
 		assert_err!(
 			Vesting::add_vesting_schedule(RuntimeOrigin::signed(ALICE::get()), BOB::get(), another_schedule),
@@ -188,6 +195,63 @@ fn add_vesting_schedule_fails_if_overflow() {
 	});
 }
 
+#[test]
+fn vested_transfer_works() {
+	ExtBuilder::default().one_hundred_for_alice().build().execute_with(|| {
+		assert_ok!(<Vesting as VestedTransfer<_, _, _>>::vested_transfer(
+			&ALICE::get(),
+			&BOB::get(),
+			25,
+			0,
+			10,
+			2
+		));
+
+		let schedule = VestingSchedule {
+			start: 0u64,
+			period: 10u64,
+			period_count: 2u32,
+			per_period: 12u64,
+			cliff: 0u64,
+		};
+		assert_eq!(Vesting::vesting_schedules(BOB::get()), vec![schedule.clone()]);
+		// The rounding remainder is not locked
+		assert_eq!(mock::balances(&BOB::get()), (25, 24));
+		assert_eq!(
+			context_events(),
+			vec![Event::VestingScheduleAdded(ALICE::get(), BOB::get(), schedule)]
+		);
+	});
+}
+
+#[test]
+fn vested_transfer_of_less_than_one_unit_per_period_is_free() {
+	ExtBuilder::default().one_hundred_for_alice().build().execute_with(|| {
+		assert_ok!(<Vesting as VestedTransfer<_, _, _>>::vested_transfer(
+			&ALICE::get(),
+			&BOB::get(),
+			3,
+			0,
+			10,
+			4
+		));
+
+		assert!(Vesting::vesting_schedules(BOB::get()).is_empty());
+		assert_eq!(mock::balances(&BOB::get()), (3, 0));
+		assert_eq!(context_events(), vec![]);
+	});
+}
+
+#[test]
+fn vested_transfer_fails_if_zero_period_count() {
+	ExtBuilder::default().one_hundred_for_alice().build().execute_with(|| {
+		assert_noop!(
+			<Vesting as VestedTransfer<_, _, _>>::vested_transfer(&ALICE::get(), &BOB::get(), 25, 0, 10, 0),
+			Error::<Runtime>::ZeroVestingPeriodCount
+		);
+	});
+}
+
 #[test]
 fn claim_works() {
 	ExtBuilder::default().one_hundred_for_alice().build().execute_with(|| {
@@ -196,6 +260,7 @@ fn claim_works() {
 			period: 10u64,
 			period_count: 2u32,
 			per_period: 10u64,
+			cliff: 0u64,
 		};
 		assert_ok!(Vesting::add_vesting_schedule(
 			RuntimeOrigin::signed(ALICE::get()),
@@ -230,8 +295,11 @@ fn claim_works() {
 		assert_eq!(PalletBalances::free_balance(BOB::get()), 0);
 		// clears the storage
 		assert!(!<VestingSchedules<Runtime>>::contains_key(BOB::get()));
-		// no locks anymore
-		assert_eq!(PalletBalances::locks(BOB::get()), vec![]);
+		// no freeze anymore
+		assert_eq!(
+			PalletBalances::balance_frozen(&FreezeReason::Vesting.into(), &BOB::get()),
+			0
+		);
 	});
 }
 
@@ -253,6 +321,7 @@ fn cancel_auto_claim_recipient_funds_and_wire_the_rest() {
 			period: 10u64,
 			period_count: 2u32,
 			per_period: 10u64,
+			cliff: 0u64,
 		};
 		assert_ok!(Vesting::add_vesting_schedule(
 			RuntimeOrigin::signed(ALICE::get()),
@@ -285,705 +354,3124 @@ fn cancel_auto_claim_recipient_funds_and_wire_the_rest() {
 }
 
 #[test]
-fn bridge_all_vesting_schedules_does_claim_first() {
+fn cancel_vesting_schedule_only_collects_the_unvested_part_of_that_schedule() {
 	ExtBuilder::default().one_hundred_for_alice().build().execute_with(|| {
-		let bridge_id = 1;
-		let bridge_name = b"zklocal";
-		let remote_chain_id = 9924;
-		assert_ok!(Vesting::set_bridge(
-			RuntimeOrigin::root(),
-			bridge_id,
-			bridge_name.into(),
-			remote_chain_id
-		));
-		let schedule = VestingSchedule {
+		let canceled = VestingSchedule {
 			start: 0u64,
 			period: 10u64,
 			period_count: 2u32,
 			per_period: 10u64,
+			cliff: 0u64,
+		};
+		let kept = VestingSchedule {
+			start: 0u64,
+			period: 10u64,
+			period_count: 3u32,
+			per_period: 5u64,
+			cliff: 0u64,
 		};
 		assert_ok!(Vesting::add_vesting_schedule(
 			RuntimeOrigin::signed(ALICE::get()),
 			BOB::get(),
-			schedule
+			canceled.clone()
+		));
+		assert_ok!(Vesting::add_vesting_schedule(
+			RuntimeOrigin::signed(ALICE::get()),
+			BOB::get(),
+			kept.clone()
 		));
 
 		System::set_block_number(11);
 
-		assert_eq!(PalletBalances::free_balance(BOB::get()), 20);
-		assert_eq!(PalletBalances::usable_balance(BOB::get()), 0);
-		assert_ok!(Vesting::bridge_all_vesting_schedules(
-			RuntimeOrigin::signed(BOB::get()),
-			hex!("2E7F3926Ae74FDCDcAde2c2AB50990C5daFD42bD"),
-			bridge_id
+		assert_noop!(
+			Vesting::cancel_vesting_schedule(
+				RuntimeOrigin::signed(CancelOrigin::get()),
+				BOB::get(),
+				CancelOrigin::get(),
+				0,
+				kept.clone()
+			),
+			Error::<Runtime>::ScheduleMismatch
+		);
+		assert_ok!(Vesting::cancel_vesting_schedule(
+			RuntimeOrigin::signed(CancelOrigin::get()),
+			BOB::get(),
+			CancelOrigin::get(),
+			0,
+			canceled
 		));
-		assert_eq!(PalletBalances::free_balance(BOB::get()), 10);
-		assert_eq!(PalletBalances::usable_balance(BOB::get()), 10);
+
+		assert_eq!(Vesting::vesting_schedules(BOB::get()).to_vec(), vec![kept]);
+		assert_eq!(PalletBalances::free_balance(CancelOrigin::get()), 10);
+		assert_eq!(PalletBalances::free_balance(BOB::get()), 25);
+		assert_eq!(
+			PalletBalances::balance_frozen(&FreezeReason::Vesting.into(), &BOB::get()),
+			10u64
+		);
+		assert_eq!(
+			context_events().last(),
+			Some(&Event::VestingScheduleCanceled(BOB::get(), 0, 10))
+		);
 	});
 }
 
 #[test]
-fn bridge_all_vesting_schedules_clears_storage() {
+fn cancel_last_vesting_schedule_clears_storage_and_lock() {
 	ExtBuilder::default().one_hundred_for_alice().build().execute_with(|| {
-		let bridge_id = 1;
-		let bridge_name = b"zklocal";
-		let remote_chain_id = 9924;
-		assert_ok!(Vesting::set_bridge(
-			RuntimeOrigin::root(),
-			bridge_id,
-			bridge_name.into(),
-			remote_chain_id
-		));
 		let schedule = VestingSchedule {
 			start: 0u64,
 			period: 10u64,
 			period_count: 2u32,
 			per_period: 10u64,
+			cliff: 0u64,
 		};
 		assert_ok!(Vesting::add_vesting_schedule(
 			RuntimeOrigin::signed(ALICE::get()),
 			BOB::get(),
-			schedule
+			schedule.clone()
 		));
 
 		System::set_block_number(11);
 
-		assert!(<VestingSchedules<Runtime>>::contains_key(BOB::get()));
-		assert_ok!(Vesting::bridge_all_vesting_schedules(
-			RuntimeOrigin::signed(BOB::get()),
-			hex!("2E7F3926Ae74FDCDcAde2c2AB50990C5daFD42bD"),
-			bridge_id
+		assert_ok!(Vesting::cancel_vesting_schedule(
+			RuntimeOrigin::signed(CancelOrigin::get()),
+			BOB::get(),
+			CancelOrigin::get(),
+			0,
+			schedule
 		));
+
 		assert!(!<VestingSchedules<Runtime>>::contains_key(BOB::get()));
-		assert!(!<Renounced<Runtime>>::contains_key(BOB::get()));
+		assert_eq!(
+			PalletBalances::balance_frozen(&FreezeReason::Vesting.into(), &BOB::get()),
+			0
+		);
+		assert_eq!(PalletBalances::free_balance(BOB::get()), 10);
+		assert_eq!(PalletBalances::free_balance(CancelOrigin::get()), 10);
 	});
 }
 
 #[test]
-fn bridge_all_vesting_schedules_reduces_total_issuance() {
+fn cancel_vesting_schedule_fails_for_invalid_index() {
 	ExtBuilder::default().one_hundred_for_alice().build().execute_with(|| {
-		let bridge_id = 1;
-		let bridge_name = b"zklocal";
-		let remote_chain_id = 9924;
-		assert_ok!(Vesting::set_bridge(
-			RuntimeOrigin::root(),
-			bridge_id,
-			bridge_name.into(),
-			remote_chain_id
-		));
 		let schedule = VestingSchedule {
 			start: 0u64,
 			period: 10u64,
 			period_count: 2u32,
 			per_period: 10u64,
+			cliff: 0u64,
 		};
 		assert_ok!(Vesting::add_vesting_schedule(
 			RuntimeOrigin::signed(ALICE::get()),
 			BOB::get(),
-			schedule
+			schedule.clone()
 		));
 
-		System::set_block_number(11);
-
-		assert_eq!(PalletBalances::total_issuance(), 100);
-		assert_ok!(Vesting::bridge_all_vesting_schedules(
-			RuntimeOrigin::signed(BOB::get()),
-			hex!("2E7F3926Ae74FDCDcAde2c2AB50990C5daFD42bD"),
-			bridge_id
-		));
-		assert_eq!(PalletBalances::total_issuance(), 90);
+		assert_noop!(
+			Vesting::cancel_vesting_schedule(
+				RuntimeOrigin::signed(CancelOrigin::get()),
+				BOB::get(),
+				CancelOrigin::get(),
+				1,
+				schedule.clone()
+			),
+			Error::<Runtime>::InvalidScheduleIndex
+		);
+		assert_noop!(
+			Vesting::cancel_vesting_schedule(
+				RuntimeOrigin::signed(ALICE::get()),
+				BOB::get(),
+				ALICE::get(),
+				0,
+				schedule
+			),
+			BadOrigin
+		);
 	});
 }
 
 #[test]
-fn bridge_all_vesting_schedules_emits_expected_event() {
+fn cliff_locks_everything_until_it_ends() {
+	let schedule = VestingSchedule {
+		start: 10u64,
+		period: 10u64,
+		period_count: 4u32,
+		per_period: 5u64,
+		cliff: 25u64,
+	};
+	assert_eq!(schedule.locked_amount(10), 20);
+	assert_eq!(schedule.locked_amount(34), 20);
+	// the two periods elapsed during the cliff unlock at once
+	assert_eq!(schedule.locked_amount(35), 10);
+	assert_eq!(schedule.locked_amount(40), 5);
+	assert_eq!(schedule.locked_amount(50), 0);
+	assert_eq!(schedule.end(), Some(50));
+
+	let long_cliff = VestingSchedule {
+		cliff: 100u64,
+		..schedule
+	};
+	assert_eq!(long_cliff.locked_amount(60), 20);
+	assert_eq!(long_cliff.locked_amount(110), 0);
+	assert_eq!(long_cliff.end(), Some(110));
+}
+
+#[test]
+fn claim_respects_cliff() {
 	ExtBuilder::default().one_hundred_for_alice().build().execute_with(|| {
-		let bridge_id = 1;
-		let bridge_name = b"zklocal";
-		let remote_chain_id = 9924;
-		assert_ok!(Vesting::set_bridge(
-			RuntimeOrigin::root(),
-			bridge_id,
-			bridge_name.into(),
-			remote_chain_id
-		));
-		let schedule1 = VestingSchedule {
+		let schedule = VestingSchedule {
 			start: 0u64,
 			period: 10u64,
 			period_count: 2u32,
 			per_period: 10u64,
+			cliff: 15u64,
 		};
 		assert_ok!(Vesting::add_vesting_schedule(
 			RuntimeOrigin::signed(ALICE::get()),
 			BOB::get(),
-			schedule1.clone()
-		));
-		let schedule2 = VestingSchedule {
-			start: 12u64,
-			period: 13u64,
-			period_count: 1u32,
-			per_period: 7u64,
-		};
-		assert_ok!(Vesting::add_vesting_schedule(
-			RuntimeOrigin::signed(ALICE::get()),
-			BOB::get(),
-			schedule2.clone()
+			schedule
 		));
 
 		System::set_block_number(11);
+		assert_ok!(Vesting::claim(RuntimeOrigin::signed(BOB::get())));
+		assert_eq!(context_events().last(), Some(&Event::Claimed(BOB::get(), 20)));
 
-		assert_ok!(Vesting::bridge_all_vesting_schedules(
-			RuntimeOrigin::signed(BOB::get()),
-			hex!("2E7F3926Ae74FDCDcAde2c2AB50990C5daFD42bD"),
-			bridge_id
-		));
-		let remaining_grants = BoundedVec::try_from(vec![schedule1, schedule2]).unwrap();
+		System::set_block_number(15);
+		assert_ok!(Vesting::claim(RuntimeOrigin::signed(BOB::get())));
+		assert_eq!(context_events().last(), Some(&Event::Claimed(BOB::get(), 10)));
+	});
+}
+
+#[test]
+fn migrate_to_v2_adds_zero_cliffs() {
+	ExtBuilder::default().build().execute_with(|| {
+		use frame_support::traits::OnRuntimeUpgrade;
+
+		let old_schedules = vec![(0u64, 10u64, 2u32, 10u64), (5u64, 1u64, 3u32, 7u64)];
+		frame_support::storage::unhashed::put(&<VestingSchedules<Runtime>>::hashed_key_for(BOB::get()), &old_schedules);
+		<VestingSchedules<Runtime>>::initialize_counter();
+		StorageVersion::<Runtime>::put(Releases::V1);
+
+		migrations::v2::MigrateToV2::<Runtime>::on_runtime_upgrade();
 
+		assert_eq!(StorageVersion::<Runtime>::get(), Releases::V2);
 		assert_eq!(
-			System::events().last().unwrap().event,
-			TestEvent::Vesting(Event::BridgeInitiated {
-				to: hex!("2E7F3926Ae74FDCDcAde2c2AB50990C5daFD42bD"),
-				bridge_id: 1,
-				amount: 17,
-				grants: remaining_grants,
-			})
+			Vesting::vesting_schedules(BOB::get()).to_vec(),
+			vec![
+				VestingSchedule {
+					start: 0u64,
+					period: 10u64,
+					period_count: 2u32,
+					per_period: 10u64,
+					cliff: 0u64,
+				},
+				VestingSchedule {
+					start: 5u64,
+					period: 1u64,
+					period_count: 3u32,
+					per_period: 7u64,
+					cliff: 0u64,
+				},
+			]
 		);
+		assert_eq!(<VestingSchedules<Runtime>>::count(), 1);
 	});
 }
 
 #[test]
-fn bridge_all_vesting_schedules_completes_one_sidedly_when_no_grants_after_claim() {
+fn migrate_to_v3_activates_existing_bridges() {
+	ExtBuilder::default().build().execute_with(|| {
+		use frame_support::traits::OnRuntimeUpgrade;
+
+		let old_details = (9924u64, b"zklocal".to_vec());
+		frame_support::storage::unhashed::put(&<Bridges<Runtime>>::hashed_key_for(BridgeId(1)), &old_details);
+		StorageVersion::<Runtime>::put(Releases::V2);
+
+		migrations::v3::MigrateToV3::<Runtime>::on_runtime_upgrade();
+
+		assert_eq!(StorageVersion::<Runtime>::get(), Releases::V3);
+		assert_eq!(
+			Vesting::bridges(BridgeId(1)),
+			Some(BridgeDetails::new(9924, b"zklocal".to_vec().try_into().unwrap()))
+		);
+
+		// Later upgrades must not run the older migrations again
+		migrations::v2::MigrateToV2::<Runtime>::on_runtime_upgrade();
+		assert_eq!(StorageVersion::<Runtime>::get(), Releases::V3);
+	});
+}
+
+#[test]
+fn migrate_to_v4_replaces_locks_with_freezes() {
 	ExtBuilder::default().one_hundred_for_alice().build().execute_with(|| {
-		let bridge_id = 1;
-		let bridge_name = b"zklocal";
-		let remote_chain_id = 9924;
-		assert_ok!(Vesting::set_bridge(
-			RuntimeOrigin::root(),
-			bridge_id,
-			bridge_name.into(),
-			remote_chain_id
-		));
+		use frame_support::{
+			migrations::SteppedMigration,
+			traits::{fungible::MutateFreeze, LockableCurrency},
+			weights::WeightMeter,
+		};
+
 		let schedule = VestingSchedule {
 			start: 0u64,
 			period: 10u64,
-			period_count: 1u32,
-			per_period: 100u64,
+			period_count: 2u32,
+			per_period: 10u64,
+			cliff: 0u64,
 		};
 		assert_ok!(Vesting::add_vesting_schedule(
 			RuntimeOrigin::signed(ALICE::get()),
 			BOB::get(),
 			schedule
 		));
+		// restore the lock the vested funds were restricted with before
+		assert_ok!(PalletBalances::thaw(&FreezeReason::Vesting.into(), &BOB::get()));
+		PalletBalances::set_lock(VESTING_LOCK_ID, &BOB::get(), 20, WithdrawReasons::all());
+		StorageVersion::<Runtime>::put(Releases::V3);
+
+		let mut cursor = None;
+		loop {
+			cursor =
+				migrations::v4::MigrateToV4::<Runtime, PalletBalances>::step(cursor, &mut WeightMeter::new()).unwrap();
+			if cursor.is_none() {
+				break;
+			}
+		}
 
-		System::set_block_number(11);
-
-		assert_ok!(Vesting::bridge_all_vesting_schedules(
-			RuntimeOrigin::signed(BOB::get()),
-			hex!("2E7F3926Ae74FDCDcAde2c2AB50990C5daFD42bD"),
-			bridge_id
-		));
-		assert_eq!(PalletBalances::usable_balance(BOB::get()), 100);
+		assert_eq!(StorageVersion::<Runtime>::get(), Releases::V4);
 		assert_eq!(
-			System::events().last().unwrap().event,
-			TestEvent::Vesting(Event::NoVestedFundsToBridgeAfterClaim)
+			PalletBalances::balance_frozen(&FreezeReason::Vesting.into(), &BOB::get()),
+			20
 		);
+		assert!(PalletBalances::locks(BOB::get()).is_empty());
+		assert_eq!(mock::balances(&BOB::get()), (20, 20));
 	});
 }
 
 #[test]
-fn bridge_all_vesting_schedules_works_for_renounced_account() {
+fn migrate_to_v4_moves_one_account_per_step_within_its_weight() {
 	ExtBuilder::default().one_hundred_for_alice().build().execute_with(|| {
-		let bridge_id = 1;
-		let bridge_name = b"zklocal";
-		let remote_chain_id = 9924;
-		assert_ok!(Vesting::set_bridge(
-			RuntimeOrigin::root(),
-			bridge_id,
-			bridge_name.into(),
-			remote_chain_id
-		));
+		use frame_support::{
+			migrations::{SteppedMigration, SteppedMigrationError},
+			traits::{fungible::MutateFreeze, LockableCurrency},
+			weights::{RuntimeDbWeight, Weight, WeightMeter},
+		};
+		use migrations::v4::{MigrateToV4, MigrationCursor};
+
+		DbWeight::set(RuntimeDbWeight { read: 1, write: 1 });
+		let account_weight = DbWeight::get().reads_writes(4, 3);
+
 		let schedule = VestingSchedule {
 			start: 0u64,
 			period: 10u64,
-			period_count: 1u32,
-			per_period: 100u64,
+			period_count: 2u32,
+			per_period: 10u64,
+			cliff: 0u64,
 		};
-		assert_ok!(Vesting::add_vesting_schedule(
-			RuntimeOrigin::signed(ALICE::get()),
-			BOB::get(),
-			schedule
-		));
-
-		System::set_block_number(11);
-
-		assert_ok!(Vesting::renounce(
-			RuntimeOrigin::signed(CancelOrigin::get()),
-			BOB::get()
-		));
-
-		assert_ok!(Vesting::bridge_all_vesting_schedules(
-			RuntimeOrigin::signed(BOB::get()),
-			hex!("2E7F3926Ae74FDCDcAde2c2AB50990C5daFD42bD"),
-			bridge_id
-		));
-		assert_eq!(PalletBalances::usable_balance(BOB::get()), 100);
-	});
-}
+		let timed_schedule = VestingSchedule {
+			start: 0u64,
+			period: 10u64,
+			period_count: 2u32,
+			per_period: 5u64,
+			cliff: 0u64,
+		};
+		for who in [10, 11, 12] {
+			assert_ok!(Vesting::add_vesting_schedule(
+				RuntimeOrigin::signed(ALICE::get()),
+				who,
+				schedule.clone()
+			));
+		}
+		for who in [12, 13] {
+			assert_ok!(Vesting::add_timed_vesting_schedule(
+				RuntimeOrigin::signed(ALICE::get()),
+				who,
+				timed_schedule.clone()
+			));
+		}
+		// restore the locks the vested funds were restricted with before
+		let locks = [(10, 20), (11, 20), (12, 30), (13, 10)];
+		for (who, locked) in locks {
+			assert_ok!(PalletBalances::thaw(&FreezeReason::Vesting.into(), &who));
+			PalletBalances::set_lock(VESTING_LOCK_ID, &who, locked, WithdrawReasons::all());
+		}
+		StorageVersion::<Runtime>::put(Releases::V3);
 
-#[test]
-fn set_bridge_fails_if_bridge_already_exists() {
-	ExtBuilder::default().build().execute_with(|| {
-		let bridge_id = 1;
-		let bridge_name = b"zklocal";
-		let remote_chain_id = 9924;
-		assert_ok!(Vesting::set_bridge(
-			RuntimeOrigin::root(),
-			bridge_id,
-			bridge_name.into(),
-			remote_chain_id
-		));
-		assert_err!(
-			Vesting::set_bridge(RuntimeOrigin::root(), bridge_id, bridge_name.into(), remote_chain_id),
-			Error::<Runtime>::BridgeAlreadyExists
+		let mut meter = WeightMeter::with_limit(account_weight.saturating_sub(Weight::from_parts(1, 0)));
+		assert_eq!(
+			MigrateToV4::<Runtime, PalletBalances>::step(None, &mut meter),
+			Err(SteppedMigrationError::InsufficientWeight {
+				required: account_weight
+			})
 		);
-	});
-}
 
-#[test]
-fn set_bridge_fails_if_name_too_long() {
-	ExtBuilder::default().build().execute_with(|| {
-		let bridge_id = 1;
-		let bridge_name = b"somereallylonglonglongnnnnnnnnameeee";
-		let remote_chain_id = 9924;
-		assert_err!(
-			Vesting::set_bridge(RuntimeOrigin::root(), bridge_id, bridge_name.into(), remote_chain_id),
-			Error::<Runtime>::BridgeNameTooLong
-		);
-	});
-}
+		// one step per account of each map, the one with both kinds of schedules being met twice
+		let mut cursors = Vec::new();
+		let mut cursor = None;
+		loop {
+			let mut meter = WeightMeter::with_limit(account_weight);
+			cursor = MigrateToV4::<Runtime, PalletBalances>::step(cursor, &mut meter).unwrap();
+			assert_eq!(meter.consumed(), account_weight);
+			let Some(current) = cursor.clone() else {
+				break;
+			};
+			assert!(!cursors.contains(&current));
+			cursors.push(current);
+			if cursors.len() == 1 {
+				assert_eq!(
+					locks
+						.iter()
+						.filter(|(who, _)| PalletBalances::locks(who).is_empty())
+						.count(),
+					1
+				);
+				assert_eq!(StorageVersion::<Runtime>::get(), Releases::V3);
+			}
+		}
 
-#[test]
-fn set_bridge_fails_if_non_root() {
-	ExtBuilder::default().build().execute_with(|| {
-		let bridge_id = 1;
-		let bridge_name = b"zklocal";
-		let remote_chain_id = 9924;
-		assert_err!(
-			Vesting::set_bridge(
-				RuntimeOrigin::signed(ALICE::get()),
-				bridge_id,
-				bridge_name.into(),
-				remote_chain_id
-			),
-			BadOrigin
-		);
+		assert_eq!(cursors.len(), 5);
+		assert!(cursors[..3]
+			.iter()
+			.all(|cursor| matches!(cursor, MigrationCursor::Schedules(_))));
+		assert!(cursors[3..]
+			.iter()
+			.all(|cursor| matches!(cursor, MigrationCursor::TimedSchedules(_))));
+		assert_eq!(StorageVersion::<Runtime>::get(), Releases::V4);
+		for (who, locked) in locks {
+			assert_eq!(
+				PalletBalances::balance_frozen(&FreezeReason::Vesting.into(), &who),
+				locked
+			);
+			assert!(PalletBalances::locks(who).is_empty());
+		}
 	});
 }
 
 #[test]
-fn remove_bridge_fails_if_non_root() {
-	ExtBuilder::default().build().execute_with(|| {
-		let bridge_id = 1;
-		assert_err!(
-			Vesting::remove_bridge(RuntimeOrigin::signed(ALICE::get()), bridge_id),
-			BadOrigin
+fn funder_can_revoke_revocable_schedule() {
+	ExtBuilder::default().one_hundred_for_alice().build().execute_with(|| {
+		let schedule = VestingSchedule {
+			start: 0u64,
+			period: 10u64,
+			period_count: 2u32,
+			per_period: 10u64,
+			cliff: 0u64,
+		};
+		assert_ok!(Vesting::add_vesting_schedule(
+			RuntimeOrigin::signed(ALICE::get()),
+			BOB::get(),
+			schedule.clone()
+		));
+		assert_ok!(Vesting::add_revocable_vesting_schedule(
+			RuntimeOrigin::signed(ALICE::get()),
+			BOB::get(),
+			schedule.clone()
+		));
+		assert_eq!(
+			Vesting::revocable_schedules(BOB::get(), ALICE::get()).to_vec(),
+			vec![schedule.clone()]
 		);
-	});
-}
 
-#[test]
-fn remove_bridge_works() {
-	ExtBuilder::default().build().execute_with(|| {
-		let bridge_id = 1;
-		let bridge_name = b"zklocal";
-		let remote_chain_id = 9924;
-		assert_ok!(Vesting::set_bridge(
-			RuntimeOrigin::root(),
-			bridge_id,
-			bridge_name.into(),
-			remote_chain_id
+		System::set_block_number(11);
+
+		assert_ok!(Vesting::revoke_vesting_schedule(
+			RuntimeOrigin::signed(ALICE::get()),
+			BOB::get(),
+			1,
+			schedule.clone()
 		));
-		let details = <Bridges<Runtime>>::get(BridgeId(bridge_id)).unwrap();
+
+		assert_eq!(Vesting::vesting_schedules(BOB::get()).to_vec(), vec![schedule]);
+		assert!(!<RevocableSchedules<Runtime>>::contains_key(BOB::get(), ALICE::get()));
+		assert_eq!(PalletBalances::free_balance(ALICE::get()), 70);
+		assert_eq!(PalletBalances::free_balance(BOB::get()), 30);
 		assert_eq!(
-			details,
-			BridgeDetails {
-				name: bridge_name.to_vec().try_into().unwrap(),
-				chain_id: remote_chain_id
-			}
+			context_events().last(),
+			Some(&Event::VestingScheduleRevoked {
+				funder: ALICE::get(),
+				who: BOB::get(),
+				schedule_index: 1,
+				amount: 10,
+			})
 		);
-		assert_ok!(Vesting::remove_bridge(RuntimeOrigin::root(), bridge_id));
-		assert!(!<Bridges<Runtime>>::contains_key(BridgeId(bridge_id)));
 	});
 }
 
 #[test]
-fn remove_bridge_fails_if_bridge_not_found() {
-	ExtBuilder::default().build().execute_with(|| {
-		let bridge_id = 1;
-		assert_err!(
-			Vesting::remove_bridge(RuntimeOrigin::root(), bridge_id),
-			Error::<Runtime>::BridgeNotFound
-		);
-	});
+fn only_revocable_schedules_of_the_funder_can_be_revoked() {
+	ExtBuilder::default()
+		.balances(vec![(ALICE::get(), 100), (CancelOrigin::get(), 100)])
+		.build()
+		.execute_with(|| {
+			let schedule = VestingSchedule {
+				start: 0u64,
+				period: 10u64,
+				period_count: 2u32,
+				per_period: 10u64,
+				cliff: 0u64,
+			};
+			assert_ok!(Vesting::add_vesting_schedule(
+				RuntimeOrigin::signed(ALICE::get()),
+				BOB::get(),
+				schedule.clone()
+			));
+			assert_ok!(Vesting::add_revocable_vesting_schedule(
+				RuntimeOrigin::signed(CancelOrigin::get()),
+				BOB::get(),
+				schedule.clone()
+			));
+
+			assert_noop!(
+				Vesting::revoke_vesting_schedule(RuntimeOrigin::signed(ALICE::get()), BOB::get(), 0, schedule.clone()),
+				Error::<Runtime>::NotRevocable
+			);
+			assert_noop!(
+				Vesting::revoke_vesting_schedule(RuntimeOrigin::signed(ALICE::get()), BOB::get(), 2, schedule.clone()),
+				Error::<Runtime>::InvalidScheduleIndex
+			);
+			assert_ok!(Vesting::revoke_vesting_schedule(
+				RuntimeOrigin::signed(CancelOrigin::get()),
+				BOB::get(),
+				0,
+				schedule
+			));
+			assert_eq!(PalletBalances::free_balance(CancelOrigin::get()), 100);
+		});
 }
 
 #[test]
-fn bridge_all_vesting_schedules_fails_if_no_bridge() {
-	ExtBuilder::default().one_hundred_for_alice().build().execute_with(|| {
-		let bridge_id = 1;
-		assert_err!(
-			Vesting::bridge_all_vesting_schedules(
-				RuntimeOrigin::signed(BOB::get()),
-				hex!("2E7F3926Ae74FDCDcAde2c2AB50990C5daFD42bD"),
-				bridge_id
-			),
-			Error::<Runtime>::BridgeNotFound
-		);
-	});
+fn pruned_schedules_cannot_be_mistaken_for_the_shifted_ones() {
+	ExtBuilder::default()
+		.balances(vec![(ALICE::get(), 100), (CancelOrigin::get(), 100)])
+		.build()
+		.execute_with(|| {
+			MaxSchedule::set(3);
+			let short = VestingSchedule {
+				start: 0u64,
+				period: 10u64,
+				period_count: 1u32,
+				per_period: 10u64,
+				cliff: 0u64,
+			};
+			let long = VestingSchedule {
+				start: 0u64,
+				period: 10u64,
+				period_count: 4u32,
+				per_period: 5u64,
+				cliff: 0u64,
+			};
+			assert_ok!(Vesting::add_revocable_vesting_schedule(
+				RuntimeOrigin::signed(ALICE::get()),
+				BOB::get(),
+				short.clone()
+			));
+			assert_ok!(Vesting::add_revocable_vesting_schedule(
+				RuntimeOrigin::signed(CancelOrigin::get()),
+				BOB::get(),
+				short.clone()
+			));
+			assert_ok!(Vesting::add_revocable_vesting_schedule(
+				RuntimeOrigin::signed(ALICE::get()),
+				BOB::get(),
+				long.clone()
+			));
+
+			System::set_block_number(11);
+			assert_ok!(Vesting::claim_for(
+				RuntimeOrigin::signed(CancelOrigin::get()),
+				BOB::get()
+			));
+			assert_eq!(Vesting::vesting_schedules(BOB::get()).to_vec(), vec![long.clone()]);
+			assert!(!<RevocableSchedules<Runtime>>::contains_key(
+				BOB::get(),
+				CancelOrigin::get()
+			));
+			assert_eq!(
+				Vesting::revocable_schedules(BOB::get(), ALICE::get()).to_vec(),
+				vec![long.clone()]
+			);
+
+			assert_noop!(
+				Vesting::revoke_vesting_schedule(RuntimeOrigin::signed(ALICE::get()), BOB::get(), 0, short),
+				Error::<Runtime>::ScheduleMismatch
+			);
+			assert_ok!(Vesting::revoke_vesting_schedule(
+				RuntimeOrigin::signed(ALICE::get()),
+				BOB::get(),
+				0,
+				long
+			));
+			MaxSchedule::set(2);
+		});
 }
 
 #[test]
-fn cancel_clears_storage() {
+fn cancel_forgets_revocable_schedules() {
 	ExtBuilder::default().one_hundred_for_alice().build().execute_with(|| {
 		let schedule = VestingSchedule {
 			start: 0u64,
 			period: 10u64,
 			period_count: 2u32,
 			per_period: 10u64,
+			cliff: 0u64,
 		};
-		assert_ok!(Vesting::add_vesting_schedule(
+		assert_ok!(Vesting::add_revocable_vesting_schedule(
 			RuntimeOrigin::signed(ALICE::get()),
 			BOB::get(),
-			schedule
+			schedule.clone()
+		));
+		assert_ok!(Vesting::add_revocable_vesting_schedule(
+			RuntimeOrigin::signed(ALICE::get()),
+			BOB::get(),
+			schedule.clone()
 		));
 
-		System::set_block_number(11);
+		assert_ok!(Vesting::cancel_vesting_schedule(
+			RuntimeOrigin::signed(CancelOrigin::get()),
+			BOB::get(),
+			CancelOrigin::get(),
+			0,
+			schedule.clone()
+		));
+		assert_eq!(Vesting::revocable_schedules(BOB::get(), ALICE::get()).len(), 1);
 
 		assert_ok!(Vesting::cancel_all_vesting_schedules(
 			RuntimeOrigin::signed(CancelOrigin::get()),
 			BOB::get(),
 			CancelOrigin::get()
 		));
-
-		assert!(!<VestingSchedules<Runtime>>::contains_key(BOB::get()));
+		assert!(!<RevocableSchedules<Runtime>>::contains_key(BOB::get(), ALICE::get()));
+		assert_noop!(
+			Vesting::revoke_vesting_schedule(RuntimeOrigin::signed(ALICE::get()), BOB::get(), 0, schedule),
+			Error::<Runtime>::InvalidScheduleIndex
+		);
 	});
 }
-#[allow(clippy::assertions_on_constants)]
+
 #[test]
-fn cancel_tolerates_corrupted_state() {
+fn grantee_can_transfer_some_schedules_with_their_locked_balance() {
 	ExtBuilder::default().one_hundred_for_alice().build().execute_with(|| {
-		let allice_vesting_to_bob_schedule = VestingSchedule {
+		let moved = VestingSchedule {
 			start: 0u64,
 			period: 10u64,
 			period_count: 2u32,
 			per_period: 10u64,
+			cliff: 0u64,
 		};
-
-		// Initial Balance Status
-		assert_eq!(mock::balances(&ALICE::get()), (100, 0));
-		assert_eq!(PalletBalances::total_balance(&ALICE::get()), 100);
-
-		assert_eq!(mock::balances(&BOB::get()), (0, 0));
-		assert_eq!(PalletBalances::total_balance(&BOB::get()), 0);
-
-		assert_eq!(mock::balances(&CancelOrigin::get()), (0, 0));
-		assert_eq!(PalletBalances::total_balance(&CancelOrigin::get()), 0);
-
-		assert!(!<VestingSchedules<Runtime>>::contains_key(BOB::get()));
-
-		let ans = Vesting::add_vesting_schedule(
+		let kept = VestingSchedule {
+			start: 0u64,
+			period: 10u64,
+			period_count: 3u32,
+			per_period: 5u64,
+			cliff: 0u64,
+		};
+		assert_ok!(Vesting::add_revocable_vesting_schedule(
 			RuntimeOrigin::signed(ALICE::get()),
 			BOB::get(),
-			allice_vesting_to_bob_schedule.clone(),
-		);
-		assert_ok!(ans);
-		assert!(<VestingSchedules<Runtime>>::contains_key(BOB::get()));
-
-		// Event & Balance Status
-		let mut expected = vec![Event::VestingScheduleAdded(
-			ALICE::get(),
+			moved.clone()
+		));
+		assert_ok!(Vesting::add_vesting_schedule(
+			RuntimeOrigin::signed(ALICE::get()),
 			BOB::get(),
-			VestingSchedule {
-				start: 0,
-				period: 10,
-				period_count: 2,
-				per_period: 10,
-			},
-		)];
-		assert_eq!(context_events(), expected);
+			kept.clone()
+		));
 
-		assert_eq!(mock::balances(&ALICE::get()), (80, 0));
-		assert_eq!(PalletBalances::total_balance(&ALICE::get()), 80);
+		System::set_block_number(11);
 
-		assert_eq!(mock::balances(&BOB::get()), (20, 20));
-		assert_eq!(PalletBalances::total_balance(&BOB::get()), 20);
+		assert_ok!(Vesting::transfer_vesting_schedules(
+			RuntimeOrigin::signed(BOB::get()),
+			CancelOrigin::get(),
+			Some(bounded_vec![0])
+		));
 
-		assert_eq!(mock::balances(&CancelOrigin::get()), (0, 0));
-		assert_eq!(PalletBalances::total_balance(&CancelOrigin::get()), 0);
+		assert_eq!(Vesting::vesting_schedules(BOB::get()).to_vec(), vec![kept]);
+		assert_eq!(
+			Vesting::vesting_schedules(CancelOrigin::get()).to_vec(),
+			vec![moved.clone()]
+		);
+		assert_eq!(PalletBalances::free_balance(BOB::get()), 25);
+		assert_eq!(PalletBalances::free_balance(CancelOrigin::get()), 10);
+		assert_eq!(
+			PalletBalances::balance_frozen(&FreezeReason::Vesting.into(), &BOB::get()),
+			10
+		);
+		assert_eq!(
+			PalletBalances::balance_frozen(&FreezeReason::Vesting.into(), &CancelOrigin::get()),
+			10
+		);
+		assert_eq!(
+			Vesting::revocable_schedules(CancelOrigin::get(), ALICE::get()).to_vec(),
+			vec![moved]
+		);
+		assert!(!<RevocableSchedules<Runtime>>::contains_key(BOB::get(), ALICE::get()));
+		assert_eq!(
+			context_events().last(),
+			Some(&Event::VestingSchedulesTransferred {
+				from: BOB::get(),
+				to: CancelOrigin::get(),
+				count: 1,
+				amount: 10,
+			})
+		);
+	});
+}
 
-		// We also add some vesting schedules without any balances to simulate
-		// a corrupted / badly canceled state.
-		let bob_modified_vesting_schedule = VestingSchedule {
+#[test]
+fn force_transfer_moves_all_schedules_unless_renounced() {
+	ExtBuilder::default().one_hundred_for_alice().build().execute_with(|| {
+		let schedule = VestingSchedule {
 			start: 0u64,
 			period: 10u64,
 			period_count: 2u32,
-			per_period: 1_000u64, // definitely too much money
+			per_period: 10u64,
+			cliff: 0u64,
 		};
+		assert_ok!(Vesting::add_vesting_schedule(
+			RuntimeOrigin::signed(ALICE::get()),
+			BOB::get(),
+			schedule.clone()
+		));
+		assert_ok!(Vesting::add_vesting_schedule(
+			RuntimeOrigin::signed(ALICE::get()),
+			BOB::get(),
+			schedule.clone()
+		));
 
-		let ans = <VestingSchedules<Runtime>>::try_mutate(BOB::get(), |s| -> Result<(), VestingSchedule<u64, u64>> {
-			s.try_push(bob_modified_vesting_schedule.clone())
-		});
-
-		assert_ok!(ans);
-
-		assert!(<VestingSchedules<Runtime>>::contains_key(BOB::get()));
+		assert_noop!(
+			Vesting::force_transfer_vesting_schedules(
+				RuntimeOrigin::signed(ALICE::get()),
+				BOB::get(),
+				ALICE::get(),
+				None
+			),
+			BadOrigin
+		);
+		assert_noop!(
+			Vesting::force_transfer_vesting_schedules(
+				RuntimeOrigin::signed(CancelOrigin::get()),
+				BOB::get(),
+				BOB::get(),
+				None
+			),
+			Error::<Runtime>::VestingToSelf
+		);
+		assert_ok!(Vesting::force_transfer_vesting_schedules(
+			RuntimeOrigin::signed(CancelOrigin::get()),
+			BOB::get(),
+			CancelOrigin::get(),
+			None
+		));
+		assert!(!<VestingSchedules<Runtime>>::contains_key(BOB::get()));
+		assert_eq!(PalletBalances::free_balance(BOB::get()), 0);
+		assert_eq!(PalletBalances::free_balance(CancelOrigin::get()), 40);
+		assert_eq!(Vesting::vesting_schedules(CancelOrigin::get()).len(), 2);
 
-		if let Ok(schedule_from_chain) = <VestingSchedules<Runtime>>::try_get(BOB::get()) {
-			assert_eq!(schedule_from_chain.len(), 2);
+		assert_ok!(Vesting::renounce(
+			RuntimeOrigin::signed(CancelOrigin::get()),
+			CancelOrigin::get()
+		));
+		assert_noop!(
+			Vesting::force_transfer_vesting_schedules(
+				RuntimeOrigin::signed(CancelOrigin::get()),
+				CancelOrigin::get(),
+				BOB::get(),
+				None
+			),
+			Error::<Runtime>::Renounced
+		);
+		// the grantee can still move its schedules but they stay renounced
+		assert_ok!(Vesting::transfer_vesting_schedules(
+			RuntimeOrigin::signed(CancelOrigin::get()),
+			BOB::get(),
+			None
+		));
+		assert!(Vesting::renounced(BOB::get()));
+	});
+}
 
-			assert_eq!(schedule_from_chain[0], allice_vesting_to_bob_schedule);
-			assert_eq!(schedule_from_chain[1], bob_modified_vesting_schedule);
-		} else {
-			assert!(false, "Expected Bob to have some grants, Got error instead");
+#[test]
+fn transfer_cannot_escape_cancel_origin_or_exceed_max_schedule() {
+	ExtBuilder::default().one_hundred_for_alice().build().execute_with(|| {
+		let schedule = VestingSchedule {
+			start: 0u64,
+			period: 10u64,
+			period_count: 2u32,
+			per_period: 1u64,
+			cliff: 0u64,
+		};
+		for _ in 0..MaxSchedule::get() {
+			assert_ok!(Vesting::add_vesting_schedule(
+				RuntimeOrigin::signed(ALICE::get()),
+				BOB::get(),
+				schedule.clone()
+			));
 		}
+		assert_ok!(Vesting::add_vesting_schedule(
+			RuntimeOrigin::signed(ALICE::get()),
+			CancelOrigin::get(),
+			schedule
+		));
 
-		System::set_block_number(11);
-		assert!(<VestingSchedules<Runtime>>::contains_key(BOB::get()));
+		assert_noop!(
+			Vesting::transfer_vesting_schedules(RuntimeOrigin::signed(CancelOrigin::get()), BOB::get(), None),
+			Error::<Runtime>::MaxScheduleOverflow
+		);
+		assert_noop!(
+			Vesting::transfer_vesting_schedules(
+				RuntimeOrigin::signed(BOB::get()),
+				ALICE::get(),
+				Some(bounded_vec![99])
+			),
+			Error::<Runtime>::InvalidScheduleIndex
+		);
 
-		let res_cancel_all_vesting_schedules = Vesting::cancel_all_vesting_schedules(
+		assert_ok!(Vesting::renounce(
 			RuntimeOrigin::signed(CancelOrigin::get()),
-			BOB::get(),
-			CancelOrigin::get(),
+			ALICE::get()
+		));
+		assert_noop!(
+			Vesting::transfer_vesting_schedules(RuntimeOrigin::signed(BOB::get()), ALICE::get(), None),
+			Error::<Runtime>::Renounced
 		);
-		assert_ok!(res_cancel_all_vesting_schedules);
-
-		assert!(!<VestingSchedules<Runtime>>::contains_key(BOB::get()));
+	});
+}
 
-		// Event & Balance Status
-		expected.append(&mut vec![Event::VestingSchedulesCanceled(2)]);
+#[test]
+fn renounced_schedules_cannot_join_revocable_ones() {
+	ExtBuilder::default().one_hundred_for_alice().build().execute_with(|| {
+		let schedule = VestingSchedule {
+			start: 0u64,
+			period: 10u64,
+			period_count: 2u32,
+			per_period: 10u64,
+			cliff: 0u64,
+		};
+		assert_ok!(Vesting::add_vesting_schedule(
+			RuntimeOrigin::signed(ALICE::get()),
+			BOB::get(),
+			schedule.clone()
+		));
+		assert_ok!(Vesting::add_vesting_schedule(
+			RuntimeOrigin::signed(ALICE::get()),
+			CancelOrigin::get(),
+			schedule.clone()
+		));
+		assert_ok!(Vesting::renounce(
+			RuntimeOrigin::signed(CancelOrigin::get()),
+			BOB::get()
+		));
 
-		assert_eq!(context_events(), expected);
+		// the schedule of `CancelOrigin` would otherwise be renounced along the moved one
+		assert_noop!(
+			Vesting::transfer_vesting_schedules(RuntimeOrigin::signed(BOB::get()), CancelOrigin::get(), None),
+			Error::<Runtime>::Renounced
+		);
+		assert!(!Vesting::renounced(CancelOrigin::get()));
 
-		assert_eq!(mock::balances(&ALICE::get()), (80, 0));
-		assert_eq!(PalletBalances::total_balance(&ALICE::get()), 80);
+		assert_ok!(Vesting::transfer_vesting_schedules(
+			RuntimeOrigin::signed(BOB::get()),
+			ALICE::get(),
+			None
+		));
+		assert!(Vesting::renounced(ALICE::get()));
+		assert_eq!(Vesting::vesting_schedules(ALICE::get()).to_vec(), vec![schedule]);
+	});
+}
 
-		assert_eq!(mock::balances(&BOB::get()), (0, 0));
-		assert_eq!(PalletBalances::total_balance(&BOB::get()), 0);
+#[test]
+fn batch_add_vesting_schedules_adds_all_of_them() {
+	ExtBuilder::default().one_hundred_for_alice().build().execute_with(|| {
+		let schedule = VestingSchedule {
+			start: 0u64,
+			period: 10u64,
+			period_count: 2u32,
+			per_period: 10u64,
+			cliff: 0u64,
+		};
+		assert_ok!(Vesting::batch_add_vesting_schedules(
+			RuntimeOrigin::signed(ALICE::get()),
+			bounded_vec![
+				(BOB::get(), schedule.clone()),
+				(BOB::get(), schedule.clone()),
+				(CancelOrigin::get(), schedule.clone())
+			]
+		));
 
-		assert_eq!(mock::balances(&CancelOrigin::get()), (20, 0));
-		assert_eq!(PalletBalances::total_balance(&CancelOrigin::get()), 20);
+		assert_eq!(
+			Vesting::vesting_schedules(BOB::get()).to_vec(),
+			vec![schedule.clone(), schedule.clone()]
+		);
+		assert_eq!(Vesting::vesting_schedules(CancelOrigin::get()).to_vec(), vec![schedule]);
+		assert_eq!(PalletBalances::free_balance(ALICE::get()), 40);
+		assert_eq!(mock::balances(&BOB::get()), (40, 40));
+		assert_eq!(mock::balances(&CancelOrigin::get()), (20, 20));
+		assert_eq!(
+			context_events(),
+			vec![Event::VestingSchedulesBatchAdded {
+				from: ALICE::get(),
+				count: 3,
+				total: 60,
+			}]
+		);
 	});
 }
 
 #[test]
-fn cannot_vest_to_self() {
+fn batch_add_vesting_schedules_fails_atomically() {
 	ExtBuilder::default().one_hundred_for_alice().build().execute_with(|| {
+		let schedule = VestingSchedule {
+			start: 0u64,
+			period: 10u64,
+			period_count: 2u32,
+			per_period: 10u64,
+			cliff: 0u64,
+		};
+		let invalid = VestingSchedule {
+			period: 0u64,
+			..schedule.clone()
+		};
+		let expensive = VestingSchedule {
+			per_period: 50u64,
+			..schedule.clone()
+		};
+
 		assert_noop!(
-			Vesting::add_vesting_schedule(
+			Vesting::batch_add_vesting_schedules(RuntimeOrigin::signed(ALICE::get()), bounded_vec![]),
+			Error::<Runtime>::EmptySchedules
+		);
+		assert_noop!(
+			Vesting::batch_add_vesting_schedules(
 				RuntimeOrigin::signed(ALICE::get()),
-				ALICE::get(),
-				VestingSchedule {
-					start: 0u64,
-					period: 10u64,
-					period_count: 1u32,
-					per_period: 100u64,
-				}
+				bounded_vec![(BOB::get(), schedule.clone()), (BOB::get(), invalid)]
+			),
+			Error::<Runtime>::ZeroVestingPeriod
+		);
+		assert_noop!(
+			Vesting::batch_add_vesting_schedules(
+				RuntimeOrigin::signed(ALICE::get()),
+				bounded_vec![(BOB::get(), schedule.clone()), (ALICE::get(), schedule.clone())]
 			),
 			Error::<Runtime>::VestingToSelf
 		);
+		assert_noop!(
+			Vesting::batch_add_vesting_schedules(
+				RuntimeOrigin::signed(ALICE::get()),
+				bounded_vec![
+					(BOB::get(), schedule.clone()),
+					(BOB::get(), schedule.clone()),
+					(BOB::get(), schedule.clone())
+				]
+			),
+			Error::<Runtime>::MaxScheduleOverflow
+		);
+		assert_noop!(
+			Vesting::batch_add_vesting_schedules(
+				RuntimeOrigin::signed(ALICE::get()),
+				bounded_vec![(BOB::get(), schedule), (CancelOrigin::get(), expensive)]
+			),
+			Error::<Runtime>::InsufficientBalance
+		);
 	});
 }
 
 #[test]
-fn add_vesting_schedule_overflow_check() {
-	ExtBuilder::default()
-		.balances(vec![(ALICE::get(), 1000)])
-		.build()
-		.execute_with(|| {
-			System::set_block_number(1);
+fn claim_prunes_fully_vested_schedules() {
+	ExtBuilder::default().one_hundred_for_alice().build().execute_with(|| {
+		let short = VestingSchedule {
+			start: 0u64,
+			period: 10u64,
+			period_count: 1u32,
+			per_period: 10u64,
+			cliff: 0u64,
+		};
+		let long = VestingSchedule {
+			start: 0u64,
+			period: 10u64,
+			period_count: 3u32,
+			per_period: 10u64,
+			cliff: 0u64,
+		};
+		assert_ok!(Vesting::add_vesting_schedule(
+			RuntimeOrigin::signed(ALICE::get()),
+			BOB::get(),
+			short
+		));
+		assert_ok!(Vesting::add_vesting_schedule(
+			RuntimeOrigin::signed(ALICE::get()),
+			BOB::get(),
+			long.clone()
+		));
 
-			let schedules = [
-				VestingSchedule {
-					start: 0u64,
-					period: 10u64,
-					period_count: 1u32,
-					per_period: 100u64,
-				},
-				VestingSchedule {
-					start: 0u64,
-					period: 10u64,
-					period_count: 1u32,
-					per_period: 100u64,
-				},
-				VestingSchedule {
-					start: 0u64,
-					period: 10u64,
-					period_count: 1u32,
-					per_period: 100u64,
+		System::set_block_number(11);
+		assert_ok!(Vesting::claim(RuntimeOrigin::signed(BOB::get())));
+
+		assert_eq!(Vesting::vesting_schedules(BOB::get()).to_vec(), vec![long]);
+		assert_eq!(mock::balances(&BOB::get()), (40, 20));
+	});
+}
+
+#[test]
+fn anyone_can_claim_for_another_account() {
+	ExtBuilder::default().one_hundred_for_alice().build().execute_with(|| {
+		let schedule = VestingSchedule {
+			start: 0u64,
+			period: 10u64,
+			period_count: 2u32,
+			per_period: 10u64,
+			cliff: 0u64,
+		};
+		assert_ok!(Vesting::add_vesting_schedule(
+			RuntimeOrigin::signed(ALICE::get()),
+			BOB::get(),
+			schedule
+		));
+
+		System::set_block_number(11);
+		assert_ok!(Vesting::claim_for(RuntimeOrigin::signed(ALICE::get()), BOB::get()));
+		assert_eq!(mock::balances(&BOB::get()), (20, 10));
+		assert_eq!(
+			context_events().last(),
+			Some(&Event::ClaimedFor {
+				who: BOB::get(),
+				caller: ALICE::get(),
+				locked: 10,
+			})
+		);
+
+		System::set_block_number(21);
+		assert_ok!(Vesting::claim_for(RuntimeOrigin::signed(ALICE::get()), BOB::get()));
+		assert_eq!(mock::balances(&BOB::get()), (20, 0));
+		assert!(!<VestingSchedules<Runtime>>::contains_key(BOB::get()));
+		assert_noop!(Vesting::claim_for(RuntimeOrigin::none(), BOB::get()), BadOrigin);
+	});
+}
+
+#[test]
+fn compact_merges_schedules_with_the_same_times() {
+	ExtBuilder::default().one_hundred_for_alice().build().execute_with(|| {
+		MaxSchedule::set(4);
+		let schedule = VestingSchedule {
+			start: 0u64,
+			period: 10u64,
+			period_count: 2u32,
+			per_period: 10u64,
+			cliff: 0u64,
+		};
+		let vested = VestingSchedule {
+			period_count: 1u32,
+			..schedule.clone()
+		};
+		assert_ok!(Vesting::add_vesting_schedule(
+			RuntimeOrigin::signed(ALICE::get()),
+			BOB::get(),
+			schedule.clone()
+		));
+		assert_ok!(Vesting::add_vesting_schedule(
+			RuntimeOrigin::signed(ALICE::get()),
+			BOB::get(),
+			vested
+		));
+		assert_ok!(Vesting::add_vesting_schedule(
+			RuntimeOrigin::signed(ALICE::get()),
+			BOB::get(),
+			VestingSchedule {
+				per_period: 5u64,
+				..schedule.clone()
+			}
+		));
+		let revocable = VestingSchedule {
+			per_period: 7u64,
+			..schedule.clone()
+		};
+		assert_ok!(Vesting::add_revocable_vesting_schedule(
+			RuntimeOrigin::signed(ALICE::get()),
+			BOB::get(),
+			revocable.clone()
+		));
+
+		System::set_block_number(10);
+		assert_ok!(Vesting::compact(RuntimeOrigin::signed(ALICE::get()), BOB::get(), true));
+
+		assert_eq!(
+			Vesting::vesting_schedules(BOB::get()).to_vec(),
+			vec![
+				VestingSchedule {
+					per_period: 15u64,
+					..schedule
 				},
-			];
+				revocable
+			]
+		);
+		assert_eq!(mock::balances(&BOB::get()), (54, 22));
+		assert_eq!(
+			context_events().last(),
+			Some(&Event::VestingSchedulesCompacted {
+				who: BOB::get(),
+				pruned: 1,
+				merged: 1,
+			})
+		);
+		MaxSchedule::set(2);
+	});
+}
+
+#[test]
+fn bridge_all_vesting_schedules_does_claim_first() {
+	ExtBuilder::default().one_hundred_for_alice().build().execute_with(|| {
+		let bridge_id = 1;
+		let bridge_name = b"zklocal";
+		let remote_chain_id = 9924;
+		assert_ok!(Vesting::set_bridge(
+			RuntimeOrigin::root(),
+			bridge_id,
+			bridge_name.into(),
+			remote_chain_id
+		));
+		let schedule = VestingSchedule {
+			start: 0u64,
+			period: 10u64,
+			period_count: 2u32,
+			per_period: 10u64,
+			cliff: 0u64,
+		};
+		assert_ok!(Vesting::add_vesting_schedule(
+			RuntimeOrigin::signed(ALICE::get()),
+			BOB::get(),
+			schedule
+		));
+
+		System::set_block_number(11);
+
+		assert_eq!(PalletBalances::free_balance(BOB::get()), 20);
+		assert_eq!(PalletBalances::usable_balance(BOB::get()), 0);
+		assert_ok!(Vesting::bridge_all_vesting_schedules(
+			RuntimeOrigin::signed(BOB::get()),
+			hex!("2E7F3926Ae74FDCDcAde2c2AB50990C5daFD42bD"),
+			bridge_id
+		));
+		assert_eq!(PalletBalances::free_balance(BOB::get()), 10);
+		assert_eq!(PalletBalances::usable_balance(BOB::get()), 10);
+	});
+}
+
+#[test]
+fn bridge_all_vesting_schedules_clears_storage() {
+	ExtBuilder::default().one_hundred_for_alice().build().execute_with(|| {
+		let bridge_id = 1;
+		let bridge_name = b"zklocal";
+		let remote_chain_id = 9924;
+		assert_ok!(Vesting::set_bridge(
+			RuntimeOrigin::root(),
+			bridge_id,
+			bridge_name.into(),
+			remote_chain_id
+		));
+		let schedule = VestingSchedule {
+			start: 0u64,
+			period: 10u64,
+			period_count: 2u32,
+			per_period: 10u64,
+			cliff: 0u64,
+		};
+		assert_ok!(Vesting::add_vesting_schedule(
+			RuntimeOrigin::signed(ALICE::get()),
+			BOB::get(),
+			schedule
+		));
+
+		System::set_block_number(11);
+
+		assert!(<VestingSchedules<Runtime>>::contains_key(BOB::get()));
+		assert_ok!(Vesting::bridge_all_vesting_schedules(
+			RuntimeOrigin::signed(BOB::get()),
+			hex!("2E7F3926Ae74FDCDcAde2c2AB50990C5daFD42bD"),
+			bridge_id
+		));
+		assert!(!<VestingSchedules<Runtime>>::contains_key(BOB::get()));
+		assert!(!<Renounced<Runtime>>::contains_key(BOB::get()));
+	});
+}
+
+#[test]
+fn bridge_all_vesting_schedules_reduces_total_issuance() {
+	ExtBuilder::default().one_hundred_for_alice().build().execute_with(|| {
+		let bridge_id = 1;
+		let bridge_name = b"zklocal";
+		let remote_chain_id = 9924;
+		assert_ok!(Vesting::set_bridge(
+			RuntimeOrigin::root(),
+			bridge_id,
+			bridge_name.into(),
+			remote_chain_id
+		));
+		let schedule = VestingSchedule {
+			start: 0u64,
+			period: 10u64,
+			period_count: 2u32,
+			per_period: 10u64,
+			cliff: 0u64,
+		};
+		assert_ok!(Vesting::add_vesting_schedule(
+			RuntimeOrigin::signed(ALICE::get()),
+			BOB::get(),
+			schedule
+		));
+
+		System::set_block_number(11);
+
+		assert_eq!(PalletBalances::total_issuance(), 100);
+		assert_ok!(Vesting::bridge_all_vesting_schedules(
+			RuntimeOrigin::signed(BOB::get()),
+			hex!("2E7F3926Ae74FDCDcAde2c2AB50990C5daFD42bD"),
+			bridge_id
+		));
+		assert_eq!(PalletBalances::total_issuance(), 90);
+	});
+}
+
+#[test]
+fn bridge_all_vesting_schedules_emits_expected_event() {
+	ExtBuilder::default().one_hundred_for_alice().build().execute_with(|| {
+		let bridge_id = 1;
+		let bridge_name = b"zklocal";
+		let remote_chain_id = 9924;
+		assert_ok!(Vesting::set_bridge(
+			RuntimeOrigin::root(),
+			bridge_id,
+			bridge_name.into(),
+			remote_chain_id
+		));
+		let schedule1 = VestingSchedule {
+			start: 0u64,
+			period: 10u64,
+			period_count: 2u32,
+			per_period: 10u64,
+			cliff: 0u64,
+		};
+		assert_ok!(Vesting::add_vesting_schedule(
+			RuntimeOrigin::signed(ALICE::get()),
+			BOB::get(),
+			schedule1.clone()
+		));
+		let schedule2 = VestingSchedule {
+			start: 12u64,
+			period: 13u64,
+			period_count: 1u32,
+			per_period: 7u64,
+			cliff: 0u64,
+		};
+		assert_ok!(Vesting::add_vesting_schedule(
+			RuntimeOrigin::signed(ALICE::get()),
+			BOB::get(),
+			schedule2.clone()
+		));
+
+		System::set_block_number(11);
+
+		assert_ok!(Vesting::bridge_all_vesting_schedules(
+			RuntimeOrigin::signed(BOB::get()),
+			hex!("2E7F3926Ae74FDCDcAde2c2AB50990C5daFD42bD"),
+			bridge_id
+		));
+		let remaining_grants = BoundedVec::try_from(vec![schedule1, schedule2]).unwrap();
+
+		assert_eq!(
+			System::events().last().unwrap().event,
+			TestEvent::Vesting(Event::BridgeInitiated {
+				to: hex!("2E7F3926Ae74FDCDcAde2c2AB50990C5daFD42bD"),
+				bridge_id: 1,
+				nonce: 0,
+				amount: 17,
+				grants: remaining_grants,
+			})
+		);
+	});
+}
+
+#[test]
+fn bridge_all_vesting_schedules_completes_one_sidedly_when_no_grants_after_claim() {
+	ExtBuilder::default().one_hundred_for_alice().build().execute_with(|| {
+		let bridge_id = 1;
+		let bridge_name = b"zklocal";
+		let remote_chain_id = 9924;
+		assert_ok!(Vesting::set_bridge(
+			RuntimeOrigin::root(),
+			bridge_id,
+			bridge_name.into(),
+			remote_chain_id
+		));
+		let schedule = VestingSchedule {
+			start: 0u64,
+			period: 10u64,
+			period_count: 1u32,
+			per_period: 100u64,
+			cliff: 0u64,
+		};
+		assert_ok!(Vesting::add_vesting_schedule(
+			RuntimeOrigin::signed(ALICE::get()),
+			BOB::get(),
+			schedule
+		));
+
+		System::set_block_number(11);
+
+		assert_ok!(Vesting::bridge_all_vesting_schedules(
+			RuntimeOrigin::signed(BOB::get()),
+			hex!("2E7F3926Ae74FDCDcAde2c2AB50990C5daFD42bD"),
+			bridge_id
+		));
+		assert_eq!(PalletBalances::usable_balance(BOB::get()), 100);
+		assert_eq!(
+			System::events().last().unwrap().event,
+			TestEvent::Vesting(Event::NoVestedFundsToBridgeAfterClaim)
+		);
+	});
+}
+
+#[test]
+fn bridge_all_vesting_schedules_works_for_renounced_account() {
+	ExtBuilder::default().one_hundred_for_alice().build().execute_with(|| {
+		let bridge_id = 1;
+		let bridge_name = b"zklocal";
+		let remote_chain_id = 9924;
+		assert_ok!(Vesting::set_bridge(
+			RuntimeOrigin::root(),
+			bridge_id,
+			bridge_name.into(),
+			remote_chain_id
+		));
+		let schedule = VestingSchedule {
+			start: 0u64,
+			period: 10u64,
+			period_count: 1u32,
+			per_period: 100u64,
+			cliff: 0u64,
+		};
+		assert_ok!(Vesting::add_vesting_schedule(
+			RuntimeOrigin::signed(ALICE::get()),
+			BOB::get(),
+			schedule
+		));
+
+		System::set_block_number(11);
+
+		assert_ok!(Vesting::renounce(
+			RuntimeOrigin::signed(CancelOrigin::get()),
+			BOB::get()
+		));
+
+		assert_ok!(Vesting::bridge_all_vesting_schedules(
+			RuntimeOrigin::signed(BOB::get()),
+			hex!("2E7F3926Ae74FDCDcAde2c2AB50990C5daFD42bD"),
+			bridge_id
+		));
+		assert_eq!(PalletBalances::usable_balance(BOB::get()), 100);
+	});
+}
+
+#[test]
+fn set_bridge_fails_if_bridge_already_exists() {
+	ExtBuilder::default().build().execute_with(|| {
+		let bridge_id = 1;
+		let bridge_name = b"zklocal";
+		let remote_chain_id = 9924;
+		assert_ok!(Vesting::set_bridge(
+			RuntimeOrigin::root(),
+			bridge_id,
+			bridge_name.into(),
+			remote_chain_id
+		));
+		assert_err!(
+			Vesting::set_bridge(RuntimeOrigin::root(), bridge_id, bridge_name.into(), remote_chain_id),
+			Error::<Runtime>::BridgeAlreadyExists
+		);
+	});
+}
+
+#[test]
+fn set_bridge_fails_if_name_too_long() {
+	ExtBuilder::default().build().execute_with(|| {
+		let bridge_id = 1;
+		let bridge_name = b"somereallylonglonglongnnnnnnnnameeee";
+		let remote_chain_id = 9924;
+		assert_err!(
+			Vesting::set_bridge(RuntimeOrigin::root(), bridge_id, bridge_name.into(), remote_chain_id),
+			Error::<Runtime>::BridgeNameTooLong
+		);
+	});
+}
+
+#[test]
+fn set_bridge_fails_if_non_root() {
+	ExtBuilder::default().build().execute_with(|| {
+		let bridge_id = 1;
+		let bridge_name = b"zklocal";
+		let remote_chain_id = 9924;
+		assert_err!(
+			Vesting::set_bridge(
+				RuntimeOrigin::signed(ALICE::get()),
+				bridge_id,
+				bridge_name.into(),
+				remote_chain_id
+			),
+			BadOrigin
+		);
+	});
+}
+
+#[test]
+fn remove_bridge_fails_if_non_root() {
+	ExtBuilder::default().build().execute_with(|| {
+		let bridge_id = 1;
+		assert_err!(
+			Vesting::remove_bridge(RuntimeOrigin::signed(ALICE::get()), bridge_id),
+			BadOrigin
+		);
+	});
+}
+
+#[test]
+fn remove_bridge_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		let bridge_id = 1;
+		let bridge_name = b"zklocal";
+		let remote_chain_id = 9924;
+		assert_ok!(Vesting::set_bridge(
+			RuntimeOrigin::root(),
+			bridge_id,
+			bridge_name.into(),
+			remote_chain_id
+		));
+		let details = <Bridges<Runtime>>::get(BridgeId(bridge_id)).unwrap();
+		assert_eq!(
+			details,
+			BridgeDetails::new(remote_chain_id, bridge_name.to_vec().try_into().unwrap())
+		);
+		assert_eq!(details.status, BridgeStatus::Active);
+		assert_ok!(Vesting::remove_bridge(RuntimeOrigin::root(), bridge_id));
+		assert!(!<Bridges<Runtime>>::contains_key(BridgeId(bridge_id)));
+	});
+}
+
+#[test]
+fn remove_bridge_fails_if_bridge_not_found() {
+	ExtBuilder::default().build().execute_with(|| {
+		let bridge_id = 1;
+		assert_err!(
+			Vesting::remove_bridge(RuntimeOrigin::root(), bridge_id),
+			Error::<Runtime>::BridgeNotFound
+		);
+	});
+}
+
+#[test]
+fn bridge_all_vesting_schedules_fails_if_no_bridge() {
+	ExtBuilder::default().one_hundred_for_alice().build().execute_with(|| {
+		let bridge_id = 1;
+		assert_err!(
+			Vesting::bridge_all_vesting_schedules(
+				RuntimeOrigin::signed(BOB::get()),
+				hex!("2E7F3926Ae74FDCDcAde2c2AB50990C5daFD42bD"),
+				bridge_id
+			),
+			Error::<Runtime>::BridgeNotFound
+		);
+	});
+}
+
+#[test]
+fn cancel_clears_storage() {
+	ExtBuilder::default().one_hundred_for_alice().build().execute_with(|| {
+		let schedule = VestingSchedule {
+			start: 0u64,
+			period: 10u64,
+			period_count: 2u32,
+			per_period: 10u64,
+			cliff: 0u64,
+		};
+		assert_ok!(Vesting::add_vesting_schedule(
+			RuntimeOrigin::signed(ALICE::get()),
+			BOB::get(),
+			schedule
+		));
+
+		System::set_block_number(11);
+
+		assert_ok!(Vesting::cancel_all_vesting_schedules(
+			RuntimeOrigin::signed(CancelOrigin::get()),
+			BOB::get(),
+			CancelOrigin::get()
+		));
+
+		assert!(!<VestingSchedules<Runtime>>::contains_key(BOB::get()));
+	});
+}
+#[allow(clippy::assertions_on_constants)]
+#[test]
+fn cancel_tolerates_corrupted_state() {
+	ExtBuilder::default().one_hundred_for_alice().build().execute_with(|| {
+		let allice_vesting_to_bob_schedule = VestingSchedule {
+			start: 0u64,
+			period: 10u64,
+			period_count: 2u32,
+			per_period: 10u64,
+			cliff: 0u64,
+		};
+
+		// Initial Balance Status
+		assert_eq!(mock::balances(&ALICE::get()), (100, 0));
+		assert_eq!(PalletBalances::total_balance(&ALICE::get()), 100);
+
+		assert_eq!(mock::balances(&BOB::get()), (0, 0));
+		assert_eq!(PalletBalances::total_balance(&BOB::get()), 0);
+
+		assert_eq!(mock::balances(&CancelOrigin::get()), (0, 0));
+		assert_eq!(PalletBalances::total_balance(&CancelOrigin::get()), 0);
+
+		assert!(!<VestingSchedules<Runtime>>::contains_key(BOB::get()));
+
+		let ans = Vesting::add_vesting_schedule(
+			RuntimeOrigin::signed(ALICE::get()),
+			BOB::get(),
+			allice_vesting_to_bob_schedule.clone(),
+		);
+		assert_ok!(ans);
+		assert!(<VestingSchedules<Runtime>>::contains_key(BOB::get()));
+
+		// Event & Balance Status
+		let mut expected = vec![Event::VestingScheduleAdded(
+			ALICE::get(),
+			BOB::get(),
+			VestingSchedule {
+				start: 0,
+				period: 10,
+				period_count: 2,
+				per_period: 10,
+				cliff: 0u64,
+			},
+		)];
+		assert_eq!(context_events(), expected);
+
+		assert_eq!(mock::balances(&ALICE::get()), (80, 0));
+		assert_eq!(PalletBalances::total_balance(&ALICE::get()), 80);
+
+		assert_eq!(mock::balances(&BOB::get()), (20, 20));
+		assert_eq!(PalletBalances::total_balance(&BOB::get()), 20);
+
+		assert_eq!(mock::balances(&CancelOrigin::get()), (0, 0));
+		assert_eq!(PalletBalances::total_balance(&CancelOrigin::get()), 0);
+
+		// We also add some vesting schedules without any balances to simulate
+		// a corrupted / badly canceled state.
+		let bob_modified_vesting_schedule = VestingSchedule {
+			start: 0u64,
+			period: 10u64,
+			period_count: 2u32,
+			per_period: 1_000u64, // definitely too much money,
+			cliff: 0u64,
+		};
+
+		let ans = <VestingSchedules<Runtime>>::try_mutate(BOB::get(), |s| -> Result<(), VestingSchedule<u64, u64>> {
+			s.try_push(bob_modified_vesting_schedule.clone())
+		});
+
+		assert_ok!(ans);
+
+		assert!(<VestingSchedules<Runtime>>::contains_key(BOB::get()));
+
+		if let Ok(schedule_from_chain) = <VestingSchedules<Runtime>>::try_get(BOB::get()) {
+			assert_eq!(schedule_from_chain.len(), 2);
+
+			assert_eq!(schedule_from_chain[0], allice_vesting_to_bob_schedule);
+			assert_eq!(schedule_from_chain[1], bob_modified_vesting_schedule);
+		} else {
+			assert!(false, "Expected Bob to have some grants, Got error instead");
+		}
+
+		System::set_block_number(11);
+		assert!(<VestingSchedules<Runtime>>::contains_key(BOB::get()));
+
+		let res_cancel_all_vesting_schedules = Vesting::cancel_all_vesting_schedules(
+			RuntimeOrigin::signed(CancelOrigin::get()),
+			BOB::get(),
+			CancelOrigin::get(),
+		);
+		assert_ok!(res_cancel_all_vesting_schedules);
+
+		assert!(!<VestingSchedules<Runtime>>::contains_key(BOB::get()));
+
+		// Event & Balance Status
+		expected.append(&mut vec![Event::VestingSchedulesCanceled(2)]);
+
+		assert_eq!(context_events(), expected);
+
+		assert_eq!(mock::balances(&ALICE::get()), (80, 0));
+		assert_eq!(PalletBalances::total_balance(&ALICE::get()), 80);
+
+		assert_eq!(mock::balances(&BOB::get()), (0, 0));
+		assert_eq!(PalletBalances::total_balance(&BOB::get()), 0);
+
+		assert_eq!(mock::balances(&CancelOrigin::get()), (20, 0));
+		assert_eq!(PalletBalances::total_balance(&CancelOrigin::get()), 20);
+	});
+}
+
+#[test]
+fn cannot_vest_to_self() {
+	ExtBuilder::default().one_hundred_for_alice().build().execute_with(|| {
+		assert_noop!(
+			Vesting::add_vesting_schedule(
+				RuntimeOrigin::signed(ALICE::get()),
+				ALICE::get(),
+				VestingSchedule {
+					start: 0u64,
+					period: 10u64,
+					period_count: 1u32,
+					per_period: 100u64,
+					cliff: 0u64,
+				}
+			),
+			Error::<Runtime>::VestingToSelf
+		);
+	});
+}
+
+#[test]
+fn add_vesting_schedule_overflow_check() {
+	ExtBuilder::default()
+		.balances(vec![(ALICE::get(), 1000)])
+		.build()
+		.execute_with(|| {
+			System::set_block_number(1);
+
+			let schedules = [
+				VestingSchedule {
+					start: 0u64,
+					period: 10u64,
+					period_count: 1u32,
+					per_period: 100u64,
+					cliff: 0u64,
+				},
+				VestingSchedule {
+					start: 0u64,
+					period: 10u64,
+					period_count: 1u32,
+					per_period: 100u64,
+					cliff: 0u64,
+				},
+				VestingSchedule {
+					start: 0u64,
+					period: 10u64,
+					period_count: 1u32,
+					per_period: 100u64,
+					cliff: 0u64,
+				},
+			];
+
+			assert_ok!(Vesting::add_vesting_schedule(
+				RuntimeOrigin::signed(ALICE::get()),
+				BOB::get(),
+				schedules[0].clone(),
+			));
+			assert_eq!(Vesting::vesting_schedules(BOB::get()).to_vec().len(), 1);
+
+			let expected = vec![Event::VestingScheduleAdded(1, 2, schedules[0].clone())];
+
+			assert_eq!(context_events(), expected);
+
+			assert_ok!(Vesting::add_vesting_schedule(
+				RuntimeOrigin::signed(ALICE::get()),
+				BOB::get(),
+				schedules[1].clone(),
+			));
+			assert_eq!(Vesting::vesting_schedules(BOB::get()).to_vec().len(), 2);
+
+			let expected = vec![
+				Event::VestingScheduleAdded(1, 2, schedules[0].clone()),
+				Event::VestingScheduleAdded(1, 2, schedules[1].clone()),
+			];
+
+			assert_eq!(context_events(), expected);
+
+			assert_err!(
+				Vesting::add_vesting_schedule(RuntimeOrigin::signed(ALICE::get()), BOB::get(), schedules[2].clone(),),
+				<Error<Runtime>>::MaxScheduleOverflow,
+			);
+
+			assert_eq!(Vesting::vesting_schedules(BOB::get()).to_vec().len(), 2);
+		});
+}
+
+#[test]
+fn add_vesting_schedule_overflow_cfg_min_check() {
+	ExtBuilder::default()
+		.balances(vec![(ALICE::get(), 1000)])
+		.build()
+		.execute_with(|| {
+			System::set_block_number(1);
+
+			let schedules = [VestingSchedule {
+				start: 0u64,
+				period: 10u64,
+				period_count: 1u32,
+				per_period: 100u64,
+				cliff: 0u64,
+			}];
+
+			mock::MAX_SCHEDULE.with(|v| *v.borrow_mut() = 0);
+
+			assert_eq!(Vesting::vesting_schedules(BOB::get()).to_vec().len(), 0);
+
+			assert_err!(
+				Vesting::add_vesting_schedule(RuntimeOrigin::signed(ALICE::get()), BOB::get(), schedules[0].clone(),),
+				<Error<Runtime>>::MaxScheduleOverflow,
+			);
+
+			assert_eq!(Vesting::vesting_schedules(BOB::get()).to_vec().len(), 0);
+
+			let expected = vec![];
+			assert_eq!(context_events(), expected);
+
+			mock::MAX_SCHEDULE.with(|v| *v.borrow_mut() = 1);
+
+			assert_ok!(Vesting::add_vesting_schedule(
+				RuntimeOrigin::signed(ALICE::get()),
+				BOB::get(),
+				schedules[0].clone(),
+			));
+			assert_eq!(Vesting::vesting_schedules(BOB::get()).to_vec().len(), 1);
+
+			let expected = vec![Event::VestingScheduleAdded(1, 2, schedules[0].clone())];
+
+			assert_eq!(context_events(), expected);
+		});
+}
+
+#[test]
+fn add_vesting_schedule_overflow_cfg_max_check() {
+	ExtBuilder::default()
+		.balances(vec![(ALICE::get(), 10_000_000)])
+		.build()
+		.execute_with(|| {
+			System::set_block_number(1);
+
+			let schedules = [VestingSchedule {
+				start: 0u64,
+				period: 10u64,
+				period_count: 1u32,
+				per_period: 100u64,
+				cliff: 0u64,
+			}];
+
+			let schedule_max = 500;
+
+			mock::MAX_SCHEDULE.with(|v| *v.borrow_mut() = schedule_max);
+
+			(0..schedule_max).for_each(|iter_index| {
+				assert_ok!(Vesting::add_vesting_schedule(
+					RuntimeOrigin::signed(ALICE::get()),
+					BOB::get(),
+					schedules[0].clone(),
+				));
+				assert_eq!(
+					Vesting::vesting_schedules(BOB::get()).to_vec().len() as u32,
+					iter_index + 1
+				);
+				assert_eq!(context_events().len() as u32, iter_index + 1);
+			});
+
+			assert_err!(
+				Vesting::add_vesting_schedule(RuntimeOrigin::signed(ALICE::get()), BOB::get(), schedules[0].clone(),),
+				<Error<Runtime>>::MaxScheduleOverflow,
+			);
+
+			assert_eq!(
+				Vesting::vesting_schedules(BOB::get()).to_vec().len(),
+				schedule_max as usize
+			);
+			assert_eq!(context_events().len(), schedule_max as usize);
+		});
+}
+
+#[test]
+fn renounce_only_works_for_cancel_origin() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			Vesting::renounce(RuntimeOrigin::signed(ALICE::get()), BOB::get()),
+			BadOrigin
+		);
+	})
+}
+
+#[test]
+fn renounce_privileges() {
+	ExtBuilder::default().one_hundred_for_alice().build().execute_with(|| {
+		let schedule = VestingSchedule {
+			start: 0u64,
+			period: 10u64,
+			period_count: 2u32,
+			per_period: 10u64,
+			cliff: 0u64,
+		};
+		assert_ok!(Vesting::add_vesting_schedule(
+			RuntimeOrigin::signed(ALICE::get()),
+			BOB::get(),
+			schedule
+		));
+
+		assert!(!Vesting::renounced(BOB::get()));
+		assert_ok!(Vesting::renounce(
+			RuntimeOrigin::signed(CancelOrigin::get()),
+			BOB::get()
+		));
+		assert!(Vesting::renounced(BOB::get()));
+		assert_noop!(
+			Vesting::cancel_all_vesting_schedules(
+				RuntimeOrigin::signed(CancelOrigin::get()),
+				BOB::get(),
+				CancelOrigin::get()
+			),
+			Error::<Runtime>::Renounced
+		);
+	});
+}
+
+#[test]
+fn bridge_transfer_burns_and_emits_bridge_event() {
+	ExtBuilder::default().one_hundred_for_alice().build().execute_with(|| {
+		assert_ok!(Vesting::set_bridge(RuntimeOrigin::root(), 1, b"zklocal".to_vec(), 9924));
+		let total_issuance = PalletBalances::total_issuance();
+		assert_ok!(<Vesting as BridgeTransfer<_, _>>::bridge_transfer(
+			&ALICE::get(),
+			hex!("2E7F3926Ae74FDCDcAde2c2AB50990C5daFD42bD"),
+			1,
+			40
+		));
+
+		assert_eq!(mock::balances(&ALICE::get()), (60, 0));
+		assert_eq!(PalletBalances::total_issuance(), total_issuance - 40);
+		assert_eq!(
+			context_events(),
+			vec![Event::BridgeInitiated {
+				to: hex!("2E7F3926Ae74FDCDcAde2c2AB50990C5daFD42bD"),
+				bridge_id: 1,
+				nonce: 0,
+				amount: 40,
+				grants: BoundedVec::default(),
+			}]
+		);
+	});
+}
+
+#[test]
+fn bridge_transfers_are_recorded_with_increasing_nonces() {
+	ExtBuilder::default().one_hundred_for_alice().build().execute_with(|| {
+		let address = hex!("2E7F3926Ae74FDCDcAde2c2AB50990C5daFD42bD");
+		assert_ok!(Vesting::set_bridge(RuntimeOrigin::root(), 1, b"zklocal".to_vec(), 9924));
+		assert_ok!(Vesting::set_bridge(RuntimeOrigin::root(), 2, b"zkmain".to_vec(), 324));
+
+		assert_ok!(<Vesting as BridgeTransfer<_, _>>::bridge_transfer(
+			&ALICE::get(),
+			address,
+			1,
+			10
+		));
+		assert_ok!(<Vesting as BridgeTransfer<_, _>>::bridge_transfer(
+			&ALICE::get(),
+			address,
+			1,
+			20
+		));
+		assert_ok!(<Vesting as BridgeTransfer<_, _>>::bridge_transfer(
+			&ALICE::get(),
+			address,
+			2,
+			30
+		));
+
+		assert_eq!(Vesting::bridge_nonce(BridgeId(1)), 2);
+		assert_eq!(Vesting::bridge_nonce(BridgeId(2)), 1);
+		assert_eq!(
+			Vesting::pending_bridge_transfers(BridgeId(1), 1),
+			Some(PendingBridgeTransfer {
+				source: ALICE::get(),
+				to: address,
+				amount: 20,
+				grants: BoundedVec::default(),
+				initiated_at: 1,
+			})
+		);
+		assert_eq!(
+			context_events().last(),
+			Some(&Event::BridgeInitiated {
+				to: address,
+				bridge_id: 2,
+				nonce: 0,
+				amount: 30,
+				grants: BoundedVec::default(),
+			})
+		);
+	});
+}
+
+#[test]
+fn bridge_oracle_confirms_pending_transfers() {
+	ExtBuilder::default().one_hundred_for_alice().build().execute_with(|| {
+		let address = hex!("2E7F3926Ae74FDCDcAde2c2AB50990C5daFD42bD");
+		assert_ok!(Vesting::set_bridge(RuntimeOrigin::root(), 1, b"zklocal".to_vec(), 9924));
+		assert_ok!(<Vesting as BridgeTransfer<_, _>>::bridge_transfer(
+			&ALICE::get(),
+			address,
+			1,
+			10
+		));
+
+		assert_noop!(Vesting::confirm_bridge_transfer(RuntimeOrigin::none(), 1, 0), BadOrigin);
+		assert_noop!(
+			Vesting::confirm_bridge_transfer(RuntimeOrigin::signed(ALICE::get()), 1, 0),
+			Error::<Runtime>::NotBridgeOracle
+		);
+		assert_noop!(
+			Vesting::confirm_bridge_transfer(RuntimeOrigin::signed(BridgeOracle::get()), 1, 1),
+			Error::<Runtime>::BridgeTransferNotFound
+		);
+		assert_ok!(Vesting::confirm_bridge_transfer(
+			RuntimeOrigin::signed(BridgeOracle::get()),
+			1,
+			0
+		));
+		assert_eq!(Vesting::pending_bridge_transfers(BridgeId(1), 0), None);
+		assert_eq!(
+			context_events().last(),
+			Some(&Event::BridgeTransferConfirmed { bridge_id: 1, nonce: 0 })
+		);
+		assert_noop!(
+			Vesting::confirm_bridge_transfer(RuntimeOrigin::signed(BridgeOracle::get()), 1, 0),
+			Error::<Runtime>::BridgeTransferNotFound
+		);
+	});
+}
+
+#[test]
+fn governance_refund_restores_bridged_schedules() {
+	ExtBuilder::default().one_hundred_for_alice().build().execute_with(|| {
+		assert_ok!(Vesting::set_bridge(RuntimeOrigin::root(), 1, b"zklocal".to_vec(), 9924));
+		let schedule = VestingSchedule {
+			start: 0u64,
+			period: 10u64,
+			period_count: 2u32,
+			per_period: 10u64,
+			cliff: 0u64,
+		};
+		assert_ok!(Vesting::add_vesting_schedule(
+			RuntimeOrigin::signed(ALICE::get()),
+			BOB::get(),
+			schedule.clone()
+		));
+		let total_issuance = PalletBalances::total_issuance();
+
+		System::set_block_number(11);
+		assert_ok!(Vesting::bridge_all_vesting_schedules(
+			RuntimeOrigin::signed(BOB::get()),
+			hex!("2E7F3926Ae74FDCDcAde2c2AB50990C5daFD42bD"),
+			1
+		));
+		assert_eq!(mock::balances(&BOB::get()), (10, 0));
+
+		assert_ok!(Vesting::refund_bridge_transfer(
+			RuntimeOrigin::signed(CancelOrigin::get()),
+			1,
+			0
+		));
+
+		assert_eq!(Vesting::vesting_schedules(BOB::get()).to_vec(), vec![schedule]);
+		assert_eq!(mock::balances(&BOB::get()), (20, 10));
+		assert_eq!(PalletBalances::total_issuance(), total_issuance);
+		assert_eq!(Vesting::pending_bridge_transfers(BridgeId(1), 0), None);
+		assert_eq!(
+			context_events().last(),
+			Some(&Event::BridgeTransferRefunded {
+				bridge_id: 1,
+				nonce: 0,
+				who: BOB::get(),
+				amount: 10,
+			})
+		);
+	});
+}
+
+#[test]
+fn pending_bridge_transfers_keep_a_schedule_slot_for_their_refund() {
+	ExtBuilder::default().one_hundred_for_alice().build().execute_with(|| {
+		assert_ok!(Vesting::set_bridge(RuntimeOrigin::root(), 1, b"zklocal".to_vec(), 9924));
+		let schedule = VestingSchedule {
+			start: 0u64,
+			period: 10u64,
+			period_count: 2u32,
+			per_period: 10u64,
+			cliff: 0u64,
+		};
+		assert_ok!(Vesting::add_vesting_schedule(
+			RuntimeOrigin::signed(ALICE::get()),
+			BOB::get(),
+			schedule.clone()
+		));
+		assert_ok!(Vesting::bridge_all_vesting_schedules(
+			RuntimeOrigin::signed(BOB::get()),
+			hex!("2E7F3926Ae74FDCDcAde2c2AB50990C5daFD42bD"),
+			1
+		));
+		assert_eq!(Vesting::reserved_schedule_slots(BOB::get()), 1);
+
+		assert_ok!(Vesting::add_vesting_schedule(
+			RuntimeOrigin::signed(ALICE::get()),
+			BOB::get(),
+			schedule.clone()
+		));
+		assert_noop!(
+			Vesting::add_vesting_schedule(RuntimeOrigin::signed(ALICE::get()), BOB::get(), schedule.clone()),
+			Error::<Runtime>::MaxScheduleOverflow
+		);
+
+		assert_ok!(Vesting::refund_bridge_transfer(
+			RuntimeOrigin::signed(CancelOrigin::get()),
+			1,
+			0
+		));
+		assert_eq!(
+			Vesting::vesting_schedules(BOB::get()).to_vec(),
+			vec![schedule.clone(), schedule]
+		);
+		assert_eq!(Vesting::reserved_schedule_slots(BOB::get()), 0);
+		assert_eq!(mock::balances(&BOB::get()), (40, 40));
+	});
+}
+
+#[test]
+fn refund_merges_the_bridged_schedules_without_room_for_them() {
+	ExtBuilder::default().one_hundred_for_alice().build().execute_with(|| {
+		assert_ok!(Vesting::set_bridge(RuntimeOrigin::root(), 1, b"zklocal".to_vec(), 9924));
+		let first = VestingSchedule {
+			start: 0u64,
+			period: 10u64,
+			period_count: 2u32,
+			per_period: 10u64,
+			cliff: 0u64,
+		};
+		let second = VestingSchedule {
+			start: 0u64,
+			period: 10u64,
+			period_count: 4u32,
+			per_period: 5u64,
+			cliff: 0u64,
+		};
+		assert_ok!(Vesting::add_vesting_schedule(
+			RuntimeOrigin::signed(ALICE::get()),
+			BOB::get(),
+			first.clone()
+		));
+		assert_ok!(Vesting::add_vesting_schedule(
+			RuntimeOrigin::signed(ALICE::get()),
+			BOB::get(),
+			second
+		));
+		assert_ok!(Vesting::bridge_all_vesting_schedules(
+			RuntimeOrigin::signed(BOB::get()),
+			hex!("2E7F3926Ae74FDCDcAde2c2AB50990C5daFD42bD"),
+			1
+		));
+		assert_ok!(Vesting::add_vesting_schedule(
+			RuntimeOrigin::signed(ALICE::get()),
+			BOB::get(),
+			first.clone()
+		));
+
+		assert_ok!(Vesting::refund_bridge_transfer(
+			RuntimeOrigin::signed(CancelOrigin::get()),
+			1,
+			0
+		));
+
+		// both bridged schedules stay locked until the last of them ends
+		assert_eq!(
+			Vesting::vesting_schedules(BOB::get()).to_vec(),
+			vec![
+				first,
+				VestingSchedule {
+					start: 1u64,
+					period: 39u64,
+					period_count: 1u32,
+					per_period: 40u64,
+					cliff: 0u64,
+				}
+			]
+		);
+		assert_eq!(mock::balances(&BOB::get()), (60, 60));
+	});
+}
+
+#[test]
+fn refund_gives_split_amounts_back_to_their_schedules_without_room_for_them() {
+	ExtBuilder::default().one_hundred_for_alice().build().execute_with(|| {
+		assert_ok!(Vesting::set_bridge(RuntimeOrigin::root(), 1, b"zklocal".to_vec(), 9924));
+		let first = VestingSchedule {
+			start: 0u64,
+			period: 10u64,
+			period_count: 2u32,
+			per_period: 10u64,
+			cliff: 0u64,
+		};
+		let second = VestingSchedule {
+			start: 0u64,
+			period: 10u64,
+			period_count: 4u32,
+			per_period: 5u64,
+			cliff: 0u64,
+		};
+		assert_ok!(Vesting::add_vesting_schedule(
+			RuntimeOrigin::signed(ALICE::get()),
+			BOB::get(),
+			first.clone()
+		));
+		assert_ok!(Vesting::add_revocable_vesting_schedule(
+			RuntimeOrigin::signed(ALICE::get()),
+			BOB::get(),
+			second.clone()
+		));
+		assert_ok!(Vesting::bridge_vesting_schedules(
+			RuntimeOrigin::signed(BOB::get()),
+			hex!("2E7F3926Ae74FDCDcAde2c2AB50990C5daFD42bD"),
+			1,
+			BridgeSelection::Amount(24)
+		));
+		assert_eq!(Vesting::vesting_schedules(BOB::get()).len(), 2);
+
+		assert_ok!(Vesting::refund_bridge_transfer(
+			RuntimeOrigin::signed(CancelOrigin::get()),
+			1,
+			0
+		));
+
+		assert_eq!(
+			Vesting::vesting_schedules(BOB::get()).to_vec(),
+			vec![first, second.clone()]
+		);
+		assert_eq!(
+			<RevocableSchedules<Runtime>>::get(BOB::get(), ALICE::get()).to_vec(),
+			vec![second]
+		);
+		assert_eq!(mock::balances(&BOB::get()), (40, 40));
+	});
+}
+
+#[test]
+fn refund_gives_back_the_bridge_period_allowance() {
+	ExtBuilder::default().one_hundred_for_alice().build().execute_with(|| {
+		assert_ok!(Vesting::set_bridge(RuntimeOrigin::root(), 1, b"zklocal".to_vec(), 9924));
+		assert_ok!(Vesting::update_bridge(
+			RuntimeOrigin::root(),
+			1,
+			BridgeUpdate {
+				max_per_period: Some(Some(PeriodCap { period: 10, amount: 50 })),
+				..Default::default()
+			}
+		));
+		let schedule = VestingSchedule {
+			start: 0u64,
+			period: 10u64,
+			period_count: 2u32,
+			per_period: 10u64,
+			cliff: 0u64,
+		};
+		assert_ok!(Vesting::add_vesting_schedule(
+			RuntimeOrigin::signed(ALICE::get()),
+			BOB::get(),
+			schedule
+		));
+		assert_ok!(Vesting::bridge_all_vesting_schedules(
+			RuntimeOrigin::signed(BOB::get()),
+			hex!("2E7F3926Ae74FDCDcAde2c2AB50990C5daFD42bD"),
+			1
+		));
+		assert_eq!(Vesting::bridge_period_usage(BridgeId(1)), (0, 20));
+
+		assert_ok!(Vesting::refund_bridge_transfer(
+			RuntimeOrigin::signed(CancelOrigin::get()),
+			1,
+			0
+		));
+		assert_eq!(Vesting::bridge_period_usage(BridgeId(1)), (0, 0));
+	});
+}
 
+#[test]
+fn anyone_can_refund_expired_bridge_transfers_but_not_confirmed_ones() {
+	ExtBuilder::default().one_hundred_for_alice().build().execute_with(|| {
+		let address = hex!("2E7F3926Ae74FDCDcAde2c2AB50990C5daFD42bD");
+		assert_ok!(Vesting::set_bridge(RuntimeOrigin::root(), 1, b"zklocal".to_vec(), 9924));
+		let schedule = VestingSchedule {
+			start: 0u64,
+			period: 10u64,
+			period_count: 2u32,
+			per_period: 10u64,
+			cliff: 0u64,
+		};
+		for _ in 0..2 {
 			assert_ok!(Vesting::add_vesting_schedule(
 				RuntimeOrigin::signed(ALICE::get()),
 				BOB::get(),
-				schedules[0].clone(),
+				schedule.clone()
 			));
-			assert_eq!(Vesting::vesting_schedules(BOB::get()).to_vec().len(), 1);
+		}
+		for _ in 0..2 {
+			assert_ok!(Vesting::bridge_vesting_schedules(
+				RuntimeOrigin::signed(BOB::get()),
+				address,
+				1,
+				BridgeSelection::Schedules(bounded_vec![(0, schedule.clone())])
+			));
+		}
+		assert_ok!(Vesting::confirm_bridge_transfer(
+			RuntimeOrigin::signed(BridgeOracle::get()),
+			1,
+			1
+		));
 
-			let expected = vec![Event::VestingScheduleAdded(1, 2, schedules[0].clone())];
+		assert_noop!(
+			Vesting::refund_bridge_transfer(RuntimeOrigin::signed(ALICE::get()), 1, 0),
+			Error::<Runtime>::BridgeTransferNotExpired
+		);
 
-			assert_eq!(context_events(), expected);
+		System::set_block_number(1 + BridgeTransferTimeout::get());
+		assert_noop!(
+			Vesting::refund_bridge_transfer(RuntimeOrigin::signed(ALICE::get()), 1, 1),
+			Error::<Runtime>::BridgeTransferNotFound
+		);
+		assert_noop!(Vesting::refund_bridge_transfer(RuntimeOrigin::none(), 1, 0), BadOrigin);
+		assert_ok!(Vesting::refund_bridge_transfer(
+			RuntimeOrigin::signed(ALICE::get()),
+			1,
+			0
+		));
+		assert_eq!(Vesting::vesting_schedules(BOB::get()).to_vec(), vec![schedule]);
+		assert_eq!(mock::balances(&BOB::get()), (20, 20));
+		assert_noop!(
+			Vesting::refund_bridge_transfer(RuntimeOrigin::root(), 1, 0),
+			Error::<Runtime>::BridgeTransferNotFound
+		);
+	});
+}
 
-			assert_ok!(Vesting::add_vesting_schedule(
-				RuntimeOrigin::signed(ALICE::get()),
-				BOB::get(),
-				schedules[1].clone(),
-			));
-			assert_eq!(Vesting::vesting_schedules(BOB::get()).to_vec().len(), 2);
+#[test]
+fn free_balance_bridge_transfers_cannot_be_refunded() {
+	ExtBuilder::default().one_hundred_for_alice().build().execute_with(|| {
+		assert_ok!(Vesting::set_bridge(RuntimeOrigin::root(), 1, b"zklocal".to_vec(), 9924));
+		// as done by the allocations pallet, which has no say over the remote beneficiary
+		assert_ok!(<Vesting as BridgeTransfer<_, _>>::bridge_transfer(
+			&ALICE::get(),
+			hex!("2E7F3926Ae74FDCDcAde2c2AB50990C5daFD42bD"),
+			1,
+			10
+		));
 
-			let expected = vec![
-				Event::VestingScheduleAdded(1, 2, schedules[0].clone()),
-				Event::VestingScheduleAdded(1, 2, schedules[1].clone()),
-			];
+		assert_noop!(
+			Vesting::refund_bridge_transfer(RuntimeOrigin::signed(CancelOrigin::get()), 1, 0),
+			Error::<Runtime>::BridgeTransferNotRefundable
+		);
+		System::set_block_number(1 + BridgeTransferTimeout::get());
+		assert_noop!(
+			Vesting::refund_bridge_transfer(RuntimeOrigin::signed(BOB::get()), 1, 0),
+			Error::<Runtime>::BridgeTransferNotRefundable
+		);
+		assert_eq!(PalletBalances::free_balance(ALICE::get()), 90);
 
-			assert_eq!(context_events(), expected);
+		assert_ok!(Vesting::confirm_bridge_transfer(
+			RuntimeOrigin::signed(BridgeOracle::get()),
+			1,
+			0
+		));
+	});
+}
 
-			assert_err!(
-				Vesting::add_vesting_schedule(RuntimeOrigin::signed(ALICE::get()), BOB::get(), schedules[2].clone(),),
-				<Error<Runtime>>::MaxScheduleOverflow,
-			);
+#[test]
+fn bridge_transfer_fails_for_unknown_bridge() {
+	ExtBuilder::default().one_hundred_for_alice().build().execute_with(|| {
+		assert_noop!(
+			<Vesting as BridgeTransfer<_, _>>::bridge_transfer(
+				&ALICE::get(),
+				hex!("2E7F3926Ae74FDCDcAde2c2AB50990C5daFD42bD"),
+				1,
+				40
+			),
+			Error::<Runtime>::BridgeNotFound
+		);
+	});
+}
+
+#[test]
+fn update_bridge_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(Vesting::set_bridge(RuntimeOrigin::root(), 1, b"zklocal".to_vec(), 9924));
+		let cap = PeriodCap { period: 10, amount: 50 };
+		assert_ok!(Vesting::update_bridge(
+			RuntimeOrigin::root(),
+			1,
+			BridgeUpdate {
+				name: Some(b"zkmain".to_vec()),
+				status: Some(BridgeStatus::Paused),
+				oracles: Some(bounded_vec![BOB::get()]),
+				max_per_transfer: Some(Some(30)),
+				max_per_period: Some(Some(cap.clone())),
+				..Default::default()
+			}
+		));
+
+		assert_eq!(
+			Vesting::bridges(BridgeId(1)),
+			Some(BridgeDetails {
+				chain_id: 9924,
+				name: b"zkmain".to_vec().try_into().unwrap(),
+				status: BridgeStatus::Paused,
+				oracles: bounded_vec![BOB::get()],
+				max_per_transfer: Some(30),
+				max_per_period: Some(cap),
+			})
+		);
+		assert_eq!(context_events(), vec![Event::BridgeUpdated { bridge_id: 1 }]);
+
+		assert_ok!(Vesting::update_bridge(
+			RuntimeOrigin::root(),
+			1,
+			BridgeUpdate {
+				status: Some(BridgeStatus::Active),
+				max_per_transfer: Some(None),
+				..Default::default()
+			}
+		));
+		let details = Vesting::bridges(BridgeId(1)).unwrap();
+		assert_eq!(details.status, BridgeStatus::Active);
+		assert_eq!(details.max_per_transfer, None);
+		assert_eq!(details.oracles.to_vec(), vec![BOB::get()]);
+	});
+}
+
+#[test]
+fn update_bridge_fails_on_bad_input() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			Vesting::update_bridge(RuntimeOrigin::root(), 1, BridgeUpdate::default()),
+			Error::<Runtime>::BridgeNotFound
+		);
+		assert_ok!(Vesting::set_bridge(RuntimeOrigin::root(), 1, b"zklocal".to_vec(), 9924));
+		assert_noop!(
+			Vesting::update_bridge(RuntimeOrigin::signed(ALICE::get()), 1, BridgeUpdate::default()),
+			BadOrigin
+		);
+		assert_noop!(
+			Vesting::update_bridge(
+				RuntimeOrigin::root(),
+				1,
+				BridgeUpdate {
+					name: Some(b"somereallylonglonglongnnnnnnnnameeee".to_vec()),
+					..Default::default()
+				}
+			),
+			Error::<Runtime>::BridgeNameTooLong
+		);
+		assert_noop!(
+			Vesting::update_bridge(
+				RuntimeOrigin::root(),
+				1,
+				BridgeUpdate {
+					max_per_period: Some(Some(PeriodCap { period: 0, amount: 50 })),
+					..Default::default()
+				}
+			),
+			Error::<Runtime>::ZeroBridgePeriod
+		);
+	});
+}
+
+#[test]
+fn paused_bridge_rejects_transfers() {
+	ExtBuilder::default().one_hundred_for_alice().build().execute_with(|| {
+		let address = hex!("2E7F3926Ae74FDCDcAde2c2AB50990C5daFD42bD");
+		assert_ok!(Vesting::set_bridge(RuntimeOrigin::root(), 1, b"zklocal".to_vec(), 9924));
+		assert_ok!(Vesting::add_vesting_schedule(
+			RuntimeOrigin::signed(ALICE::get()),
+			BOB::get(),
+			VestingSchedule {
+				start: 0u64,
+				period: 10u64,
+				period_count: 2u32,
+				per_period: 10u64,
+				cliff: 0u64,
+			}
+		));
+		assert_ok!(Vesting::update_bridge(
+			RuntimeOrigin::root(),
+			1,
+			BridgeUpdate {
+				status: Some(BridgeStatus::Paused),
+				..Default::default()
+			}
+		));
+
+		assert_noop!(
+			<Vesting as BridgeTransfer<_, _>>::bridge_transfer(&ALICE::get(), address, 1, 10),
+			Error::<Runtime>::BridgePaused
+		);
+		assert_noop!(
+			Vesting::bridge_all_vesting_schedules(RuntimeOrigin::signed(BOB::get()), address, 1),
+			Error::<Runtime>::BridgePaused
+		);
+
+		assert_ok!(Vesting::update_bridge(
+			RuntimeOrigin::root(),
+			1,
+			BridgeUpdate {
+				status: Some(BridgeStatus::Active),
+				..Default::default()
+			}
+		));
+		assert_ok!(Vesting::bridge_all_vesting_schedules(
+			RuntimeOrigin::signed(BOB::get()),
+			address,
+			1
+		));
+	});
+}
+
+#[test]
+fn bridge_caps_limit_transfers() {
+	ExtBuilder::default().one_hundred_for_alice().build().execute_with(|| {
+		let address = hex!("2E7F3926Ae74FDCDcAde2c2AB50990C5daFD42bD");
+		assert_ok!(Vesting::set_bridge(RuntimeOrigin::root(), 1, b"zklocal".to_vec(), 9924));
+		assert_ok!(Vesting::update_bridge(
+			RuntimeOrigin::root(),
+			1,
+			BridgeUpdate {
+				max_per_transfer: Some(Some(30)),
+				max_per_period: Some(Some(PeriodCap { period: 10, amount: 50 })),
+				..Default::default()
+			}
+		));
+
+		assert_noop!(
+			<Vesting as BridgeTransfer<_, _>>::bridge_transfer(&ALICE::get(), address, 1, 31),
+			Error::<Runtime>::BridgeTransferCapExceeded
+		);
+		assert_ok!(<Vesting as BridgeTransfer<_, _>>::bridge_transfer(
+			&ALICE::get(),
+			address,
+			1,
+			30
+		));
+		assert_noop!(
+			<Vesting as BridgeTransfer<_, _>>::bridge_transfer(&ALICE::get(), address, 1, 30),
+			Error::<Runtime>::BridgePeriodCapExceeded
+		);
+		assert_ok!(<Vesting as BridgeTransfer<_, _>>::bridge_transfer(
+			&ALICE::get(),
+			address,
+			1,
+			20
+		));
+		assert_eq!(Vesting::bridge_period_usage(BridgeId(1)), (0, 50));
+
+		System::set_block_number(10);
+		assert_ok!(<Vesting as BridgeTransfer<_, _>>::bridge_transfer(
+			&ALICE::get(),
+			address,
+			1,
+			30
+		));
+		assert_eq!(Vesting::bridge_period_usage(BridgeId(1)), (1, 30));
+		assert_eq!(mock::balances(&ALICE::get()), (20, 0));
+	});
+}
+
+#[test]
+fn bridge_oracle_set_confirms_pending_transfers() {
+	ExtBuilder::default().one_hundred_for_alice().build().execute_with(|| {
+		assert_ok!(Vesting::set_bridge(RuntimeOrigin::root(), 1, b"zklocal".to_vec(), 9924));
+		assert_ok!(Vesting::update_bridge(
+			RuntimeOrigin::root(),
+			1,
+			BridgeUpdate {
+				oracles: Some(bounded_vec![BOB::get()]),
+				..Default::default()
+			}
+		));
+		assert_ok!(<Vesting as BridgeTransfer<_, _>>::bridge_transfer(
+			&ALICE::get(),
+			hex!("2E7F3926Ae74FDCDcAde2c2AB50990C5daFD42bD"),
+			1,
+			10
+		));
+
+		assert_noop!(
+			Vesting::confirm_bridge_transfer(RuntimeOrigin::signed(ALICE::get()), 1, 0),
+			Error::<Runtime>::NotBridgeOracle
+		);
+		assert_noop!(
+			Vesting::confirm_bridge_transfer(RuntimeOrigin::signed(BOB::get()), 2, 0),
+			Error::<Runtime>::BridgeNotFound
+		);
+		assert_ok!(Vesting::confirm_bridge_transfer(
+			RuntimeOrigin::signed(BOB::get()),
+			1,
+			0
+		));
+		assert_eq!(Vesting::pending_bridge_transfers(BridgeId(1), 0), None);
+	});
+}
+
+#[test]
+fn bridge_vesting_schedules_bridges_selected_schedules() {
+	ExtBuilder::default().one_hundred_for_alice().build().execute_with(|| {
+		let address = hex!("2E7F3926Ae74FDCDcAde2c2AB50990C5daFD42bD");
+		assert_ok!(Vesting::set_bridge(RuntimeOrigin::root(), 1, b"zklocal".to_vec(), 9924));
+		let first = VestingSchedule {
+			start: 0u64,
+			period: 10u64,
+			period_count: 2u32,
+			per_period: 10u64,
+			cliff: 0u64,
+		};
+		let second = VestingSchedule {
+			start: 0u64,
+			period: 10u64,
+			period_count: 4u32,
+			per_period: 5u64,
+			cliff: 0u64,
+		};
+		assert_ok!(Vesting::add_vesting_schedule(
+			RuntimeOrigin::signed(ALICE::get()),
+			BOB::get(),
+			first.clone()
+		));
+		assert_ok!(Vesting::add_revocable_vesting_schedule(
+			RuntimeOrigin::signed(ALICE::get()),
+			BOB::get(),
+			second.clone()
+		));
+
+		assert_noop!(
+			Vesting::bridge_vesting_schedules(
+				RuntimeOrigin::signed(BOB::get()),
+				address,
+				1,
+				BridgeSelection::Schedules(bounded_vec![(2, second.clone())])
+			),
+			Error::<Runtime>::InvalidScheduleIndex
+		);
+		assert_noop!(
+			Vesting::bridge_vesting_schedules(
+				RuntimeOrigin::signed(BOB::get()),
+				address,
+				1,
+				BridgeSelection::Schedules(bounded_vec![(0, second.clone())])
+			),
+			Error::<Runtime>::ScheduleMismatch
+		);
+		assert_ok!(Vesting::bridge_vesting_schedules(
+			RuntimeOrigin::signed(BOB::get()),
+			address,
+			1,
+			BridgeSelection::Schedules(bounded_vec![(1, second.clone())])
+		));
+
+		assert_eq!(Vesting::vesting_schedules(BOB::get()).to_vec(), vec![first]);
+		assert_eq!(<RevocableSchedules<Runtime>>::iter_prefix(BOB::get()).count(), 0);
+		assert_eq!(mock::balances(&BOB::get()), (20, 20));
+		assert_eq!(
+			context_events().last(),
+			Some(&Event::BridgeInitiated {
+				to: address,
+				bridge_id: 1,
+				nonce: 0,
+				amount: 20,
+				grants: bounded_vec![second],
+			})
+		);
+	});
+}
+
+#[test]
+fn bridge_vesting_schedules_splits_an_amount_proportionally() {
+	ExtBuilder::default().one_hundred_for_alice().build().execute_with(|| {
+		let address = hex!("2E7F3926Ae74FDCDcAde2c2AB50990C5daFD42bD");
+		assert_ok!(Vesting::set_bridge(RuntimeOrigin::root(), 1, b"zklocal".to_vec(), 9924));
+		let first = VestingSchedule {
+			start: 0u64,
+			period: 10u64,
+			period_count: 2u32,
+			per_period: 10u64,
+			cliff: 0u64,
+		};
+		let second = VestingSchedule {
+			start: 0u64,
+			period: 10u64,
+			period_count: 4u32,
+			per_period: 5u64,
+			cliff: 0u64,
+		};
+		assert_ok!(Vesting::add_vesting_schedule(
+			RuntimeOrigin::signed(ALICE::get()),
+			BOB::get(),
+			first.clone()
+		));
+		assert_ok!(Vesting::add_revocable_vesting_schedule(
+			RuntimeOrigin::signed(ALICE::get()),
+			BOB::get(),
+			second.clone()
+		));
+
+		assert_noop!(
+			Vesting::bridge_vesting_schedules(
+				RuntimeOrigin::signed(BOB::get()),
+				address,
+				1,
+				BridgeSelection::Amount(41)
+			),
+			Error::<Runtime>::InvalidBridgeAmount
+		);
+		// `per_period`s of 7 and 3 would only bridge 26 of the 30 requested
+		assert_noop!(
+			Vesting::bridge_vesting_schedules(
+				RuntimeOrigin::signed(BOB::get()),
+				address,
+				1,
+				BridgeSelection::Amount(30)
+			),
+			Error::<Runtime>::InexactBridgeAmount
+		);
+		// `per_period`s of 5 and 2 would only bridge 18 of the 20 requested
+		assert_noop!(
+			Vesting::bridge_vesting_schedules(
+				RuntimeOrigin::signed(BOB::get()),
+				address,
+				1,
+				BridgeSelection::Amount(20)
+			),
+			Error::<Runtime>::InexactBridgeAmount
+		);
+		assert_ok!(Vesting::bridge_vesting_schedules(
+			RuntimeOrigin::signed(BOB::get()),
+			address,
+			1,
+			BridgeSelection::Amount(24)
+		));
 
-			assert_eq!(Vesting::vesting_schedules(BOB::get()).to_vec().len(), 2);
-		});
+		// both schedules give the same share of their locked funds
+		let kept_second = VestingSchedule {
+			per_period: 2u64,
+			..second.clone()
+		};
+		assert_eq!(
+			Vesting::vesting_schedules(BOB::get()).to_vec(),
+			vec![
+				VestingSchedule {
+					per_period: 4u64,
+					..first.clone()
+				},
+				kept_second.clone()
+			]
+		);
+		assert_eq!(
+			<RevocableSchedules<Runtime>>::get(BOB::get(), ALICE::get()).to_vec(),
+			vec![kept_second]
+		);
+		assert_eq!(mock::balances(&BOB::get()), (16, 16));
+		assert_eq!(
+			context_events().last(),
+			Some(&Event::BridgeInitiated {
+				to: address,
+				bridge_id: 1,
+				nonce: 0,
+				amount: 24,
+				grants: bounded_vec![
+					VestingSchedule {
+						per_period: 6u64,
+						..first
+					},
+					VestingSchedule {
+						per_period: 3u64,
+						..second
+					},
+				],
+			})
+		);
+	});
 }
 
 #[test]
-fn add_vesting_schedule_overflow_cfg_min_check() {
+fn bridge_vesting_schedules_bridges_non_round_amounts_exactly() {
 	ExtBuilder::default()
-		.balances(vec![(ALICE::get(), 1000)])
+		.balances(vec![(ALICE::get(), 2_000_000_000_000)])
 		.build()
 		.execute_with(|| {
-			System::set_block_number(1);
-
-			let schedules = [VestingSchedule {
+			let address = hex!("2E7F3926Ae74FDCDcAde2c2AB50990C5daFD42bD");
+			assert_ok!(Vesting::set_bridge(RuntimeOrigin::root(), 1, b"zklocal".to_vec(), 9924));
+			let schedule = VestingSchedule {
 				start: 0u64,
 				period: 10u64,
 				period_count: 1u32,
-				per_period: 100u64,
-			}];
-
-			mock::MAX_SCHEDULE.with(|v| *v.borrow_mut() = 0);
-
-			assert_eq!(Vesting::vesting_schedules(BOB::get()).to_vec().len(), 0);
-
-			assert_err!(
-				Vesting::add_vesting_schedule(RuntimeOrigin::signed(ALICE::get()), BOB::get(), schedules[0].clone(),),
-				<Error<Runtime>>::MaxScheduleOverflow,
-			);
-
-			assert_eq!(Vesting::vesting_schedules(BOB::get()).to_vec().len(), 0);
-
-			let expected = vec![];
-			assert_eq!(context_events(), expected);
-
-			mock::MAX_SCHEDULE.with(|v| *v.borrow_mut() = 1);
-
+				per_period: 1_000_000_000_000u64,
+				cliff: 0u64,
+			};
 			assert_ok!(Vesting::add_vesting_schedule(
 				RuntimeOrigin::signed(ALICE::get()),
 				BOB::get(),
-				schedules[0].clone(),
+				schedule.clone()
 			));
-			assert_eq!(Vesting::vesting_schedules(BOB::get()).to_vec().len(), 1);
 
-			let expected = vec![Event::VestingScheduleAdded(1, 2, schedules[0].clone())];
+			assert_ok!(Vesting::bridge_vesting_schedules(
+				RuntimeOrigin::signed(BOB::get()),
+				address,
+				1,
+				BridgeSelection::Amount(333_333_333_333)
+			));
 
-			assert_eq!(context_events(), expected);
+			assert_eq!(
+				Vesting::vesting_schedules(BOB::get()).to_vec(),
+				vec![VestingSchedule {
+					per_period: 666_666_666_667u64,
+					..schedule.clone()
+				}]
+			);
+			assert_eq!(mock::balances(&BOB::get()), (666_666_666_667, 666_666_666_667));
+			assert_eq!(
+				context_events().last(),
+				Some(&Event::BridgeInitiated {
+					to: address,
+					bridge_id: 1,
+					nonce: 0,
+					amount: 333_333_333_333,
+					grants: bounded_vec![VestingSchedule {
+						per_period: 333_333_333_333u64,
+						..schedule
+					}],
+				})
+			);
 		});
 }
 
 #[test]
-fn add_vesting_schedule_overflow_cfg_max_check() {
+fn bridge_vesting_schedules_never_bridges_more_than_requested() {
 	ExtBuilder::default()
-		.balances(vec![(ALICE::get(), 10_000_000)])
+		.balances(vec![(ALICE::get(), 2_000_000_000_000)])
 		.build()
 		.execute_with(|| {
-			System::set_block_number(1);
-
-			let schedules = [VestingSchedule {
+			let address = hex!("2E7F3926Ae74FDCDcAde2c2AB50990C5daFD42bD");
+			assert_ok!(Vesting::set_bridge(RuntimeOrigin::root(), 1, b"zklocal".to_vec(), 9924));
+			let schedule = VestingSchedule {
 				start: 0u64,
 				period: 10u64,
 				period_count: 1u32,
-				per_period: 100u64,
-			}];
-
-			let schedule_max = 500;
-
-			mock::MAX_SCHEDULE.with(|v| *v.borrow_mut() = schedule_max);
+				per_period: 1_000_000_000_000u64,
+				cliff: 0u64,
+			};
+			assert_ok!(Vesting::add_vesting_schedule(
+				RuntimeOrigin::signed(ALICE::get()),
+				BOB::get(),
+				schedule.clone()
+			));
 
-			(0..schedule_max).for_each(|iter_index| {
-				assert_ok!(Vesting::add_vesting_schedule(
-					RuntimeOrigin::signed(ALICE::get()),
-					BOB::get(),
-					schedules[0].clone(),
-				));
-				assert_eq!(
-					Vesting::vesting_schedules(BOB::get()).to_vec().len() as u32,
-					iter_index + 1
-				);
-				assert_eq!(context_events().len() as u32, iter_index + 1);
-			});
+			// a ratio of the locked funds would round up to 666_666_667_000
+			assert_ok!(Vesting::bridge_vesting_schedules(
+				RuntimeOrigin::signed(BOB::get()),
+				address,
+				1,
+				BridgeSelection::Amount(666_666_666_667)
+			));
 
-			assert_err!(
-				Vesting::add_vesting_schedule(RuntimeOrigin::signed(ALICE::get()), BOB::get(), schedules[0].clone(),),
-				<Error<Runtime>>::MaxScheduleOverflow,
+			assert_eq!(
+				Vesting::vesting_schedules(BOB::get()).to_vec(),
+				vec![VestingSchedule {
+					per_period: 333_333_333_333u64,
+					..schedule.clone()
+				}]
 			);
-
+			assert_eq!(mock::balances(&BOB::get()), (333_333_333_333, 333_333_333_333));
 			assert_eq!(
-				Vesting::vesting_schedules(BOB::get()).to_vec().len(),
-				schedule_max as usize
+				context_events().last(),
+				Some(&Event::BridgeInitiated {
+					to: address,
+					bridge_id: 1,
+					nonce: 0,
+					amount: 666_666_666_667,
+					grants: bounded_vec![VestingSchedule {
+						per_period: 666_666_666_667u64,
+						..schedule
+					}],
+				})
 			);
-			assert_eq!(context_events().len(), schedule_max as usize);
 		});
 }
 
 #[test]
-fn renounce_only_works_for_cancel_origin() {
-	ExtBuilder::default().build().execute_with(|| {
-		assert_noop!(
-			Vesting::renounce(RuntimeOrigin::signed(ALICE::get()), BOB::get()),
-			BadOrigin
-		);
-	})
+fn next_unlock_accounts_for_cliffs() {
+	let schedule = VestingSchedule {
+		start: 0u64,
+		period: 10u64,
+		period_count: 4u32,
+		per_period: 10u64,
+		cliff: 25u64,
+	};
+	assert_eq!(schedule.next_unlock(1), Some(25));
+	assert_eq!(schedule.next_unlock(25), Some(30));
+	assert_eq!(schedule.next_unlock(39), Some(40));
+	assert_eq!(schedule.next_unlock(40), None);
+
+	let short_cliff = VestingSchedule {
+		cliff: 5u64,
+		..schedule
+	};
+	assert_eq!(short_cliff.next_unlock(1), Some(10));
 }
 
 #[test]
-fn renounce_privileges() {
+fn vesting_status_reports_locked_and_claimable_amounts() {
 	ExtBuilder::default().one_hundred_for_alice().build().execute_with(|| {
 		let schedule = VestingSchedule {
 			start: 0u64,
 			period: 10u64,
 			period_count: 2u32,
 			per_period: 10u64,
+			cliff: 0u64,
 		};
 		assert_ok!(Vesting::add_vesting_schedule(
 			RuntimeOrigin::signed(ALICE::get()),
 			BOB::get(),
-			schedule
+			schedule.clone()
 		));
 
-		assert!(!Vesting::renounced(BOB::get()));
+		assert_eq!(
+			Vesting::vesting_status(&BOB::get()),
+			runtime_api::VestingStatus {
+				schedules: vec![schedule.clone()],
+				timed_schedules: vec![],
+				locked: 20,
+				claimable: 0,
+				next_unlock: Some(10),
+				end: Some(20),
+				timed_next_unlock: None,
+				timed_end: None,
+				renounced: false,
+			}
+		);
+
+		System::set_block_number(12);
+		let status = Vesting::vesting_status(&BOB::get());
+		assert_eq!(
+			(status.locked, status.claimable, status.next_unlock),
+			(10, 10, Some(20))
+		);
+
+		assert_ok!(Vesting::claim(RuntimeOrigin::signed(BOB::get())));
+		assert_eq!(Vesting::vesting_status(&BOB::get()).claimable, 0);
+
 		assert_ok!(Vesting::renounce(
 			RuntimeOrigin::signed(CancelOrigin::get()),
 			BOB::get()
 		));
-		assert!(Vesting::renounced(BOB::get()));
-		assert_noop!(
-			Vesting::cancel_all_vesting_schedules(
-				RuntimeOrigin::signed(CancelOrigin::get()),
+		System::set_block_number(25);
+		let status = Vesting::vesting_status(&BOB::get());
+		assert_eq!((status.locked, status.claimable, status.next_unlock), (0, 10, None));
+		assert!(status.renounced);
+	});
+}
+
+#[test]
+fn timed_schedules_coexist_with_block_schedules() {
+	ExtBuilder::default().one_hundred_for_alice().build().execute_with(|| {
+		const MONTH: u64 = 30 * 24 * 60 * 60 * 1000;
+		// 2025-01-01T00:00:00Z
+		const START: u64 = 1_735_689_600_000;
+		Timestamp::set_timestamp(START);
+
+		let schedule = VestingSchedule {
+			start: 0u64,
+			period: 10u64,
+			period_count: 2u32,
+			per_period: 10u64,
+			cliff: 0u64,
+		};
+		let timed_schedule = VestingSchedule {
+			start: START,
+			period: MONTH,
+			period_count: 3u32,
+			per_period: 10u64,
+			cliff: 0u64,
+		};
+		assert_ok!(Vesting::add_vesting_schedule(
+			RuntimeOrigin::signed(ALICE::get()),
+			BOB::get(),
+			schedule
+		));
+		assert_ok!(Vesting::add_timed_vesting_schedule(
+			RuntimeOrigin::signed(ALICE::get()),
+			BOB::get(),
+			timed_schedule.clone()
+		));
+		assert_eq!(
+			context_events().last(),
+			Some(&Event::TimedVestingScheduleAdded(
+				ALICE::get(),
 				BOB::get(),
-				CancelOrigin::get()
+				timed_schedule.clone()
+			))
+		);
+		assert_eq!(mock::balances(&BOB::get()), (50, 50));
+
+		System::set_block_number(21);
+		assert_ok!(Vesting::claim(RuntimeOrigin::signed(BOB::get())));
+		assert_eq!(mock::balances(&BOB::get()), (50, 30));
+
+		Timestamp::set_timestamp(START + MONTH);
+		assert_ok!(Vesting::claim(RuntimeOrigin::signed(BOB::get())));
+		assert_eq!(mock::balances(&BOB::get()), (50, 20));
+		assert_eq!(
+			Vesting::vesting_status(&BOB::get()).timed_next_unlock,
+			Some(START + 2 * MONTH)
+		);
+
+		Timestamp::set_timestamp(START + 3 * MONTH);
+		assert_ok!(Vesting::claim(RuntimeOrigin::signed(BOB::get())));
+		assert_eq!(mock::balances(&BOB::get()), (50, 0));
+		assert!(!<TimedVestingSchedules<Runtime>>::contains_key(BOB::get()));
+	});
+}
+
+#[test]
+fn timed_schedules_are_canceled_but_not_bridged() {
+	ExtBuilder::default().one_hundred_for_alice().build().execute_with(|| {
+		let address = hex!("2E7F3926Ae74FDCDcAde2c2AB50990C5daFD42bD");
+		assert_ok!(Vesting::set_bridge(RuntimeOrigin::root(), 1, b"zklocal".to_vec(), 9924));
+		let schedule = VestingSchedule {
+			start: 0u64,
+			period: 10u64,
+			period_count: 2u32,
+			per_period: 10u64,
+			cliff: 0u64,
+		};
+		let timed_schedule = VestingSchedule {
+			start: 0u64,
+			period: 1_000u64,
+			period_count: 3u32,
+			per_period: 10u64,
+			cliff: 0u64,
+		};
+		assert_ok!(Vesting::add_vesting_schedule(
+			RuntimeOrigin::signed(ALICE::get()),
+			BOB::get(),
+			schedule
+		));
+		assert_ok!(Vesting::add_timed_vesting_schedule(
+			RuntimeOrigin::signed(ALICE::get()),
+			BOB::get(),
+			timed_schedule.clone()
+		));
+
+		assert_ok!(Vesting::bridge_all_vesting_schedules(
+			RuntimeOrigin::signed(BOB::get()),
+			address,
+			1
+		));
+		assert_eq!(mock::balances(&BOB::get()), (30, 30));
+		assert_eq!(
+			Vesting::timed_vesting_schedules(BOB::get()).to_vec(),
+			vec![timed_schedule]
+		);
+
+		assert_ok!(Vesting::cancel_all_vesting_schedules(
+			RuntimeOrigin::root(),
+			BOB::get(),
+			ALICE::get()
+		));
+		assert_eq!(mock::balances(&BOB::get()), (0, 0));
+		assert!(!<TimedVestingSchedules<Runtime>>::contains_key(BOB::get()));
+	});
+}
+
+#[test]
+fn timed_schedules_move_with_all_schedules_and_are_canceled_by_index() {
+	ExtBuilder::default().one_hundred_for_alice().build().execute_with(|| {
+		let schedule = VestingSchedule {
+			start: 0u64,
+			period: 10u64,
+			period_count: 2u32,
+			per_period: 10u64,
+			cliff: 0u64,
+		};
+		let timed_schedule = VestingSchedule {
+			start: 0u64,
+			period: 1_000u64,
+			period_count: 3u32,
+			per_period: 10u64,
+			cliff: 0u64,
+		};
+		let other_timed_schedule = VestingSchedule {
+			per_period: 5u64,
+			..timed_schedule.clone()
+		};
+		assert_ok!(Vesting::add_vesting_schedule(
+			RuntimeOrigin::signed(ALICE::get()),
+			BOB::get(),
+			schedule.clone()
+		));
+		assert_ok!(Vesting::add_timed_vesting_schedule(
+			RuntimeOrigin::signed(ALICE::get()),
+			BOB::get(),
+			timed_schedule.clone()
+		));
+		assert_ok!(Vesting::add_timed_vesting_schedule(
+			RuntimeOrigin::signed(ALICE::get()),
+			BOB::get(),
+			other_timed_schedule.clone()
+		));
+
+		assert_ok!(Vesting::transfer_vesting_schedules(
+			RuntimeOrigin::signed(BOB::get()),
+			CancelOrigin::get(),
+			None
+		));
+		assert_eq!(mock::balances(&BOB::get()), (0, 0));
+		assert!(!<TimedVestingSchedules<Runtime>>::contains_key(BOB::get()));
+		assert_eq!(Vesting::vesting_schedules(CancelOrigin::get()).to_vec(), vec![schedule]);
+		assert_eq!(
+			Vesting::timed_vesting_schedules(CancelOrigin::get()).to_vec(),
+			vec![timed_schedule.clone(), other_timed_schedule.clone()]
+		);
+		assert_eq!(mock::balances(&CancelOrigin::get()), (65, 65));
+
+		assert_noop!(
+			Vesting::cancel_timed_vesting_schedule(
+				RuntimeOrigin::root(),
+				CancelOrigin::get(),
+				ALICE::get(),
+				2,
+				other_timed_schedule.clone()
 			),
-			Error::<Runtime>::Renounced
+			Error::<Runtime>::InvalidScheduleIndex
+		);
+		assert_noop!(
+			Vesting::cancel_timed_vesting_schedule(
+				RuntimeOrigin::root(),
+				CancelOrigin::get(),
+				ALICE::get(),
+				0,
+				other_timed_schedule.clone()
+			),
+			Error::<Runtime>::ScheduleMismatch
+		);
+		assert_ok!(Vesting::cancel_timed_vesting_schedule(
+			RuntimeOrigin::root(),
+			CancelOrigin::get(),
+			ALICE::get(),
+			1,
+			other_timed_schedule
+		));
+		assert_eq!(
+			Vesting::timed_vesting_schedules(CancelOrigin::get()).to_vec(),
+			vec![timed_schedule]
+		);
+		assert_eq!(mock::balances(&CancelOrigin::get()), (50, 50));
+		assert_eq!(PalletBalances::free_balance(ALICE::get()), 50);
+		assert_eq!(
+			context_events().last(),
+			Some(&Event::TimedVestingScheduleCanceled(CancelOrigin::get(), 1, 15))
+		);
+	});
+}
+
+#[test]
+fn genesis_registers_schedules_bridges_and_renounced_accounts() {
+	let storage = GenesisConfig::<Runtime> {
+		vesting: vec![(BOB::get(), vec![(0u64, 10u64, 1u32, 5u64)])],
+		cliffed_vesting: vec![(BOB::get(), vec![(0u64, 10u64, 2u32, 10u64, 15u64)])],
+		bridges: vec![(1, b"zklocal".to_vec(), 9924, vec![BridgeOracle::get()])],
+		renounced: vec![BOB::get()],
+	}
+	.build_storage()
+	.unwrap();
+
+	sp_io::TestExternalities::from(storage).execute_with(|| {
+		assert_eq!(
+			Vesting::vesting_schedules(BOB::get()).to_vec(),
+			vec![
+				VestingSchedule {
+					start: 0u64,
+					period: 10u64,
+					period_count: 1u32,
+					per_period: 5u64,
+					cliff: 0u64,
+				},
+				VestingSchedule {
+					start: 0u64,
+					period: 10u64,
+					period_count: 2u32,
+					per_period: 10u64,
+					cliff: 15u64,
+				},
+			]
 		);
+		assert_eq!(mock::balances(&BOB::get()), (25, 25));
+		assert!(Vesting::renounced(BOB::get()));
+		assert_eq!(StorageVersion::<Runtime>::get(), Releases::V4);
+
+		let mut details = BridgeDetails::new(9924, b"zklocal".to_vec().try_into().unwrap());
+		details.oracles = bounded_vec![BridgeOracle::get()];
+		assert_eq!(Vesting::bridges(BridgeId(1)), Some(details));
 	});
 }
+
+#[test]
+fn genesis_config_reports_invalid_entries() {
+	let config = |cliffed_vesting, bridges| GenesisConfig::<Runtime> {
+		cliffed_vesting,
+		bridges,
+		..Default::default()
+	};
+
+	assert_eq!(config(vec![], vec![]).validate(), Ok(()));
+	assert_eq!(
+		config(vec![(BOB::get(), vec![(0u64, 0u64, 1u32, 10u64, 0u64)])], vec![]).validate(),
+		Err("a genesis vesting schedule has a zero period")
+	);
+	assert_eq!(
+		config(vec![(BOB::get(), vec![(0u64, 10u64, 0u32, 10u64, 0u64)])], vec![]).validate(),
+		Err("a genesis vesting schedule has a zero period count")
+	);
+	assert_eq!(
+		config(vec![(BOB::get(), vec![(0u64, 10u64, 2u32, u64::MAX, 0u64)])], vec![]).validate(),
+		Err("a genesis vesting schedule overflows the block number or balance type")
+	);
+	assert_eq!(
+		config(
+			vec![(
+				BOB::get(),
+				vec![(0u64, 10u64, 1u32, 1u64, 0u64); MaxSchedule::get() as usize + 1]
+			)],
+			vec![]
+		)
+		.validate(),
+		Err("an account has more genesis vesting schedules than MaxSchedule")
+	);
+	assert_eq!(
+		config(vec![], vec![(1, vec![], 1, vec![]), (1, vec![], 2, vec![])]).validate(),
+		Err("a genesis bridge id is registered more than once")
+	);
+	assert_eq!(
+		config(vec![], vec![(1, vec![0u8; 33], 1, vec![])]).validate(),
+		Err("a genesis bridge name is longer than 32 bytes")
+	);
+	assert_eq!(
+		config(
+			vec![],
+			vec![(1, vec![], 1, vec![ALICE::get(), BOB::get(), CancelOrigin::get()])]
+		)
+		.validate(),
+		Err("a genesis bridge has more oracles than MaxBridgeOracles")
+	);
+}
@@ -16,12 +16,12 @@
  * along with this program.  If not, see <http://www.gnu.org/licenses/>.
  */
 
-//! Autogenerated weights for pallet_grants
+//! Weights for pallet_grants
 //!
-//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 32.0.0
-//! DATE: 2024-02-20, STEPS: `50`, REPEAT: 20, LOW RANGE: `[]`, HIGH RANGE: `[]`
-//! HOSTNAME: `chain-bench-a18ada46`, CPU: `AMD EPYC 7B13`
-//! EXECUTION: , WASM-EXECUTION: Compiled, CHAIN: Some("dev"), DB CACHE: 1024
+//! Generated with the Substrate benchmark CLI version 32.0.0 on 2024-02-20 (STEPS: `50`,
+//! REPEAT: 20, CPU: `AMD EPYC 7B13`). The functions marked `TODO(benchmarks)` were added or
+//! changed since and hold conservative estimates until the file is regenerated with the
+//! command below.
 
 // Executed Command:
 // ./target/release/nodle-parachain
@@ -50,6 +50,19 @@ pub trait WeightInfo {
 	fn set_bridge() -> Weight;
 	fn remove_bridge() -> Weight;
 	fn renounce() -> Weight;
+	fn cancel_vesting_schedule() -> Weight;
+	fn add_revocable_vesting_schedule() -> Weight;
+	fn revoke_vesting_schedule() -> Weight;
+	fn transfer_vesting_schedules() -> Weight;
+	fn batch_add_vesting_schedules(n: u32) -> Weight;
+	fn compact() -> Weight;
+	fn confirm_bridge_transfer() -> Weight;
+	fn refund_bridge_transfer() -> Weight;
+	fn update_bridge() -> Weight;
+	fn bridge_vesting_schedules() -> Weight;
+	fn claim_for() -> Weight;
+	fn add_timed_vesting_schedule() -> Weight;
+	fn cancel_timed_vesting_schedule() -> Weight;
 }
 
 /// Weight functions for `pallet_grants`.
@@ -58,51 +71,54 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	// Storage: `ParachainSystem::ValidationData` (r:1 w:0)
 	// Proof: `ParachainSystem::ValidationData` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
 	// Storage: `Vesting::VestingSchedules` (r:1 w:1)
-	// Proof: `Vesting::VestingSchedules` (`max_values`: None, `max_size`: Some(2850), added: 5325, mode: `MaxEncodedLen`)
+	// Proof: `Vesting::VestingSchedules` (`max_values`: None, `max_size`: Some(3250), added: 5725, mode: `MaxEncodedLen`)
 	// Storage: `System::Account` (r:2 w:2)
 	// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
-	// Storage: `Balances::Locks` (r:1 w:1)
+	// Storage: `Balances::Locks` (r:1 w:0)
 	// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
-	// Storage: `Balances::Freezes` (r:1 w:0)
+	// Storage: `Balances::Freezes` (r:1 w:1)
 	// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
 	fn add_vesting_schedule() -> Weight {
-		// Minimum execution time: 100_400 nanoseconds.
 		Weight::from_parts(103_331_000_u64, 0)
 			.saturating_add(T::DbWeight::get().reads(6_u64))
 			.saturating_add(T::DbWeight::get().writes(4_u64))
 	}
 	// Storage: `ParachainSystem::ValidationData` (r:1 w:0)
 	// Proof: `ParachainSystem::ValidationData` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
-	// Storage: `Vesting::VestingSchedules` (r:1 w:0)
-	// Proof: `Vesting::VestingSchedules` (`max_values`: None, `max_size`: Some(2850), added: 5325, mode: `MaxEncodedLen`)
-	// Storage: `Balances::Locks` (r:1 w:1)
+	// Storage: `Vesting::VestingSchedules` (r:1 w:1)
+	// Proof: `Vesting::VestingSchedules` (`max_values`: None, `max_size`: Some(3250), added: 5725, mode: `MaxEncodedLen`)
+	// Storage: `Balances::Locks` (r:1 w:0)
 	// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
-	// Storage: `Balances::Freezes` (r:1 w:0)
+	// Storage: `Balances::Freezes` (r:1 w:1)
 	// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
 	// Storage: `System::Account` (r:1 w:1)
 	// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	// Storage: `Vesting::TimedVestingSchedules` (r:1 w:0)
+	// Proof: `Vesting::TimedVestingSchedules` (`max_values`: None, `max_size`: Some(4450), added: 6925, mode: `MaxEncodedLen`)
+	// Storage: `Vesting::RevocableSchedules` (r:100 w:99)
+	// Proof: `Vesting::RevocableSchedules` (`max_values`: None, `max_size`: Some(3310), added: 5785, mode: `MaxEncodedLen`)
 	fn claim() -> Weight {
-		// Minimum execution time: 43_860 nanoseconds.
+		// TODO(benchmarks): conservative estimate, not measured yet.
 		Weight::from_parts(45_080_000_u64, 0)
-			.saturating_add(T::DbWeight::get().reads(5_u64))
-			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().reads(106_u64))
+			.saturating_add(T::DbWeight::get().writes(102_u64))
 	}
 	// Storage: `Vesting::Renounced` (r:1 w:0)
 	// Proof: `Vesting::Renounced` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
 	// Storage: `ParachainSystem::ValidationData` (r:1 w:0)
 	// Proof: `ParachainSystem::ValidationData` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
 	// Storage: `Vesting::VestingSchedules` (r:1 w:1)
-	// Proof: `Vesting::VestingSchedules` (`max_values`: None, `max_size`: Some(2850), added: 5325, mode: `MaxEncodedLen`)
-	// Storage: `Balances::Locks` (r:1 w:1)
+	// Proof: `Vesting::VestingSchedules` (`max_values`: None, `max_size`: Some(3250), added: 5725, mode: `MaxEncodedLen`)
+	// Storage: `Balances::Locks` (r:1 w:0)
 	// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
-	// Storage: `Balances::Freezes` (r:1 w:0)
+	// Storage: `Balances::Freezes` (r:1 w:1)
 	// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
 	// Storage: `System::Account` (r:2 w:2)
 	// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	// Storage: `Vesting::CounterForVestingSchedules` (r:1 w:1)
 	// Proof: `Vesting::CounterForVestingSchedules` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	fn cancel_all_vesting_schedules() -> Weight {
-		// Minimum execution time: 133_520 nanoseconds.
+		// TODO(benchmarks): conservative estimate, not measured yet.
 		Weight::from_parts(137_240_000_u64, 0)
 			.saturating_add(T::DbWeight::get().reads(8_u64))
 			.saturating_add(T::DbWeight::get().writes(5_u64))
@@ -110,17 +126,17 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	// Storage: `ParachainSystem::ValidationData` (r:1 w:0)
 	// Proof: `ParachainSystem::ValidationData` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
 	// Storage: `Vesting::VestingSchedules` (r:1 w:1)
-	// Proof: `Vesting::VestingSchedules` (`max_values`: None, `max_size`: Some(2850), added: 5325, mode: `MaxEncodedLen`)
-	// Storage: `Balances::Locks` (r:1 w:1)
+	// Proof: `Vesting::VestingSchedules` (`max_values`: None, `max_size`: Some(3250), added: 5725, mode: `MaxEncodedLen`)
+	// Storage: `Balances::Locks` (r:1 w:0)
 	// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
-	// Storage: `Balances::Freezes` (r:1 w:0)
+	// Storage: `Balances::Freezes` (r:1 w:1)
 	// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
 	// Storage: `System::Account` (r:1 w:1)
 	// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	// Storage: `Vesting::CounterForVestingSchedules` (r:1 w:1)
 	// Proof: `Vesting::CounterForVestingSchedules` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	fn bridge_all_vesting_schedules() -> Weight {
-		// Minimum execution time: 75_000 nanoseconds.
+		// TODO(benchmarks): conservative estimate, not measured yet.
 		Weight::from_parts(78_000_000_u64, 0)
 			.saturating_add(T::DbWeight::get().reads(6_u64))
 			.saturating_add(T::DbWeight::get().writes(4_u64))
@@ -135,11 +151,13 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	}
 	// Storage: `Vesting::Bridges` (r:1 w:1)
 	// Proof: `Vesting::Bridges` (`max_values`: None, `max_size`: Some(61), added: 2536, mode: `MaxEncodedLen`)
+	// Storage: `Vesting::BridgePeriodUsage` (r:0 w:1)
+	// Proof: `Vesting::BridgePeriodUsage` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
 	fn remove_bridge() -> Weight {
-		// Minimum execution time: 8_000 nanoseconds.
+		// TODO(benchmarks): conservative estimate, not measured yet.
 		Weight::from_parts(9_000_000_u64, 0)
 			.saturating_add(T::DbWeight::get().reads(1_u64))
-			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
 	// Storage: `Vesting::Renounced` (r:0 w:1)
 	// Proof: `Vesting::Renounced` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
@@ -147,57 +165,310 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		// Minimum execution time: 9_610 nanoseconds.
 		Weight::from_parts(10_200_000_u64, 0).saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	// Storage: `Vesting::Renounced` (r:1 w:0)
+	// Proof: `Vesting::Renounced` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	// Storage: `ParachainSystem::ValidationData` (r:1 w:0)
+	// Proof: `ParachainSystem::ValidationData` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	// Storage: `Vesting::VestingSchedules` (r:1 w:1)
+	// Proof: `Vesting::VestingSchedules` (`max_values`: None, `max_size`: Some(3250), added: 5725, mode: `MaxEncodedLen`)
+	// Storage: `Balances::Locks` (r:1 w:0)
+	// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	// Storage: `Balances::Freezes` (r:1 w:1)
+	// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	// Storage: `System::Account` (r:2 w:2)
+	// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn cancel_vesting_schedule() -> Weight {
+		// TODO(benchmarks): conservative estimate, not measured yet.
+		Weight::from_parts(121_870_000_u64, 0)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	// Storage: `ParachainSystem::ValidationData` (r:1 w:0)
+	// Proof: `ParachainSystem::ValidationData` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	// Storage: `Vesting::VestingSchedules` (r:1 w:1)
+	// Proof: `Vesting::VestingSchedules` (`max_values`: None, `max_size`: Some(3250), added: 5725, mode: `MaxEncodedLen`)
+	// Storage: `System::Account` (r:2 w:2)
+	// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	// Storage: `Balances::Locks` (r:1 w:0)
+	// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	// Storage: `Balances::Freezes` (r:1 w:1)
+	// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	// Storage: `Vesting::RevocableSchedules` (r:1 w:1)
+	// Proof: `Vesting::RevocableSchedules` (`max_values`: None, `max_size`: Some(3310), added: 5785, mode: `MaxEncodedLen`)
+	fn add_revocable_vesting_schedule() -> Weight {
+		// TODO(benchmarks): conservative estimate, not measured yet.
+		Weight::from_parts(112_560_000_u64, 0)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	// Storage: `ParachainSystem::ValidationData` (r:1 w:0)
+	// Proof: `ParachainSystem::ValidationData` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	// Storage: `Vesting::VestingSchedules` (r:1 w:1)
+	// Proof: `Vesting::VestingSchedules` (`max_values`: None, `max_size`: Some(3250), added: 5725, mode: `MaxEncodedLen`)
+	// Storage: `Vesting::RevocableSchedules` (r:1 w:1)
+	// Proof: `Vesting::RevocableSchedules` (`max_values`: None, `max_size`: Some(3310), added: 5785, mode: `MaxEncodedLen`)
+	// Storage: `Balances::Locks` (r:1 w:0)
+	// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	// Storage: `Balances::Freezes` (r:1 w:1)
+	// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	// Storage: `System::Account` (r:2 w:2)
+	// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn revoke_vesting_schedule() -> Weight {
+		// TODO(benchmarks): conservative estimate, not measured yet.
+		Weight::from_parts(126_410_000_u64, 0)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	// Storage: `Vesting::Renounced` (r:2 w:0)
+	// Proof: `Vesting::Renounced` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	// Storage: `ParachainSystem::ValidationData` (r:1 w:0)
+	// Proof: `ParachainSystem::ValidationData` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	// Storage: `Vesting::VestingSchedules` (r:2 w:2)
+	// Proof: `Vesting::VestingSchedules` (`max_values`: None, `max_size`: Some(3250), added: 5725, mode: `MaxEncodedLen`)
+	// Storage: `Vesting::RevocableSchedules` (r:2 w:2)
+	// Proof: `Vesting::RevocableSchedules` (`max_values`: None, `max_size`: Some(3310), added: 5785, mode: `MaxEncodedLen`)
+	// Storage: `Balances::Locks` (r:2 w:0)
+	// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	// Storage: `Balances::Freezes` (r:2 w:2)
+	// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	// Storage: `System::Account` (r:2 w:2)
+	// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	// Storage: `Vesting::TimedVestingSchedules` (r:2 w:2)
+	// Proof: `Vesting::TimedVestingSchedules` (`max_values`: None, `max_size`: Some(4450), added: 6925, mode: `MaxEncodedLen`)
+	// Storage: `Timestamp::Now` (r:1 w:0)
+	// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	fn transfer_vesting_schedules() -> Weight {
+		// TODO(benchmarks): conservative estimate, not measured yet.
+		Weight::from_parts(236_180_000_u64, 0)
+			.saturating_add(T::DbWeight::get().reads(15_u64))
+			.saturating_add(T::DbWeight::get().writes(10_u64))
+	}
+	// Storage: `ParachainSystem::ValidationData` (r:1 w:0)
+	// Proof: `ParachainSystem::ValidationData` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	// Storage: `Vesting::VestingSchedules` (r:100 w:100)
+	// Proof: `Vesting::VestingSchedules` (`max_values`: None, `max_size`: Some(3250), added: 5725, mode: `MaxEncodedLen`)
+	// Storage: `System::Account` (r:101 w:101)
+	// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	// Storage: `Balances::Locks` (r:100 w:0)
+	// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	// Storage: `Balances::Freezes` (r:100 w:100)
+	// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	// Storage: `Vesting::CounterForVestingSchedules` (r:1 w:1)
+	// Proof: `Vesting::CounterForVestingSchedules` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	fn batch_add_vesting_schedules(n: u32) -> Weight {
+		// TODO(benchmarks): conservative estimate, not measured yet.
+		Weight::from_parts(24_310_000_u64, 0)
+			.saturating_add(Weight::from_parts(98_612_000_u64, 0).saturating_mul(n as u64))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().reads((5_u64).saturating_mul(n as u64)))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes((4_u64).saturating_mul(n as u64)))
+	}
+	// Storage: `ParachainSystem::ValidationData` (r:1 w:0)
+	// Proof: `ParachainSystem::ValidationData` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	// Storage: `Vesting::VestingSchedules` (r:1 w:1)
+	// Proof: `Vesting::VestingSchedules` (`max_values`: None, `max_size`: Some(3250), added: 5725, mode: `MaxEncodedLen`)
+	// Storage: `Vesting::RevocableSchedules` (r:1 w:0)
+	// Proof: `Vesting::RevocableSchedules` (`max_values`: None, `max_size`: Some(3310), added: 5785, mode: `MaxEncodedLen`)
+	// Storage: `Balances::Locks` (r:1 w:0)
+	// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	// Storage: `Balances::Freezes` (r:1 w:1)
+	// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	// Storage: `System::Account` (r:1 w:1)
+	// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn compact() -> Weight {
+		// TODO(benchmarks): conservative estimate, not measured yet.
+		Weight::from_parts(92_350_000_u64, 0)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	// Storage: `Vesting::Bridges` (r:1 w:0)
+	// Proof: `Vesting::Bridges` (`max_values`: None, `max_size`: Some(1138), added: 3613, mode: `MaxEncodedLen`)
+	// Storage: `Vesting::PendingBridgeTransfers` (r:1 w:1)
+	// Proof: `Vesting::PendingBridgeTransfers` (`max_values`: None, `max_size`: Some(2946), added: 5421, mode: `MaxEncodedLen`)
+	// Storage: `Vesting::ReservedScheduleSlots` (r:1 w:1)
+	// Proof: `Vesting::ReservedScheduleSlots` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	fn confirm_bridge_transfer() -> Weight {
+		// TODO(benchmarks): conservative estimate, not measured yet.
+		Weight::from_parts(14_820_000_u64, 0)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	// Storage: `Vesting::PendingBridgeTransfers` (r:1 w:1)
+	// Proof: `Vesting::PendingBridgeTransfers` (`max_values`: None, `max_size`: Some(2946), added: 5421, mode: `MaxEncodedLen`)
+	// Storage: `Vesting::VestingSchedules` (r:1 w:1)
+	// Proof: `Vesting::VestingSchedules` (`max_values`: None, `max_size`: Some(3250), added: 5725, mode: `MaxEncodedLen`)
+	// Storage: `ParachainSystem::ValidationData` (r:1 w:0)
+	// Proof: `ParachainSystem::ValidationData` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	// Storage: `Balances::Locks` (r:1 w:0)
+	// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	// Storage: `Balances::Freezes` (r:1 w:1)
+	// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	// Storage: `System::Account` (r:1 w:1)
+	// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	// Storage: `Vesting::ReservedScheduleSlots` (r:1 w:1)
+	// Proof: `Vesting::ReservedScheduleSlots` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	// Storage: `Vesting::Bridges` (r:1 w:0)
+	// Proof: `Vesting::Bridges` (`max_values`: None, `max_size`: Some(1138), added: 3613, mode: `MaxEncodedLen`)
+	// Storage: `Vesting::BridgePeriodUsage` (r:1 w:1)
+	// Proof: `Vesting::BridgePeriodUsage` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	// Storage: `Vesting::RevocableSchedules` (r:1 w:1)
+	// Proof: `Vesting::RevocableSchedules` (`max_values`: None, `max_size`: Some(3310), added: 5785, mode: `MaxEncodedLen`)
+	fn refund_bridge_transfer() -> Weight {
+		// TODO(benchmarks): conservative estimate, not measured yet.
+		Weight::from_parts(81_530_000_u64, 0)
+			.saturating_add(T::DbWeight::get().reads(10_u64))
+			.saturating_add(T::DbWeight::get().writes(8_u64))
+	}
+	// Storage: `Vesting::Bridges` (r:1 w:1)
+	// Proof: `Vesting::Bridges` (`max_values`: None, `max_size`: Some(1138), added: 3613, mode: `MaxEncodedLen`)
+	// Storage: `Vesting::BridgePeriodUsage` (r:0 w:1)
+	// Proof: `Vesting::BridgePeriodUsage` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	fn update_bridge() -> Weight {
+		// TODO(benchmarks): conservative estimate, not measured yet.
+		Weight::from_parts(16_450_000_u64, 0)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	// Storage: `Vesting::Bridges` (r:1 w:0)
+	// Proof: `Vesting::Bridges` (`max_values`: None, `max_size`: Some(1138), added: 3613, mode: `MaxEncodedLen`)
+	// Storage: `ParachainSystem::ValidationData` (r:1 w:0)
+	// Proof: `ParachainSystem::ValidationData` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	// Storage: `Vesting::VestingSchedules` (r:1 w:1)
+	// Proof: `Vesting::VestingSchedules` (`max_values`: None, `max_size`: Some(3250), added: 5725, mode: `MaxEncodedLen`)
+	// Storage: `Vesting::RevocableSchedules` (r:1 w:0)
+	// Proof: `Vesting::RevocableSchedules` (`max_values`: None, `max_size`: Some(3310), added: 5785, mode: `MaxEncodedLen`)
+	// Storage: `Balances::Locks` (r:1 w:0)
+	// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	// Storage: `Balances::Freezes` (r:1 w:1)
+	// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	// Storage: `System::Account` (r:1 w:1)
+	// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	// Storage: `Vesting::BridgeNonces` (r:1 w:1)
+	// Proof: `Vesting::BridgeNonces` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	// Storage: `Vesting::PendingBridgeTransfers` (r:0 w:1)
+	// Proof: `Vesting::PendingBridgeTransfers` (`max_values`: None, `max_size`: Some(2946), added: 5421, mode: `MaxEncodedLen`)
+	fn bridge_vesting_schedules() -> Weight {
+		// TODO(benchmarks): conservative estimate, not measured yet.
+		Weight::from_parts(96_410_000_u64, 0)
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
+	// Storage: `ParachainSystem::ValidationData` (r:1 w:0)
+	// Proof: `ParachainSystem::ValidationData` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	// Storage: `Vesting::VestingSchedules` (r:1 w:1)
+	// Proof: `Vesting::VestingSchedules` (`max_values`: None, `max_size`: Some(3250), added: 5725, mode: `MaxEncodedLen`)
+	// Storage: `Balances::Locks` (r:1 w:0)
+	// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	// Storage: `Balances::Freezes` (r:1 w:1)
+	// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	// Storage: `System::Account` (r:1 w:1)
+	// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	// Storage: `Vesting::TimedVestingSchedules` (r:1 w:0)
+	// Proof: `Vesting::TimedVestingSchedules` (`max_values`: None, `max_size`: Some(4450), added: 6925, mode: `MaxEncodedLen`)
+	// Storage: `Vesting::RevocableSchedules` (r:100 w:99)
+	// Proof: `Vesting::RevocableSchedules` (`max_values`: None, `max_size`: Some(3310), added: 5785, mode: `MaxEncodedLen`)
+	fn claim_for() -> Weight {
+		// TODO(benchmarks): conservative estimate, not measured yet.
+		Weight::from_parts(46_920_000_u64, 0)
+			.saturating_add(T::DbWeight::get().reads(106_u64))
+			.saturating_add(T::DbWeight::get().writes(102_u64))
+	}
+	// Storage: `ParachainSystem::ValidationData` (r:1 w:0)
+	// Proof: `ParachainSystem::ValidationData` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	// Storage: `Vesting::VestingSchedules` (r:1 w:0)
+	// Proof: `Vesting::VestingSchedules` (`max_values`: None, `max_size`: Some(3250), added: 5725, mode: `MaxEncodedLen`)
+	// Storage: `Vesting::TimedVestingSchedules` (r:1 w:1)
+	// Proof: `Vesting::TimedVestingSchedules` (`max_values`: None, `max_size`: Some(4450), added: 6925, mode: `MaxEncodedLen`)
+	// Storage: `Timestamp::Now` (r:1 w:0)
+	// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	// Storage: `System::Account` (r:2 w:2)
+	// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	// Storage: `Balances::Locks` (r:1 w:0)
+	// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	// Storage: `Balances::Freezes` (r:1 w:1)
+	// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	fn add_timed_vesting_schedule() -> Weight {
+		// TODO(benchmarks): conservative estimate, not measured yet.
+		Weight::from_parts(108_270_000_u64, 0)
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	// Storage: `Vesting::Renounced` (r:1 w:0)
+	// Proof: `Vesting::Renounced` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	// Storage: `Vesting::TimedVestingSchedules` (r:1 w:1)
+	// Proof: `Vesting::TimedVestingSchedules` (`max_values`: None, `max_size`: Some(4450), added: 6925, mode: `MaxEncodedLen`)
+	// Storage: `Timestamp::Now` (r:1 w:0)
+	// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	// Storage: `ParachainSystem::ValidationData` (r:1 w:0)
+	// Proof: `ParachainSystem::ValidationData` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	// Storage: `Vesting::VestingSchedules` (r:1 w:0)
+	// Proof: `Vesting::VestingSchedules` (`max_values`: None, `max_size`: Some(3250), added: 5725, mode: `MaxEncodedLen`)
+	// Storage: `Balances::Freezes` (r:1 w:1)
+	// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	// Storage: `System::Account` (r:2 w:2)
+	// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn cancel_timed_vesting_schedule() -> Weight {
+		// TODO(benchmarks): conservative estimate, not measured yet.
+		Weight::from_parts(121_870_000_u64, 0)
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
 }
 
 impl WeightInfo for () {
 	// Storage: `ParachainSystem::ValidationData` (r:1 w:0)
 	// Proof: `ParachainSystem::ValidationData` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
 	// Storage: `Vesting::VestingSchedules` (r:1 w:1)
-	// Proof: `Vesting::VestingSchedules` (`max_values`: None, `max_size`: Some(2850), added: 5325, mode: `MaxEncodedLen`)
+	// Proof: `Vesting::VestingSchedules` (`max_values`: None, `max_size`: Some(3250), added: 5725, mode: `MaxEncodedLen`)
 	// Storage: `System::Account` (r:2 w:2)
 	// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
-	// Storage: `Balances::Locks` (r:1 w:1)
+	// Storage: `Balances::Locks` (r:1 w:0)
 	// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
-	// Storage: `Balances::Freezes` (r:1 w:0)
+	// Storage: `Balances::Freezes` (r:1 w:1)
 	// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
 	fn add_vesting_schedule() -> Weight {
-		// Minimum execution time: 100_400 nanoseconds.
+		// TODO(benchmarks): conservative estimate, not measured yet.
 		Weight::from_parts(103_331_000_u64, 0)
 			.saturating_add(RocksDbWeight::get().reads(6_u64))
 			.saturating_add(RocksDbWeight::get().writes(4_u64))
 	}
 	// Storage: `ParachainSystem::ValidationData` (r:1 w:0)
 	// Proof: `ParachainSystem::ValidationData` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
-	// Storage: `Vesting::VestingSchedules` (r:1 w:0)
-	// Proof: `Vesting::VestingSchedules` (`max_values`: None, `max_size`: Some(2850), added: 5325, mode: `MaxEncodedLen`)
-	// Storage: `Balances::Locks` (r:1 w:1)
+	// Storage: `Vesting::VestingSchedules` (r:1 w:1)
+	// Proof: `Vesting::VestingSchedules` (`max_values`: None, `max_size`: Some(3250), added: 5725, mode: `MaxEncodedLen`)
+	// Storage: `Balances::Locks` (r:1 w:0)
 	// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
-	// Storage: `Balances::Freezes` (r:1 w:0)
+	// Storage: `Balances::Freezes` (r:1 w:1)
 	// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
 	// Storage: `System::Account` (r:1 w:1)
 	// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	// Storage: `Vesting::TimedVestingSchedules` (r:1 w:0)
+	// Proof: `Vesting::TimedVestingSchedules` (`max_values`: None, `max_size`: Some(4450), added: 6925, mode: `MaxEncodedLen`)
+	// Storage: `Vesting::RevocableSchedules` (r:100 w:99)
+	// Proof: `Vesting::RevocableSchedules` (`max_values`: None, `max_size`: Some(3310), added: 5785, mode: `MaxEncodedLen`)
 	fn claim() -> Weight {
-		// Minimum execution time: 43_860 nanoseconds.
+		// TODO(benchmarks): conservative estimate, not measured yet.
 		Weight::from_parts(45_080_000_u64, 0)
-			.saturating_add(RocksDbWeight::get().reads(5_u64))
-			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(RocksDbWeight::get().reads(106_u64))
+			.saturating_add(RocksDbWeight::get().writes(102_u64))
 	}
 	// Storage: `Vesting::Renounced` (r:1 w:0)
 	// Proof: `Vesting::Renounced` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
 	// Storage: `ParachainSystem::ValidationData` (r:1 w:0)
 	// Proof: `ParachainSystem::ValidationData` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
 	// Storage: `Vesting::VestingSchedules` (r:1 w:1)
-	// Proof: `Vesting::VestingSchedules` (`max_values`: None, `max_size`: Some(2850), added: 5325, mode: `MaxEncodedLen`)
-	// Storage: `Balances::Locks` (r:1 w:1)
+	// Proof: `Vesting::VestingSchedules` (`max_values`: None, `max_size`: Some(3250), added: 5725, mode: `MaxEncodedLen`)
+	// Storage: `Balances::Locks` (r:1 w:0)
 	// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
-	// Storage: `Balances::Freezes` (r:1 w:0)
+	// Storage: `Balances::Freezes` (r:1 w:1)
 	// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
 	// Storage: `System::Account` (r:2 w:2)
 	// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	// Storage: `Vesting::CounterForVestingSchedules` (r:1 w:1)
 	// Proof: `Vesting::CounterForVestingSchedules` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	fn cancel_all_vesting_schedules() -> Weight {
-		// Minimum execution time: 133_520 nanoseconds.
+		// TODO(benchmarks): conservative estimate, not measured yet.
 		Weight::from_parts(137_240_000_u64, 0)
 			.saturating_add(RocksDbWeight::get().reads(8_u64))
 			.saturating_add(RocksDbWeight::get().writes(5_u64))
@@ -205,17 +476,17 @@ impl WeightInfo for () {
 	// Storage: `ParachainSystem::ValidationData` (r:1 w:0)
 	// Proof: `ParachainSystem::ValidationData` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
 	// Storage: `Vesting::VestingSchedules` (r:1 w:1)
-	// Proof: `Vesting::VestingSchedules` (`max_values`: None, `max_size`: Some(2850), added: 5325, mode: `MaxEncodedLen`)
-	// Storage: `Balances::Locks` (r:1 w:1)
+	// Proof: `Vesting::VestingSchedules` (`max_values`: None, `max_size`: Some(3250), added: 5725, mode: `MaxEncodedLen`)
+	// Storage: `Balances::Locks` (r:1 w:0)
 	// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
-	// Storage: `Balances::Freezes` (r:1 w:0)
+	// Storage: `Balances::Freezes` (r:1 w:1)
 	// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
 	// Storage: `System::Account` (r:1 w:1)
 	// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	// Storage: `Vesting::CounterForVestingSchedules` (r:1 w:1)
 	// Proof: `Vesting::CounterForVestingSchedules` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	fn bridge_all_vesting_schedules() -> Weight {
-		// Minimum execution time: 75_000 nanoseconds.
+		// TODO(benchmarks): conservative estimate, not measured yet.
 		Weight::from_parts(78_000_000_u64, 0)
 			.saturating_add(RocksDbWeight::get().reads(6_u64))
 			.saturating_add(RocksDbWeight::get().writes(4_u64))
@@ -230,11 +501,13 @@ impl WeightInfo for () {
 	}
 	// Storage: `Vesting::Bridges` (r:1 w:1)
 	// Proof: `Vesting::Bridges` (`max_values`: None, `max_size`: Some(61), added: 2536, mode: `MaxEncodedLen`)
+	// Storage: `Vesting::BridgePeriodUsage` (r:0 w:1)
+	// Proof: `Vesting::BridgePeriodUsage` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
 	fn remove_bridge() -> Weight {
-		// Minimum execution time: 8_000 nanoseconds.
+		// TODO(benchmarks): conservative estimate, not measured yet.
 		Weight::from_parts(9_000_000_u64, 0)
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
 	// Storage: `Vesting::Renounced` (r:0 w:1)
 	// Proof: `Vesting::Renounced` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
@@ -242,4 +515,253 @@ impl WeightInfo for () {
 		// Minimum execution time: 9_610 nanoseconds.
 		Weight::from_parts(10_200_000_u64, 0).saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	// Storage: `Vesting::Renounced` (r:1 w:0)
+	// Proof: `Vesting::Renounced` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	// Storage: `ParachainSystem::ValidationData` (r:1 w:0)
+	// Proof: `ParachainSystem::ValidationData` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	// Storage: `Vesting::VestingSchedules` (r:1 w:1)
+	// Proof: `Vesting::VestingSchedules` (`max_values`: None, `max_size`: Some(3250), added: 5725, mode: `MaxEncodedLen`)
+	// Storage: `Balances::Locks` (r:1 w:0)
+	// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	// Storage: `Balances::Freezes` (r:1 w:1)
+	// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	// Storage: `System::Account` (r:2 w:2)
+	// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn cancel_vesting_schedule() -> Weight {
+		// TODO(benchmarks): conservative estimate, not measured yet.
+		Weight::from_parts(121_870_000_u64, 0)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	// Storage: `ParachainSystem::ValidationData` (r:1 w:0)
+	// Proof: `ParachainSystem::ValidationData` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	// Storage: `Vesting::VestingSchedules` (r:1 w:1)
+	// Proof: `Vesting::VestingSchedules` (`max_values`: None, `max_size`: Some(3250), added: 5725, mode: `MaxEncodedLen`)
+	// Storage: `System::Account` (r:2 w:2)
+	// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	// Storage: `Balances::Locks` (r:1 w:0)
+	// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	// Storage: `Balances::Freezes` (r:1 w:1)
+	// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	// Storage: `Vesting::RevocableSchedules` (r:1 w:1)
+	// Proof: `Vesting::RevocableSchedules` (`max_values`: None, `max_size`: Some(3310), added: 5785, mode: `MaxEncodedLen`)
+	fn add_revocable_vesting_schedule() -> Weight {
+		// TODO(benchmarks): conservative estimate, not measured yet.
+		Weight::from_parts(112_560_000_u64, 0)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	// Storage: `ParachainSystem::ValidationData` (r:1 w:0)
+	// Proof: `ParachainSystem::ValidationData` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	// Storage: `Vesting::VestingSchedules` (r:1 w:1)
+	// Proof: `Vesting::VestingSchedules` (`max_values`: None, `max_size`: Some(3250), added: 5725, mode: `MaxEncodedLen`)
+	// Storage: `Vesting::RevocableSchedules` (r:1 w:1)
+	// Proof: `Vesting::RevocableSchedules` (`max_values`: None, `max_size`: Some(3310), added: 5785, mode: `MaxEncodedLen`)
+	// Storage: `Balances::Locks` (r:1 w:0)
+	// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	// Storage: `Balances::Freezes` (r:1 w:1)
+	// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	// Storage: `System::Account` (r:2 w:2)
+	// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn revoke_vesting_schedule() -> Weight {
+		// TODO(benchmarks): conservative estimate, not measured yet.
+		Weight::from_parts(126_410_000_u64, 0)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	// Storage: `Vesting::Renounced` (r:2 w:0)
+	// Proof: `Vesting::Renounced` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	// Storage: `ParachainSystem::ValidationData` (r:1 w:0)
+	// Proof: `ParachainSystem::ValidationData` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	// Storage: `Vesting::VestingSchedules` (r:2 w:2)
+	// Proof: `Vesting::VestingSchedules` (`max_values`: None, `max_size`: Some(3250), added: 5725, mode: `MaxEncodedLen`)
+	// Storage: `Vesting::RevocableSchedules` (r:2 w:2)
+	// Proof: `Vesting::RevocableSchedules` (`max_values`: None, `max_size`: Some(3310), added: 5785, mode: `MaxEncodedLen`)
+	// Storage: `Balances::Locks` (r:2 w:0)
+	// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	// Storage: `Balances::Freezes` (r:2 w:2)
+	// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	// Storage: `System::Account` (r:2 w:2)
+	// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	// Storage: `Vesting::TimedVestingSchedules` (r:2 w:2)
+	// Proof: `Vesting::TimedVestingSchedules` (`max_values`: None, `max_size`: Some(4450), added: 6925, mode: `MaxEncodedLen`)
+	// Storage: `Timestamp::Now` (r:1 w:0)
+	// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	fn transfer_vesting_schedules() -> Weight {
+		// TODO(benchmarks): conservative estimate, not measured yet.
+		Weight::from_parts(236_180_000_u64, 0)
+			.saturating_add(RocksDbWeight::get().reads(15_u64))
+			.saturating_add(RocksDbWeight::get().writes(10_u64))
+	}
+	// Storage: `ParachainSystem::ValidationData` (r:1 w:0)
+	// Proof: `ParachainSystem::ValidationData` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	// Storage: `Vesting::VestingSchedules` (r:100 w:100)
+	// Proof: `Vesting::VestingSchedules` (`max_values`: None, `max_size`: Some(3250), added: 5725, mode: `MaxEncodedLen`)
+	// Storage: `System::Account` (r:101 w:101)
+	// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	// Storage: `Balances::Locks` (r:100 w:0)
+	// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	// Storage: `Balances::Freezes` (r:100 w:100)
+	// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	// Storage: `Vesting::CounterForVestingSchedules` (r:1 w:1)
+	// Proof: `Vesting::CounterForVestingSchedules` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	fn batch_add_vesting_schedules(n: u32) -> Weight {
+		// TODO(benchmarks): conservative estimate, not measured yet.
+		Weight::from_parts(24_310_000_u64, 0)
+			.saturating_add(Weight::from_parts(98_612_000_u64, 0).saturating_mul(n as u64))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().reads((5_u64).saturating_mul(n as u64)))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().writes((4_u64).saturating_mul(n as u64)))
+	}
+	// Storage: `ParachainSystem::ValidationData` (r:1 w:0)
+	// Proof: `ParachainSystem::ValidationData` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	// Storage: `Vesting::VestingSchedules` (r:1 w:1)
+	// Proof: `Vesting::VestingSchedules` (`max_values`: None, `max_size`: Some(3250), added: 5725, mode: `MaxEncodedLen`)
+	// Storage: `Vesting::RevocableSchedules` (r:1 w:0)
+	// Proof: `Vesting::RevocableSchedules` (`max_values`: None, `max_size`: Some(3310), added: 5785, mode: `MaxEncodedLen`)
+	// Storage: `Balances::Locks` (r:1 w:0)
+	// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	// Storage: `Balances::Freezes` (r:1 w:1)
+	// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	// Storage: `System::Account` (r:1 w:1)
+	// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn compact() -> Weight {
+		// TODO(benchmarks): conservative estimate, not measured yet.
+		Weight::from_parts(92_350_000_u64, 0)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	// Storage: `Vesting::Bridges` (r:1 w:0)
+	// Proof: `Vesting::Bridges` (`max_values`: None, `max_size`: Some(1138), added: 3613, mode: `MaxEncodedLen`)
+	// Storage: `Vesting::PendingBridgeTransfers` (r:1 w:1)
+	// Proof: `Vesting::PendingBridgeTransfers` (`max_values`: None, `max_size`: Some(2946), added: 5421, mode: `MaxEncodedLen`)
+	// Storage: `Vesting::ReservedScheduleSlots` (r:1 w:1)
+	// Proof: `Vesting::ReservedScheduleSlots` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	fn confirm_bridge_transfer() -> Weight {
+		// TODO(benchmarks): conservative estimate, not measured yet.
+		Weight::from_parts(14_820_000_u64, 0)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	// Storage: `Vesting::PendingBridgeTransfers` (r:1 w:1)
+	// Proof: `Vesting::PendingBridgeTransfers` (`max_values`: None, `max_size`: Some(2946), added: 5421, mode: `MaxEncodedLen`)
+	// Storage: `Vesting::VestingSchedules` (r:1 w:1)
+	// Proof: `Vesting::VestingSchedules` (`max_values`: None, `max_size`: Some(3250), added: 5725, mode: `MaxEncodedLen`)
+	// Storage: `ParachainSystem::ValidationData` (r:1 w:0)
+	// Proof: `ParachainSystem::ValidationData` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	// Storage: `Balances::Locks` (r:1 w:0)
+	// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	// Storage: `Balances::Freezes` (r:1 w:1)
+	// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	// Storage: `System::Account` (r:1 w:1)
+	// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	// Storage: `Vesting::ReservedScheduleSlots` (r:1 w:1)
+	// Proof: `Vesting::ReservedScheduleSlots` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	// Storage: `Vesting::Bridges` (r:1 w:0)
+	// Proof: `Vesting::Bridges` (`max_values`: None, `max_size`: Some(1138), added: 3613, mode: `MaxEncodedLen`)
+	// Storage: `Vesting::BridgePeriodUsage` (r:1 w:1)
+	// Proof: `Vesting::BridgePeriodUsage` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	// Storage: `Vesting::RevocableSchedules` (r:1 w:1)
+	// Proof: `Vesting::RevocableSchedules` (`max_values`: None, `max_size`: Some(3310), added: 5785, mode: `MaxEncodedLen`)
+	fn refund_bridge_transfer() -> Weight {
+		// TODO(benchmarks): conservative estimate, not measured yet.
+		Weight::from_parts(81_530_000_u64, 0)
+			.saturating_add(RocksDbWeight::get().reads(10_u64))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
+	}
+	// Storage: `Vesting::Bridges` (r:1 w:1)
+	// Proof: `Vesting::Bridges` (`max_values`: None, `max_size`: Some(1138), added: 3613, mode: `MaxEncodedLen`)
+	// Storage: `Vesting::BridgePeriodUsage` (r:0 w:1)
+	// Proof: `Vesting::BridgePeriodUsage` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	fn update_bridge() -> Weight {
+		// TODO(benchmarks): conservative estimate, not measured yet.
+		Weight::from_parts(16_450_000_u64, 0)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	// Storage: `Vesting::Bridges` (r:1 w:0)
+	// Proof: `Vesting::Bridges` (`max_values`: None, `max_size`: Some(1138), added: 3613, mode: `MaxEncodedLen`)
+	// Storage: `ParachainSystem::ValidationData` (r:1 w:0)
+	// Proof: `ParachainSystem::ValidationData` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	// Storage: `Vesting::VestingSchedules` (r:1 w:1)
+	// Proof: `Vesting::VestingSchedules` (`max_values`: None, `max_size`: Some(3250), added: 5725, mode: `MaxEncodedLen`)
+	// Storage: `Vesting::RevocableSchedules` (r:1 w:0)
+	// Proof: `Vesting::RevocableSchedules` (`max_values`: None, `max_size`: Some(3310), added: 5785, mode: `MaxEncodedLen`)
+	// Storage: `Balances::Locks` (r:1 w:0)
+	// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	// Storage: `Balances::Freezes` (r:1 w:1)
+	// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	// Storage: `System::Account` (r:1 w:1)
+	// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	// Storage: `Vesting::BridgeNonces` (r:1 w:1)
+	// Proof: `Vesting::BridgeNonces` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	// Storage: `Vesting::PendingBridgeTransfers` (r:0 w:1)
+	// Proof: `Vesting::PendingBridgeTransfers` (`max_values`: None, `max_size`: Some(2946), added: 5421, mode: `MaxEncodedLen`)
+	fn bridge_vesting_schedules() -> Weight {
+		// TODO(benchmarks): conservative estimate, not measured yet.
+		Weight::from_parts(96_410_000_u64, 0)
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+	// Storage: `ParachainSystem::ValidationData` (r:1 w:0)
+	// Proof: `ParachainSystem::ValidationData` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	// Storage: `Vesting::VestingSchedules` (r:1 w:1)
+	// Proof: `Vesting::VestingSchedules` (`max_values`: None, `max_size`: Some(3250), added: 5725, mode: `MaxEncodedLen`)
+	// Storage: `Balances::Locks` (r:1 w:0)
+	// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	// Storage: `Balances::Freezes` (r:1 w:1)
+	// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	// Storage: `System::Account` (r:1 w:1)
+	// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	// Storage: `Vesting::TimedVestingSchedules` (r:1 w:0)
+	// Proof: `Vesting::TimedVestingSchedules` (`max_values`: None, `max_size`: Some(4450), added: 6925, mode: `MaxEncodedLen`)
+	// Storage: `Vesting::RevocableSchedules` (r:100 w:99)
+	// Proof: `Vesting::RevocableSchedules` (`max_values`: None, `max_size`: Some(3310), added: 5785, mode: `MaxEncodedLen`)
+	fn claim_for() -> Weight {
+		// TODO(benchmarks): conservative estimate, not measured yet.
+		Weight::from_parts(46_920_000_u64, 0)
+			.saturating_add(RocksDbWeight::get().reads(106_u64))
+			.saturating_add(RocksDbWeight::get().writes(102_u64))
+	}
+	// Storage: `ParachainSystem::ValidationData` (r:1 w:0)
+	// Proof: `ParachainSystem::ValidationData` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	// Storage: `Vesting::VestingSchedules` (r:1 w:0)
+	// Proof: `Vesting::VestingSchedules` (`max_values`: None, `max_size`: Some(3250), added: 5725, mode: `MaxEncodedLen`)
+	// Storage: `Vesting::TimedVestingSchedules` (r:1 w:1)
+	// Proof: `Vesting::TimedVestingSchedules` (`max_values`: None, `max_size`: Some(4450), added: 6925, mode: `MaxEncodedLen`)
+	// Storage: `Timestamp::Now` (r:1 w:0)
+	// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	// Storage: `System::Account` (r:2 w:2)
+	// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	// Storage: `Balances::Locks` (r:1 w:0)
+	// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	// Storage: `Balances::Freezes` (r:1 w:1)
+	// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	fn add_timed_vesting_schedule() -> Weight {
+		// TODO(benchmarks): conservative estimate, not measured yet.
+		Weight::from_parts(108_270_000_u64, 0)
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	// Storage: `Vesting::Renounced` (r:1 w:0)
+	// Proof: `Vesting::Renounced` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	// Storage: `Vesting::TimedVestingSchedules` (r:1 w:1)
+	// Proof: `Vesting::TimedVestingSchedules` (`max_values`: None, `max_size`: Some(4450), added: 6925, mode: `MaxEncodedLen`)
+	// Storage: `Timestamp::Now` (r:1 w:0)
+	// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	// Storage: `ParachainSystem::ValidationData` (r:1 w:0)
+	// Proof: `ParachainSystem::ValidationData` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	// Storage: `Vesting::VestingSchedules` (r:1 w:0)
+	// Proof: `Vesting::VestingSchedules` (`max_values`: None, `max_size`: Some(3250), added: 5725, mode: `MaxEncodedLen`)
+	// Storage: `Balances::Freezes` (r:1 w:1)
+	// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	// Storage: `System::Account` (r:2 w:2)
+	// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn cancel_timed_vesting_schedule() -> Weight {
+		// TODO(benchmarks): conservative estimate, not measured yet.
+		Weight::from_parts(121_870_000_u64, 0)
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
 }
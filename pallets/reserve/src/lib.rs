@@ -32,7 +32,10 @@ use frame_support::{
 };
 
 use frame_system::pallet_prelude::*;
-use sp_runtime::traits::{AccountIdConversion, Dispatchable};
+use sp_runtime::{
+	traits::{AccountIdConversion, CheckedDiv, CheckedSub, Dispatchable, Saturating, Zero},
+	DispatchError, DispatchResult,
+};
 use sp_std::prelude::Box;
 use support::WithAccountId;
 
@@ -55,11 +58,19 @@ pub mod pallet {
 	pub trait Config<I: 'static = ()>: frame_system::Config {
 		type RuntimeEvent: From<Event<Self, I>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
 		type ExternalOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+		/// The origin allowed to spend beyond the budget of `ExternalOrigin`, besides root.
+		type LargeSpendOrigin: EnsureOrigin<Self::RuntimeOrigin>;
 		type Currency: Currency<Self::AccountId>;
 		type RuntimeCall: Parameter
 			+ Dispatchable<RuntimeOrigin = Self::RuntimeOrigin, PostInfo = PostDispatchInfo>
 			+ GetDispatchInfo;
 		type PalletId: Get<PalletId>;
+		/// How much `ExternalOrigin` can spend from the reserve over every `BudgetPeriod`.
+		#[pallet::constant]
+		type SpendBudget: Get<BalanceOf<Self, I>>;
+		/// The length in blocks of the periods the spending budget is renewed for.
+		#[pallet::constant]
+		type BudgetPeriod: Get<BlockNumberFor<Self>>;
 		/// Weight information for extrinsics in this pallet.
 		type WeightInfo: WeightInfo;
 	}
@@ -68,16 +79,26 @@ pub mod pallet {
 	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T, I = ()>(PhantomData<(T, I)>);
 
+	/// The current budget period and the amount `ExternalOrigin` spent during it.
+	#[pallet::storage]
+	#[pallet::getter(fn budget_usage)]
+	pub type BudgetUsage<T: Config<I>, I: 'static = ()> =
+		StorageValue<_, (BlockNumberFor<T>, BalanceOf<T, I>), ValueQuery>;
+
 	#[pallet::hooks]
 	impl<T: Config<I>, I: 'static> Hooks<BlockNumberFor<T>> for Pallet<T, I> {}
 
 	#[pallet::call]
 	impl<T: Config<I>, I: 'static> Pallet<T, I> {
-		/// Spend `amount` funds from the reserve account to `to`.
+		/// Spend `amount` funds from the reserve account to `to`. Unless coming from root or
+		/// `LargeSpendOrigin` this is limited by what is left of the current budget period.
 		#[pallet::call_index(0)]
 		#[pallet::weight(T::WeightInfo::spend())]
 		pub fn spend(origin: OriginFor<T>, to: T::AccountId, amount: BalanceOf<T, I>) -> DispatchResultWithPostInfo {
-			T::ExternalOrigin::try_origin(origin).map(|_| ()).or_else(ensure_root)?;
+			let unlimited = Self::ensure_spender(origin)?;
+			if !unlimited {
+				Self::consume_budget(amount)?;
+			}
 
 			T::Currency::transfer(&Self::account_id(), &to, amount, ExistenceRequirement::KeepAlive)?;
 
@@ -100,7 +121,9 @@ pub mod pallet {
 		}
 
 		#[allow(clippy::boxed_local)]
-		/// Dispatch a call as coming from the reserve account
+		/// Dispatch a call as coming from the reserve account. The call could move funds in ways
+		/// the budget cannot follow, such as through proxies or batches, so only root or the
+		/// `LargeSpendOrigin` can do so.
 		#[pallet::call_index(2)]
 		#[pallet::weight({
             let dispatch_info = call.get_dispatch_info();
@@ -110,7 +133,9 @@ pub mod pallet {
             )
         })]
 		pub fn apply_as(origin: OriginFor<T>, call: Box<<T as Config<I>>::RuntimeCall>) -> DispatchResultWithPostInfo {
-			T::ExternalOrigin::try_origin(origin).map(|_| ()).or_else(ensure_root)?;
+			T::LargeSpendOrigin::try_origin(origin)
+				.map(|_| ())
+				.or_else(ensure_root)?;
 
 			call.dispatch(frame_system::RawOrigin::Signed(Self::account_id()).into())?;
 			Self::deposit_event(Event::ReserveOpExecuted);
@@ -130,6 +155,17 @@ pub mod pallet {
 		TipReceived(T::AccountId, BalanceOf<T, I>),
 		/// We executed a call coming from the company reserve account
 		ReserveOpExecuted,
+		/// Some of the budget of the current period was spent.
+		BudgetSpent {
+			amount: BalanceOf<T, I>,
+			remaining: BalanceOf<T, I>,
+		},
+	}
+
+	#[pallet::error]
+	pub enum Error<T, I = ()> {
+		/// The spend exceeds what is left of the budget of the current period.
+		BudgetExceeded,
 	}
 
 	#[pallet::genesis_config]
@@ -157,6 +193,43 @@ pub mod pallet {
 	}
 }
 
+impl<T: Config<I>, I: 'static> Pallet<T, I> {
+	/// Ensure `origin` can spend from the reserve, returning whether it can go beyond the budget.
+	fn ensure_spender(origin: OriginFor<T>) -> Result<bool, DispatchError> {
+		let origin = match T::LargeSpendOrigin::try_origin(origin) {
+			Ok(_) => return Ok(true),
+			Err(origin) => origin,
+		};
+		if ensure_root(origin.clone()).is_ok() {
+			return Ok(true);
+		}
+		T::ExternalOrigin::ensure_origin(origin)?;
+		Ok(false)
+	}
+
+	/// Record `amount` as spent during the current budget period, failing if it exceeds what is
+	/// left of the budget.
+	fn consume_budget(amount: BalanceOf<T, I>) -> DispatchResult {
+		let now = frame_system::Pallet::<T>::block_number();
+		let current_period = now.checked_div(&T::BudgetPeriod::get()).unwrap_or_else(Zero::zero);
+
+		let remaining = <BudgetUsage<T, I>>::try_mutate(|(period, spent)| -> Result<_, DispatchError> {
+			if *period != current_period {
+				*period = current_period;
+				*spent = Zero::zero();
+			}
+			let remaining = T::SpendBudget::get()
+				.checked_sub(&spent.saturating_add(amount))
+				.ok_or(Error::<T, I>::BudgetExceeded)?;
+			*spent = spent.saturating_add(amount);
+			Ok(remaining)
+		})?;
+
+		Self::deposit_event(Event::BudgetSpent { amount, remaining });
+		Ok(())
+	}
+}
+
 impl<T: Config<I>, I: 'static> WithAccountId<T::AccountId> for Pallet<T, I> {
 	fn account_id() -> T::AccountId {
 		T::PalletId::get().into_account_truncating()
@@ -95,6 +95,7 @@ impl pallet_balances::Config for Test {
 
 ord_parameter_types! {
 	pub const Admin: u64 = 1;
+	pub const LargeSpender: u64 = 2;
 }
 parameter_types! {
 	pub const ReserveModuleId: PalletId = PalletId(*b"py/resrv");
@@ -103,8 +104,11 @@ impl Config for Test {
 	type RuntimeEvent = ();
 	type Currency = pallet_balances::Pallet<Self>;
 	type ExternalOrigin = EnsureSignedBy<Admin, u64>;
+	type LargeSpendOrigin = EnsureSignedBy<LargeSpender, u64>;
 	type RuntimeCall = RuntimeCall;
 	type PalletId = ReserveModuleId;
+	type SpendBudget = ConstU64<150>;
+	type BudgetPeriod = ConstU64<10>;
 	type WeightInfo = ();
 }
 type TestCurrency = <Test as Config>::Currency;
@@ -194,13 +198,20 @@ fn make_call(value: u8) -> Box<RuntimeCall> {
 fn apply_as_error_if_bad_origin() {
 	new_test_ext().execute_with(|| {
 		assert_noop!(TestModule::apply_as(RuntimeOrigin::signed(0), make_call(1)), BadOrigin);
+		assert_noop!(
+			TestModule::apply_as(RuntimeOrigin::signed(Admin::get()), make_call(1)),
+			BadOrigin
+		);
 	})
 }
 
 #[test]
 fn apply_as_works() {
 	new_test_ext().execute_with(|| {
-		assert_ok!(TestModule::apply_as(RuntimeOrigin::signed(Admin::get()), make_call(1)));
+		assert_ok!(TestModule::apply_as(
+			RuntimeOrigin::signed(LargeSpender::get()),
+			make_call(1)
+		));
 	})
 }
 
@@ -225,8 +236,62 @@ fn forward_error_if_call_error() {
 		}));
 
 		assert_noop!(
-			TestModule::apply_as(RuntimeOrigin::signed(Admin::get()), failing),
+			TestModule::apply_as(RuntimeOrigin::signed(LargeSpender::get()), failing),
 			BadOrigin
 		);
 	})
 }
+
+#[test]
+fn spend_beyond_budget_requires_large_spend_origin() {
+	new_test_ext().execute_with(|| {
+		TestCurrency::make_free_balance_be(&TestModule::account_id(), 1000);
+
+		assert_ok!(TestModule::spend(RuntimeOrigin::signed(Admin::get()), 3, 100));
+		assert_noop!(
+			TestModule::spend(RuntimeOrigin::signed(Admin::get()), 3, 60),
+			Error::<Test>::BudgetExceeded
+		);
+		assert_ok!(TestModule::spend(RuntimeOrigin::signed(Admin::get()), 3, 50));
+
+		assert_ok!(TestModule::spend(RuntimeOrigin::signed(LargeSpender::get()), 3, 200));
+		assert_ok!(TestModule::spend(RawOrigin::Root.into(), 3, 200));
+		assert_eq!(TestModule::budget_usage(), (0, 150));
+		assert_eq!(Balances::free_balance(3), 550);
+	})
+}
+
+#[test]
+fn budget_is_renewed_every_period() {
+	new_test_ext().execute_with(|| {
+		TestCurrency::make_free_balance_be(&TestModule::account_id(), 1000);
+
+		System::set_block_number(9);
+		assert_ok!(TestModule::spend(RuntimeOrigin::signed(Admin::get()), 3, 150));
+		assert_noop!(
+			TestModule::spend(RuntimeOrigin::signed(Admin::get()), 3, 1),
+			Error::<Test>::BudgetExceeded
+		);
+
+		System::set_block_number(10);
+		assert_ok!(TestModule::spend(RuntimeOrigin::signed(Admin::get()), 3, 100));
+		assert_eq!(TestModule::budget_usage(), (1, 100));
+	})
+}
+
+#[test]
+fn apply_as_does_not_count_against_budget() {
+	new_test_ext().execute_with(|| {
+		TestCurrency::make_free_balance_be(&TestModule::account_id(), 1000);
+		let transfer = Box::new(RuntimeCall::Balances(
+			pallet_balances::Call::<Test>::transfer_allow_death { dest: 3, value: 200 },
+		));
+
+		assert_ok!(TestModule::apply_as(
+			RuntimeOrigin::signed(LargeSpender::get()),
+			transfer
+		));
+		assert_eq!(TestModule::budget_usage(), (0, 0));
+		assert_eq!(Balances::free_balance(3), 200);
+	})
+}
@@ -16,12 +16,12 @@
  * along with this program.  If not, see <http://www.gnu.org/licenses/>.
  */
 
-//! Autogenerated weights for pallet_reserve
+//! Weights for pallet_reserve
 //!
-//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 32.0.0
-//! DATE: 2024-02-20, STEPS: `50`, REPEAT: 20, LOW RANGE: `[]`, HIGH RANGE: `[]`
-//! HOSTNAME: `chain-bench-a18ada46`, CPU: `AMD EPYC 7B13`
-//! EXECUTION: , WASM-EXECUTION: Compiled, CHAIN: Some("dev"), DB CACHE: 1024
+//! Generated with the Substrate benchmark CLI version 32.0.0 on 2024-02-20 (STEPS: `50`,
+//! REPEAT: 20, CPU: `AMD EPYC 7B13`). The functions marked `TODO(benchmarks)` were added or
+//! changed since and hold conservative estimates until the file is regenerated with the
+//! command below.
 
 // Executed Command:
 // ./target/release/nodle-parachain
@@ -60,11 +60,13 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	}
 	// Storage: `System::Account` (r:2 w:2)
 	// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	// Storage: `Reserve::BudgetUsage` (r:1 w:1)
+	// Proof: `Reserve::BudgetUsage` (`max_values`: Some(1), `max_size`: Some(20), added: 515, mode: `MaxEncodedLen`)
 	fn spend() -> Weight {
-		// Minimum execution time: 59_990 nanoseconds.
+		// TODO(benchmarks): conservative estimate, not measured yet.
 		Weight::from_parts(61_500_000_u64, 0)
-			.saturating_add(T::DbWeight::get().reads(2_u64))
-			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
 }
 
@@ -79,10 +81,12 @@ impl WeightInfo for () {
 	}
 	// Storage: `System::Account` (r:2 w:2)
 	// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	// Storage: `Reserve::BudgetUsage` (r:1 w:1)
+	// Proof: `Reserve::BudgetUsage` (`max_values`: Some(1), `max_size`: Some(20), added: 515, mode: `MaxEncodedLen`)
 	fn spend() -> Weight {
-		// Minimum execution time: 59_990 nanoseconds.
+		// TODO(benchmarks): conservative estimate, not measured yet.
 		Weight::from_parts(61_500_000_u64, 0)
-			.saturating_add(RocksDbWeight::get().reads(2_u64))
-			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
 }
@@ -45,7 +45,7 @@ use frame_support::{
 };
 use pallet_aura::Authorities;
 use pallet_transaction_payment::{FeeDetails, RuntimeDispatchInfo};
-use primitives::{AccountId, Balance, BlockNumber, Hash, Nonce, Signature};
+use primitives::{AccountId, Balance, BlockNumber, Hash, Moment, Nonce, Signature};
 pub use primitives::{AuraId, ParaId};
 use sp_core::OpaqueMetadata;
 #[cfg(any(feature = "std", test))]
@@ -82,6 +82,7 @@ mod xcm_config;
 
 pub use genesis_config_presets::development_config_genesis;
 pub use pallets_consensus::SessionKeys;
+pub use pallets_nodle::EdenMintCurve;
 #[cfg(feature = "std")]
 pub use version::native_version;
 pub use version::VERSION;
@@ -129,6 +130,7 @@ construct_runtime! {
 		NodleUniques: pallet_nodle_uniques = 44,
 		Sponsorship: pallet_sponsorship = 45,
 		Identity: pallet_identity::{Pallet, Call, Storage, Event<T>} = 46,
+		MultiBlockMigrations: pallet_migrations = 47,
 
 		// Nodle Stack
 		// EmergencyShutdown: pallet_emergency_shutdown = 50,
@@ -208,9 +210,21 @@ pub type SignedPayload = generic::SignedPayload<RuntimeCall, SignedExtra>;
 /// Extrinsic type that has already been checked.
 pub type CheckedExtrinsic = generic::CheckedExtrinsic<AccountId, RuntimeCall, SignedExtra>;
 
+/// Storage migrations to apply on the next runtime upgrade.
+pub type Migrations = (
+	pallet_grants::migrations::v2::MigrateToV2<Runtime>,
+	pallet_grants::migrations::v3::MigrateToV3<Runtime>,
+);
+
 /// Executive: handles dispatch to the various modules.
-pub type Executive =
-	frame_executive::Executive<Runtime, Block, frame_system::ChainContext<Runtime>, Runtime, AllPalletsWithSystem>;
+pub type Executive = frame_executive::Executive<
+	Runtime,
+	Block,
+	frame_system::ChainContext<Runtime>,
+	Runtime,
+	AllPalletsWithSystem,
+	Migrations,
+>;
 
 #[cfg(feature = "runtime-benchmarks")]
 pub type XcmGenericBenchmarks = pallet_xcm_benchmarks::generic::Pallet<Runtime>;
@@ -337,6 +351,12 @@ sp_api::impl_runtime_apis! {
 		}
 	}
 
+	impl pallet_grants::runtime_api::GrantsApi<Block, AccountId, BlockNumber, Moment, Balance> for Runtime {
+		fn vesting_status(who: AccountId) -> pallet_grants::runtime_api::VestingStatus<BlockNumber, Moment, Balance> {
+			Vesting::vesting_status(&who)
+		}
+	}
+
 	impl pallet_transaction_payment_rpc_runtime_api::TransactionPaymentApi<
 		Block,
 		Balance,
@@ -21,9 +21,14 @@ use crate::{constants, Runtime, RuntimeCall, RuntimeEvent, RuntimeOrigin, Techni
 use frame_support::pallet_prelude::Weight;
 use frame_support::{parameter_types, traits::EitherOfDiverse, PalletId};
 use frame_system::{EnsureNever, EnsureRoot};
-use primitives::{AccountId, BlockNumber};
+use primitives::{AccountId, Balance, BlockNumber};
 pub use sp_runtime::Perbill;
 
+parameter_types! {
+	pub const ReserveSpendBudget: Balance = 1_000_000 * constants::NODL;
+	pub const ReserveBudgetPeriod: BlockNumber = 30 * constants::DAYS;
+}
+
 parameter_types! {
 	pub const CompanyReservePalletId: PalletId = PalletId(*b"py/resrv"); // 5EYCAe5ijiYfha9GzQDgPVtUCYDY9B8ZgcyiANL2L34crMoR
 }
@@ -32,8 +37,11 @@ impl pallet_reserve::Config<pallet_reserve::Instance1> for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type Currency = pallet_balances::Pallet<Runtime>;
 	type ExternalOrigin = MoreThanHalfOfTechComm;
+	type LargeSpendOrigin = AtLeastTwoThirdsOfTechComm;
 	type RuntimeCall = RuntimeCall;
 	type PalletId = CompanyReservePalletId;
+	type SpendBudget = ReserveSpendBudget;
+	type BudgetPeriod = ReserveBudgetPeriod;
 	type WeightInfo = crate::weights::pallet_reserve_company_reserve::WeightInfo<Runtime>;
 }
 
@@ -45,8 +53,11 @@ impl pallet_reserve::Config<pallet_reserve::Instance2> for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type Currency = pallet_balances::Pallet<Runtime>;
 	type ExternalOrigin = MoreThanHalfOfTechComm;
+	type LargeSpendOrigin = AtLeastTwoThirdsOfTechComm;
 	type RuntimeCall = RuntimeCall;
 	type PalletId = InternationalReservePalletId;
+	type SpendBudget = ReserveSpendBudget;
+	type BudgetPeriod = ReserveBudgetPeriod;
 	type WeightInfo = crate::weights::pallet_reserve_international_reserve::WeightInfo<Runtime>;
 }
 
@@ -58,8 +69,11 @@ impl pallet_reserve::Config<pallet_reserve::Instance3> for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type Currency = pallet_balances::Pallet<Runtime>;
 	type ExternalOrigin = MoreThanHalfOfTechComm;
+	type LargeSpendOrigin = AtLeastTwoThirdsOfTechComm;
 	type RuntimeCall = RuntimeCall;
 	type PalletId = UsaReservePalletId;
+	type SpendBudget = ReserveSpendBudget;
+	type BudgetPeriod = ReserveBudgetPeriod;
 	type WeightInfo = crate::weights::pallet_reserve_usa_reserve::WeightInfo<Runtime>;
 }
 
@@ -73,8 +87,11 @@ impl pallet_reserve::Config<pallet_reserve::Instance4> for Runtime {
 	// as of now nobody can spend this, later, we need to map this to the
 	// correct governance origin.
 	type ExternalOrigin = EnsureNever<AccountId>;
+	type LargeSpendOrigin = EnsureNever<AccountId>;
 	type RuntimeCall = RuntimeCall;
 	type PalletId = DaoReservePalletId;
+	type SpendBudget = ReserveSpendBudget;
+	type BudgetPeriod = ReserveBudgetPeriod;
 	type WeightInfo = crate::weights::pallet_reserve_dao_reserve::WeightInfo<Runtime>;
 }
 
@@ -88,6 +105,8 @@ parameter_types! {
 
 pub type MoreThanHalfOfTechComm =
 	pallet_collective::EnsureProportionMoreThan<AccountId, pallet_collective::Instance1, 1, 2>;
+pub type AtLeastTwoThirdsOfTechComm =
+	pallet_collective::EnsureProportionAtLeast<AccountId, pallet_collective::Instance1, 2, 3>;
 pub type EnsureRootOrMoreThanHalfOfTechComm = EitherOfDiverse<EnsureRoot<AccountId>, MoreThanHalfOfTechComm>;
 impl pallet_collective::Config<pallet_collective::Instance1> for Runtime {
 	type RuntimeOrigin = RuntimeOrigin;
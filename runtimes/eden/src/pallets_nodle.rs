@@ -16,13 +16,14 @@
  * along with this program.  If not, see <http://www.gnu.org/licenses/>.
  */
 use crate::{
-	constants, pallets_governance::MoreThanHalfOfTechComm, AllocationsOracles, Balances, DaoReserve, Runtime,
-	RuntimeEvent,
+	constants,
+	pallets_governance::{EnsureRootOrMoreThanHalfOfTechComm, MoreThanHalfOfTechComm},
+	AllocationsOracles, Balances, DaoReserve, Runtime, RuntimeEvent, Vesting,
 };
 use cumulus_pallet_parachain_system::RelaychainDataProvider;
 use frame_support::{parameter_types, PalletId};
 use lazy_static::lazy_static;
-use pallet_allocations::MintCurve;
+use pallet_allocations::{CarryOverPolicy, MintCurve};
 use sp_runtime::Perbill;
 
 lazy_static! {
@@ -84,6 +85,9 @@ parameter_types! {
 	pub const ProtocolFee: Perbill = Perbill::from_percent(20);
 	pub const AllocPalletId: PalletId = PalletId(*b"py/alloc");
 	pub const MaxAllocs: u32 = 500;
+	pub const MaxProtocolFeeDestinations: u32 = 4;
+	pub const QuotaCarryOver: CarryOverPolicy = CarryOverPolicy::None;
+	pub const AutoPauseThreshold: Option<Perbill> = None;
 	pub EdenMintCurve: &'static MintCurve<Runtime> = &EDEN_MINT_CURVE;
 }
 
@@ -93,11 +97,19 @@ impl pallet_allocations::Config for Runtime {
 	type PalletId = AllocPalletId;
 	type ProtocolFee = ProtocolFee;
 	type ProtocolFeeReceiver = DaoReserve;
+	type MaxProtocolFeeDestinations = MaxProtocolFeeDestinations;
+	type ProtocolFeeSplitOrigin = EnsureRootOrMoreThanHalfOfTechComm;
 	type MintCurve = EdenMintCurve;
+	type QuotaCarryOver = QuotaCarryOver;
 	type ExistentialDeposit = <Runtime as pallet_balances::Config>::ExistentialDeposit;
 	type MaxAllocs = MaxAllocs;
 	type OracleMembers = AllocationsOracles;
+	type OracleAdminOrigin = EnsureRootOrMoreThanHalfOfTechComm;
+	type PauseOrigin = MoreThanHalfOfTechComm;
+	type AutoPauseThreshold = AutoPauseThreshold;
 	type BlockNumberProvider = RelaychainDataProvider<Runtime>;
+	type VestedTransfer = Vesting;
+	type BridgeTransfer = Vesting;
 	type WeightInfo = crate::weights::pallet_allocations::WeightInfo<Runtime>;
 }
 
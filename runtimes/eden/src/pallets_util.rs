@@ -19,8 +19,8 @@
 
 use crate::{
 	constants, constants::DAYS, pallets_governance::MoreThanHalfOfTechComm, Balances, DaoReserve, OriginCaller,
-	Preimage, RandomnessCollectiveFlip, Runtime, RuntimeCall, RuntimeEvent, RuntimeHoldReason, RuntimeOrigin,
-	Signature, Timestamp,
+	Preimage, RandomnessCollectiveFlip, Runtime, RuntimeCall, RuntimeEvent, RuntimeFreezeReason, RuntimeHoldReason,
+	RuntimeOrigin, Signature, Timestamp,
 };
 use cumulus_pallet_parachain_system::RelaychainDataProvider;
 use frame_support::{
@@ -33,20 +33,29 @@ use frame_support::{
 use frame_system::{EnsureRoot, EnsureSigned};
 use pallet_contracts::{Frame, Schedule};
 use pallet_identity::legacy::IdentityInfo;
-use primitives::{AccountId, Balance};
+use primitives::{AccountId, Balance, BlockNumber};
 use sp_runtime::{traits::Verify, Perbill};
 
 parameter_types! {
 	pub const MaxSchedule: u32 = 100;
+	pub const MaxBatchSchedules: u32 = 100;
+	pub const MaxBridgeOracles: u32 = 16;
+	pub const BridgeTransferTimeout: BlockNumber = 30 * constants::DAYS_RELAY_CHAIN;
 }
 
 impl pallet_grants::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
+	type RuntimeFreezeReason = RuntimeFreezeReason;
 	type Currency = Balances;
 	type CancelOrigin = MoreThanHalfOfTechComm;
+	type BridgeOracleOrigin = MoreThanHalfOfTechComm;
+	type BridgeTransferTimeout = BridgeTransferTimeout;
 	type MaxSchedule = MaxSchedule;
+	type MaxBatchSchedules = MaxBatchSchedules;
+	type MaxBridgeOracles = MaxBridgeOracles;
 	type WeightInfo = crate::weights::pallet_grants::WeightInfo<Runtime>;
 	type BlockNumberProvider = RelaychainDataProvider<Runtime>;
+	type Time = Timestamp;
 }
 
 impl pallet_utility::Config for Runtime {
@@ -275,3 +284,22 @@ impl pallet_identity::Config for Runtime {
 	type MaxSuffixLength = ConstU32<7>;
 	type MaxUsernameLength = ConstU32<32>;
 }
+
+parameter_types! {
+	pub MbmServiceWeight: Weight = Perbill::from_percent(80) * constants::RuntimeBlockWeights::get().max_block;
+}
+
+impl pallet_migrations::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	#[cfg(not(feature = "runtime-benchmarks"))]
+	type Migrations = (pallet_grants::migrations::v4::MigrateToV4<Runtime, Balances>,);
+	// Benchmarks need mocked migrations to guarantee that they succeed.
+	#[cfg(feature = "runtime-benchmarks")]
+	type Migrations = pallet_migrations::mock_helpers::MockedMigrations;
+	type CursorMaxLen = ConstU32<65_536>;
+	type IdentifierMaxLen = ConstU32<256>;
+	type MigrationStatusHandler = ();
+	type FailedMigrationHandler = frame_support::migrations::FreezeChainOnFailedMigration;
+	type MaxServiceWeight = MbmServiceWeight;
+	type WeightInfo = pallet_migrations::weights::SubstrateWeight<Runtime>;
+}
@@ -40,7 +40,7 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
 	// Version of the runtime specification. A full-node will not attempt to use its native
 	// runtime in substitute for the on-chain Wasm runtime unless all of `spec_name`,
 	// `spec_version` and `authoring_version` are the same between Wasm and native.
-	spec_version: 33,
+	spec_version: 34,
 
 	// Version of the implementation of the specification. Nodes are free to ignore this; it
 	// serves only as an indication that the code is different; as long as the other two versions
@@ -51,7 +51,7 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
 	impl_version: 0,
 
 	// Used for hardware wallets. This typically happens when `SignedExtra` changes.
-	transaction_version: 12,
+	transaction_version: 13,
 
 	apis: RUNTIME_API_VERSIONS,
 	state_version: 0,
@@ -16,12 +16,12 @@
  * along with this program.  If not, see <http://www.gnu.org/licenses/>.
  */
 
-//! Autogenerated weights for pallet_allocations
+//! Weights for pallet_allocations
 //!
-//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 32.0.0
-//! DATE: 2024-08-21, STEPS: `50`, REPEAT: 20, LOW RANGE: `[]`, HIGH RANGE: `[]`
-//! HOSTNAME: `chain-bench-a18ada46`, CPU: `AMD EPYC 7B13`
-//! EXECUTION: , WASM-EXECUTION: Compiled, CHAIN: None, DB CACHE: 1024
+//! Generated with the Substrate benchmark CLI version 32.0.0 on 2024-08-21 (STEPS: `50`,
+//! REPEAT: 20, CPU: `AMD EPYC 7B13`). The functions marked `TODO(benchmarks)` were added or
+//! changed since and hold conservative estimates until the file is regenerated with the
+//! command below.
 
 // Executed Command:
 // ./target/release/nodle-parachain
@@ -47,17 +47,71 @@ impl<T: frame_system::Config> pallet_allocations::WeightInfo for WeightInfo<T> {
 	// Proof: `Allocations::SessionQuota` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
 	// Storage: `System::Account` (r:502 w:502)
 	// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	// Storage: `Allocations::OracleQuota` (r:1 w:0)
+	// Proof: `Allocations::OracleQuota` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	// Storage: `Allocations::OracleSessionAllocated` (r:1 w:1)
+	// Proof: `Allocations::OracleSessionAllocated` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	// Storage: `Allocations::SessionQuotaRenewSchedule` (r:1 w:0)
+	// Proof: `Allocations::SessionQuotaRenewSchedule` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	// Storage: `Allocations::OracleMinted` (r:1 w:1)
+	// Proof: `Allocations::OracleMinted` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	// Storage: `Allocations::Paused` (r:1 w:0)
+	// Proof: `Allocations::Paused` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
 	/// The range of component `b` is `[1, 500]`.
 	fn allocate(b: u32) -> Weight {
-		// Minimum execution time: 114_369 nanoseconds.
+		// TODO(benchmarks): conservative estimate, not measured yet.
 		Weight::from_parts(115_700_000_u64, 0)
-			// Standard Error: 13_456
 			.saturating_add(Weight::from_parts(43_748_727_u64, 0).saturating_mul(b as u64))
-			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().reads(8_u64))
 			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(b as u64)))
-			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(b as u64)))
+	}
+	// Storage: `Vesting::Bridges` (r:500 w:0)
+	// Proof: `Vesting::Bridges` (`max_values`: None, `max_size`: Some(77), added: 2552, mode: `MaxEncodedLen`)
+	fn allocate_bridged(b: u32) -> Weight {
+		// TODO(benchmarks): conservative estimate, not measured yet.
+		Weight::from_parts(115_700_000_u64, 0)
+			.saturating_add(Weight::from_parts(43_748_727_u64, 0).saturating_mul(b as u64))
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(b as u64)))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
 			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(b as u64)))
 	}
+	// Storage: `Allocations::SessionQuota` (r:1 w:1)
+	// Proof: `Allocations::SessionQuota` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	// Storage: `ParachainSystem::ValidationData` (r:1 w:0)
+	// Proof: `ParachainSystem::ValidationData` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	// Storage: `ParachainSystem::LastRelayChainBlockNumber` (r:1 w:0)
+	// Proof: `ParachainSystem::LastRelayChainBlockNumber` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	// Storage: `Vesting::VestingSchedules` (r:500 w:500)
+	// Proof: `Vesting::VestingSchedules` (`max_values`: None, `max_size`: Some(3250), added: 5725, mode: `MaxEncodedLen`)
+	// Storage: `System::Account` (r:502 w:502)
+	// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	// Storage: `Balances::Locks` (r:500 w:0)
+	// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	// Storage: `Balances::Freezes` (r:500 w:500)
+	// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	// Storage: `Allocations::OracleQuota` (r:1 w:0)
+	// Proof: `Allocations::OracleQuota` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	// Storage: `Allocations::OracleSessionAllocated` (r:1 w:1)
+	// Proof: `Allocations::OracleSessionAllocated` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	// Storage: `Allocations::SessionQuotaRenewSchedule` (r:1 w:0)
+	// Proof: `Allocations::SessionQuotaRenewSchedule` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	// Storage: `Allocations::OracleMinted` (r:1 w:1)
+	// Proof: `Allocations::OracleMinted` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	// Storage: `Allocations::Paused` (r:1 w:0)
+	// Proof: `Allocations::Paused` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// The range of component `b` is `[1, 500]`.
+	fn allocate_vested(b: u32) -> Weight {
+		// TODO(benchmarks): conservative estimate, not measured yet.
+		Weight::from_parts(203_118_000_u64, 0)
+			.saturating_add(Weight::from_parts(126_530_114_u64, 0).saturating_mul(b as u64))
+			.saturating_add(T::DbWeight::get().reads(10_u64))
+			.saturating_add(T::DbWeight::get().reads((4_u64).saturating_mul(b as u64)))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+			.saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(b as u64)))
+	}
 	// Storage: `Allocations::SessionQuotaCalculationSchedule` (r:1 w:1)
 	// Proof: `Allocations::SessionQuotaCalculationSchedule` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	// Storage: `Allocations::MintCurveStartingBlock` (r:1 w:1)
@@ -76,13 +130,17 @@ impl<T: frame_system::Config> pallet_allocations::WeightInfo for WeightInfo<T> {
 	// Proof: `Allocations::MintCurveStartingBlock` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	// Storage: `Allocations::NextSessionQuota` (r:1 w:0)
 	// Proof: `Allocations::NextSessionQuota` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
-	// Storage: `Allocations::SessionQuota` (r:0 w:1)
+	// Storage: `Allocations::SessionQuota` (r:1 w:1)
 	// Proof: `Allocations::SessionQuota` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	// Storage: `Balances::TotalIssuance` (r:1 w:0)
+	// Proof: `Balances::TotalIssuance` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	// Storage: `Allocations::RenewedSessionQuota` (r:0 w:1)
+	// Proof: `Allocations::RenewedSessionQuota` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
 	fn renew_quota() -> Weight {
-		// Minimum execution time: 5_020 nanoseconds.
+		// TODO(benchmarks): conservative estimate, not measured yet.
 		Weight::from_parts(5_230_000_u64, 0)
-			.saturating_add(T::DbWeight::get().reads(3_u64))
-			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
 	}
 	// Storage: `ParachainSystem::ValidationData` (r:1 w:0)
 	// Proof: `ParachainSystem::ValidationData` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
@@ -120,4 +178,49 @@ impl<T: frame_system::Config> pallet_allocations::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(2_u64))
 			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
+	// Storage: `Allocations::ProtocolFeeSplit` (r:0 w:1)
+	// Proof: `Allocations::ProtocolFeeSplit` (`max_values`: Some(1), `max_size`: Some(133), added: 628, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[0, 4]`.
+	fn set_protocol_fee_split(n: u32) -> Weight {
+		// TODO(benchmarks): conservative estimate, not measured yet.
+		Weight::from_parts(6_120_178_u64, 0)
+			.saturating_add(Weight::from_parts(288_771_u64, 0).saturating_mul(n as u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	// Storage: `Allocations::OracleQuota` (r:0 w:1)
+	// Proof: `Allocations::OracleQuota` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	fn set_oracle_quota() -> Weight {
+		// TODO(benchmarks): conservative estimate, not measured yet.
+		Weight::from_parts(6_636_000_u64, 0).saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	// Storage: `Allocations::SuspendedOracles` (r:0 w:1)
+	// Proof: `Allocations::SuspendedOracles` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	fn suspend_oracle() -> Weight {
+		// TODO(benchmarks): conservative estimate, not measured yet.
+		Weight::from_parts(6_307_000_u64, 0).saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	// Storage: `Allocations::SuspendedOracles` (r:1 w:1)
+	// Proof: `Allocations::SuspendedOracles` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	fn resume_oracle() -> Weight {
+		// TODO(benchmarks): conservative estimate, not measured yet.
+		Weight::from_parts(8_589_000_u64, 0)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	// Storage: `Allocations::Paused` (r:1 w:1)
+	// Proof: `Allocations::Paused` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	fn pause() -> Weight {
+		// TODO(benchmarks): conservative estimate, not measured yet.
+		Weight::from_parts(5_817_000_u64, 0)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	// Storage: `Allocations::Paused` (r:1 w:1)
+	// Proof: `Allocations::Paused` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	fn unpause() -> Weight {
+		// TODO(benchmarks): conservative estimate, not measured yet.
+		Weight::from_parts(5_880_000_u64, 0)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
 }
@@ -0,0 +1,31 @@
+#!/bin/bash
+
+# Run the runtime upgrade against a snapshot of the live chain, including the multi block
+# migrations which the try-runtime action of the CI does not step through.
+# The snapshot and the maximum number of blocks for the migrations are optional command line
+# arguments in $1 and $2, the eden snapshot is downloaded when none is given.
+SNAPSHOT="${1:-snaps/eden-snapshot-full}"
+MBM_MAX_BLOCKS="${2:-100}"
+TRY_RUNTIME_CLI_TAG="${TRY_RUNTIME_CLI_TAG:-v0.8.0}"
+
+if [ ! -f "$SNAPSHOT" ]; then
+    mkdir -p "$(dirname "$SNAPSHOT")"
+    curl -L --fail -o "$SNAPSHOT" \
+        https://github.com/NodleCode/eden-snapshot/releases/latest/download/eden-snapshot-full || exit -1
+fi
+
+if ! command -v try-runtime >/dev/null; then
+    cargo install --git https://github.com/paritytech/try-runtime-cli \
+        --tag "$TRY_RUNTIME_CLI_TAG" --locked || exit -1
+fi
+
+cargo build --release --features=try-runtime -p runtime-eden || exit -1
+
+try-runtime \
+    --runtime ./target/release/wbuild/runtime-eden/runtime_eden.wasm \
+    on-runtime-upgrade \
+    --blocktime 12000 \
+    --checks all \
+    --disable-idempotency-checks \
+    --mbm-max-blocks "$MBM_MAX_BLOCKS" \
+    snap --path "$SNAPSHOT"
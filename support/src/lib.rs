@@ -20,7 +20,7 @@
 
 use frame_support::{
 	pallet_prelude::{Decode, Encode, MaxEncodedLen, TypeInfo},
-	sp_runtime::RuntimeDebug,
+	sp_runtime::{DispatchResult, RuntimeDebug},
 	traits::tokens::Balance,
 };
 
@@ -28,6 +28,31 @@ pub trait WithAccountId<AccountId> {
 	fn account_id() -> AccountId;
 }
 
+/// Transfer funds which are only released gradually to their receiver.
+pub trait VestedTransfer<AccountId, BlockNumber, Balance> {
+	/// Transfer `amount` from `source` to `target` and lock it so that it is released in
+	/// `period_count` equal parts, one every `period` blocks after `start`.
+	fn vested_transfer(
+		source: &AccountId,
+		target: &AccountId,
+		amount: Balance,
+		start: BlockNumber,
+		period: BlockNumber,
+		period_count: u32,
+	) -> DispatchResult;
+}
+
+/// Move funds to an address on a remote EVM chain through one of the registered bridges.
+pub trait BridgeTransfer<AccountId, Balance> {
+	/// Burn `amount` from `source` and signal the bridge oracles that `eth_address` should be
+	/// credited with it on the chain behind `bridge_id`.
+	fn bridge_transfer(source: &AccountId, eth_address: [u8; 20], bridge_id: u32, amount: Balance) -> DispatchResult;
+
+	/// Make sure `bridge_id` is registered so that benchmarks can take the successful path.
+	#[cfg(feature = "runtime-benchmarks")]
+	fn register_bridge(_bridge_id: u32) {}
+}
+
 #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, Default, TypeInfo, MaxEncodedLen)]
 pub struct LimitedBalance<T: Balance> {
 	/// The cap for the balance